@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{data_encoding::BASE32, prelude::*};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(base32(padding(Allow)))]
+pub struct Base32(String);
+
+#[derive(Validator)]
+#[validator(base32_decoded(padding(Allow)))]
+pub struct Base32Decoded(Vec<u8>);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Base32>(data, |v| v.0.clone());
+    check::<Base32Decoded>(data, |v| BASE32.encode(&v.0));
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{data_encoding::BASE64, prelude::*};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(base64(padding(Allow)))]
+pub struct Base64(String);
+
+#[derive(Validator)]
+#[validator(base64_decoded(padding(Allow)))]
+pub struct Base64Decoded(Vec<u8>);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Base64>(data, |v| v.0.clone());
+    check::<Base64Decoded>(data, |v| BASE64.encode(&v.0));
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{data_encoding::BASE64URL, prelude::*};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(base64_url(padding(Allow)))]
+pub struct Base64Url(String);
+
+#[derive(Validator)]
+#[validator(base64_url_decoded(padding(Allow)))]
+pub struct Base64UrlDecoded(Vec<u8>);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Base64Url>(data, |v| v.0.clone());
+    check::<Base64UrlDecoded>(data, |v| BASE64URL.encode(&v.0));
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(boolean)]
+pub struct Boolean(bool);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Boolean>(data, |v| v.0.to_string());
+});
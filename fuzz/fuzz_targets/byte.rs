@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{
+    byte_unit::{Bit, Byte},
+    prelude::*,
+};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(bit)]
+pub struct BitSize(Bit);
+
+#[derive(Validator)]
+#[validator(byte(ignore_case = true))]
+pub struct ByteSize(Byte);
+
+fuzz_target!(|data: &[u8]| {
+    check::<BitSize>(data, |v| v.0.to_string());
+    check::<ByteSize>(data, |v| v.0.to_string());
+});
@@ -0,0 +1,37 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(Ipv4Addr);
+
+#[derive(Validator)]
+#[validator(ipv6(local(Allow), port(Disallow)))]
+pub struct Ipv6(Ipv6Addr);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[0-9a-f:.]+$"))]
+pub struct Lowercase(String);
+
+#[derive(Validator)]
+#[validator(all(Ipv6, Lowercase))]
+pub struct LowercaseIpv6(Ipv6);
+
+#[derive(Validator)]
+#[validator(any(Ipv4, Ipv6))]
+pub struct IpString(String);
+
+#[derive(Validator)]
+#[validator(not(Ipv4))]
+pub struct NotIpv4(String);
+
+fuzz_target!(|data: &[u8]| {
+    check::<LowercaseIpv6>(data, |v| v.0 .0.to_string());
+    check::<IpString>(data, |v| v.0.clone());
+    check::<NotIpv4>(data, |v| v.0.clone());
+});
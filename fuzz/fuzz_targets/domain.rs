@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Allow), local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct DomainAllowPort {
+    pub domain: String,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(domain(
+    ipv4(Disallow),
+    local(Disallow),
+    port(Disallow),
+    at_least_two_labels(Must)
+))]
+pub struct Domain(String);
+
+fuzz_target!(|data: &[u8]| {
+    check::<DomainAllowPort>(data, |v| v.to_uri_authority_string().into_owned());
+    check::<Domain>(data, |v| v.0.clone());
+});
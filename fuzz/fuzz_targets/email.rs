@@ -0,0 +1,42 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{models::Host, prelude::*};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Allow),
+    ip(Allow),
+    local(Allow),
+    at_least_two_labels(Allow),
+    non_ascii(Allow)
+))]
+pub struct EmailAllowComment {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: Host,
+    pub comment_before_local_part: Option<String>,
+    pub comment_after_local_part: Option<String>,
+    pub comment_before_domain_part: Option<String>,
+    pub comment_after_domain_part: Option<String>,
+}
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Disallow),
+    ip(Disallow),
+    local(Disallow),
+    at_least_two_labels(Must),
+    non_ascii(Disallow)
+))]
+pub struct Email {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<EmailAllowComment>(data, |v| v.to_email_string());
+    check::<Email>(data, |v| v.to_email_string());
+});
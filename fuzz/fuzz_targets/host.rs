@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{models::Host, prelude::*};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct HostAllowPort {
+    pub host: Host,
+    pub port: Option<u16>,
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<HostAllowPort>(data, |v| v.to_uri_authority_string().into_owned());
+});
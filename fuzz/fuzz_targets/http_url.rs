@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{models::Protocol, prelude::*, url::Url};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(http_url(local(Allow)))]
+pub struct HttpUrl {
+    pub url: Url,
+    pub is_https: bool,
+}
+
+#[derive(Validator)]
+#[validator(http_ftp_url(local(Allow)))]
+pub struct HttpFtpUrl {
+    pub url: Url,
+    pub protocol: Protocol,
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<HttpUrl>(data, |v| v.url.to_string());
+    check::<HttpFtpUrl>(data, |v| v.url.to_string());
+});
@@ -0,0 +1,34 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(ip(local(Allow), port(Allow)))]
+pub struct IpAllowPort {
+    pub ip: IpAddr,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Allow)))]
+pub struct Ipv4AllowPort {
+    pub ipv4: Ipv4Addr,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(ipv6(local(Allow), port(Allow)))]
+pub struct Ipv6AllowPort {
+    pub ipv6: Ipv6Addr,
+    pub port: Option<u16>,
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<IpAllowPort>(data, |v| v.to_uri_authority_string().into_owned());
+    check::<Ipv4AllowPort>(data, |v| v.to_uri_authority_string().into_owned());
+    check::<Ipv6AllowPort>(data, |v| v.to_uri_authority_string().into_owned());
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(json)]
+pub struct Json(String);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Json>(data, |v| v.to_minified_json_string());
+});
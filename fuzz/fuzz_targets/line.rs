@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(line(char_length(trimmed_min = 1, max = 1000)))]
+pub struct Line(String);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Line>(data, |v| v.0.clone());
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(mac_address(case(Any), separator(Allow(b':'))))]
+pub struct MacAddress(u64);
+
+fuzz_target!(|data: &[u8]| {
+    check::<MacAddress>(data, |v| v.to_mac_address_string());
+});
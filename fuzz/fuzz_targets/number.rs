@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(number(nan(Allow), range(Unlimited)))]
+pub struct Double(f64);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = -1, max = 1, inclusive = false))))]
+pub struct Ratio(f32);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Double>(data, |v| v.0.to_string());
+    check::<Ratio>(data, |v| v.0.to_string());
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use std::path::PathBuf;
+
+use validators::prelude::*;
+use validators_fuzz::check_os_str;
+
+#[derive(Validator)]
+#[validator(path(absolute(Disallow), parent(Disallow), windows_reserved(Disallow)))]
+pub struct RelativePath(PathBuf);
+
+#[derive(Validator)]
+#[validator(path(component_length(max = 255), extensions("txt", "md")))]
+pub struct Document(PathBuf);
+
+fuzz_target!(|data: &[u8]| {
+    check_os_str::<RelativePath>(data, |v| v.0.clone().into_os_string());
+    check_os_str::<Document>(data, |v| v.0.clone().into_os_string());
+});
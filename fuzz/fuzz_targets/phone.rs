@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{phonenumber::PhoneNumber, prelude::*};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(phone)]
+pub struct InternationalPhone(PhoneNumber);
+
+fuzz_target!(|data: &[u8]| {
+    check::<InternationalPhone>(data, |v| v.0.to_string());
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(regex(regex = r"^[a-z][a-z0-9_]{2,15}$"))]
+pub struct Identifier(String);
+
+#[derive(Validator)]
+#[validator(regex(regex = r"^\p{L}+(?: \p{L}+)*$"))]
+pub struct Words(String);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Identifier>(data, |v| v.0.clone());
+    check::<Words>(data, |v| v.0.clone());
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{
+    prelude::*,
+    semver::{Version, VersionReq},
+};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(semver)]
+pub struct SemVer(Version);
+
+#[derive(Validator)]
+#[validator(semver_req)]
+pub struct SemVerReq(VersionReq);
+
+fuzz_target!(|data: &[u8]| {
+    check::<SemVer>(data, |v| v.0.to_string());
+    check::<SemVerReq>(data, |v| v.0.to_string());
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Outside(min = -10, max = 10))))]
+pub struct FarFromZero(i32);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Unlimited)))]
+pub struct Offset(i128);
+
+fuzz_target!(|data: &[u8]| {
+    check::<FarFromZero>(data, |v| v.0.to_string());
+    check::<Offset>(data, |v| v.0.to_string());
+});
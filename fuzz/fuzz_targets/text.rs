@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(text(byte_length(trimmed_min = 1, max = 1000)))]
+pub struct Text(String);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Text>(data, |v| v.0.clone());
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 1000))))]
+pub struct Count(u16);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Unlimited)))]
+pub struct Size(u128);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Count>(data, |v| v.0.to_string());
+    check::<Size>(data, |v| v.0.to_string());
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::{prelude::*, url::Url};
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(url)]
+pub struct AnyUrl(Url);
+
+fuzz_target!(|data: &[u8]| {
+    check::<AnyUrl>(data, |v| v.0.to_string());
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use validators::prelude::*;
+use validators_fuzz::check;
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid(u128);
+
+fuzz_target!(|data: &[u8]| {
+    check::<Uuid>(data, |v| v.to_uuid_string());
+});
@@ -0,0 +1,97 @@
+/*!
+# Validators Fuzz
+
+Shared checks of the fuzz targets. Run a target with `cargo fuzz run <target>` in this directory.
+
+The targets take raw bytes, so input which is not valid UTF-8 is fuzzed too. It is rejected before `ValidateString`, which only takes `&str`, and given as is to the validators implementing `ValidateOsStr`.
+ */
+
+use std::{
+    ffi::{OsStr, OsString},
+    fmt::Debug,
+    os::unix::ffi::OsStrExt,
+};
+
+use validators::prelude::*;
+
+/// Check that `validate_str` and `parse_str` agree on the input if it is valid UTF-8, and that the string created from the parsed value by `to_string` can be parsed again.
+pub fn check<T: ValidateString>(data: &[u8], to_string: impl Fn(&T) -> String)
+where
+    T::Error: Debug,
+{
+    let s = match std::str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    let validated = T::validate_str(s);
+
+    match T::parse_str(s) {
+        Ok(value) => {
+            assert!(
+                validated.is_ok(),
+                "{s:?} is parsed but not validated: {validated:?}"
+            );
+
+            let serialized = to_string(&value);
+
+            if let Err(err) = T::parse_str(&serialized) {
+                panic!("{serialized:?} serialized from {s:?} cannot be parsed: {err:?}");
+            }
+        }
+        Err(err) => {
+            assert!(
+                validated.is_err(),
+                "{s:?} is validated but not parsed: {err:?}"
+            );
+        }
+    }
+}
+
+/// Check that `validate_os_str` and `parse_os_str` agree on the input, which may not be valid UTF-8, that the OS string created from the parsed value by `to_os_string` can be parsed again, and that `ValidateString` gives the same result for valid UTF-8.
+pub fn check_os_str<T: ValidateOsStr + ValidateString>(
+    data: &[u8],
+    to_os_string: impl Fn(&T) -> OsString,
+) where
+    <T as ValidateOsStr>::Error: Debug,
+    <T as ValidateString>::Error: Debug,
+{
+    let s = OsStr::from_bytes(data);
+
+    let validated = T::validate_os_str(s);
+
+    let parsed = match T::parse_os_str(s) {
+        Ok(value) => {
+            assert!(
+                validated.is_ok(),
+                "{s:?} is parsed but not validated: {validated:?}"
+            );
+
+            let serialized = to_os_string(&value);
+
+            if let Err(err) = T::parse_os_str(&serialized) {
+                panic!("{serialized:?} serialized from {s:?} cannot be parsed: {err:?}");
+            }
+
+            true
+        }
+        Err(err) => {
+            assert!(
+                validated.is_err(),
+                "{s:?} is validated but not parsed: {err:?}"
+            );
+
+            false
+        }
+    };
+
+    if let Ok(s) = std::str::from_utf8(data) {
+        let error = <T as ValidateString>::parse_str(s).err();
+
+        assert_eq!(
+            parsed,
+            error.is_none(),
+            "{s:?} is parsed differently as a string: {error:?}"
+        );
+    }
+}
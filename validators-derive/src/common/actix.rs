@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use crate::common::parses_owned_string;
+
+/// Implement `FromRequest` of `actix-web` for the struct, extracting it from the only path parameter of the route.
+pub(crate) fn impl_from_request(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    if !parses_owned_string(ast, meta) {
+        return TokenStream::new();
+    }
+
+    let name = &ast.ident;
+    let actix_path = quote! { validators_prelude::actix_web };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #actix_path::FromRequest for #name #ty_generics #where_clause {
+            type Error = #actix_path::Error;
+            type Future = ::core::future::Ready<Result<Self, Self::Error>>;
+
+            #[inline]
+            fn from_request(req: &#actix_path::HttpRequest, _: &mut #actix_path::dev::Payload) -> Self::Future {
+                ::core::future::ready(
+                    validators_prelude::web::actix_path_parameter(req)
+                        .and_then(|v| <Self as ValidateString>::parse_string(v).map_err(#actix_path::Error::from)),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use syn::{punctuated::Punctuated, spanned::Spanned, Expr, Ident, Lit, Meta, Token};
+
+use crate::panic;
+
+#[derive(Debug)]
+pub(crate) struct ActixOptions {
+    pub(crate) from_request: bool,
+}
+
+impl Default for ActixOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            from_request: true
+        }
+    }
+}
+
+impl ActixOptions {
+    #[inline]
+    pub(crate) fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        debug_assert!(meta.path().is_ident("actix"));
+
+        match meta {
+            Meta::Path(_) => {
+                #[cfg(feature = "actix")]
+                {
+                    Ok(Self::default())
+                }
+                #[cfg(not(feature = "actix"))]
+                {
+                    Err(syn::Error::new(meta.path().span(), "the `actix` feature is not enabled"))
+                }
+            },
+            Meta::NameValue(name_value) => {
+                if let Expr::Lit(lit) = &name_value.value {
+                    if let Lit::Bool(lit) = &lit.lit {
+                        let b = lit.value;
+
+                        #[cfg(not(feature = "actix"))]
+                        if b {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "the `actix` feature is not enabled, so the value cannot be `true`",
+                            ));
+                        }
+
+                        return Ok(Self {
+                            from_request: b
+                        });
+                    }
+                }
+
+                Err(syn::Error::new(name_value.value.span(), "expected a bool"))
+            },
+            Meta::List(list) => {
+                let result =
+                    list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_separated_nonempty)?;
+
+                let mut from_request = false;
+
+                #[cfg_attr(not(feature = "actix"), allow(clippy::never_loop))]
+                for p in result {
+                    match p.to_string().as_str() {
+                        "FromRequest" => {
+                            if from_request {
+                                return Err(panic::parameter_reset(&p));
+                            }
+
+                            from_request = true;
+                        },
+                        _ => {
+                            return Err(syn::Error::new(p.span(), "expected FromRequest"));
+                        },
+                    }
+
+                    #[cfg(not(feature = "actix"))]
+                    {
+                        let _ = from_request;
+
+                        return Err(syn::Error::new(
+                            p.span(),
+                            format!(
+                                "cannot implement `{p}` because the `actix` feature is not enabled"
+                            ),
+                        ));
+                    }
+                }
+
+                Ok(Self {
+                    from_request,
+                })
+            },
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use proc_macro2::Ident;
+use quote::{quote, ToTokens};
 use syn::{spanned::Spanned, Expr, Meta};
 
 use crate::common::path_to_string;
@@ -68,3 +69,18 @@ impl Allow {
         ))
     }
 }
+
+impl ToTokens for Allow {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        // we don't have `Allow` in the `validators` crate, use `TriAllow` instead
+        match self {
+            Self::Allow => {
+                token_stream.extend(quote! { validators_prelude::TriAllow::Allow });
+            },
+            Self::Disallow => {
+                token_stream.extend(quote! { validators_prelude::TriAllow::Disallow });
+            },
+        }
+    }
+}
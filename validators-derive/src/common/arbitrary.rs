@@ -0,0 +1,100 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, GenericParam, Lifetime, Meta};
+
+use crate::common::{
+    borrowed::borrowed_type,
+    inner_types::{inner_types, InnerTypes},
+    path_to_string,
+};
+
+/// Implement `Arbitrary` of `arbitrary` for the struct by parsing the arbitrary input with the validator. Input rejected by the validator becomes `Error::IncorrectFormat`.
+pub(crate) fn impl_arbitrary(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    let name = &ast.ident;
+    let arbitrary_path = quote! { validators_prelude::arbitrary };
+
+    // structs holding borrowed strings borrow them from the input, so they use their own lifetime
+    let (lifetime, generics) = match borrowed_type(ast) {
+        Some(_) => match ast.generics.params.first() {
+            Some(GenericParam::Lifetime(param)) => (param.lifetime.clone(), ast.generics.clone()),
+            _ => return TokenStream::new(),
+        },
+        None => {
+            let lifetime: Lifetime = parse_quote!('__validators);
+
+            let mut generics = ast.generics.clone();
+
+            generics.params.insert(0, parse_quote!(#lifetime));
+
+            (lifetime, generics)
+        },
+    };
+
+    let (impl_generics, ..) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let mut where_clause = ast.generics.where_clause.clone();
+
+    let parse = |input: TokenStream| match borrowed_type(ast) {
+        Some(_) => quote! {
+            <Self as ValidateBorrowedStr<#lifetime>>::parse_borrowed(<&#lifetime str as #arbitrary_path::Arbitrary<#lifetime>>::#input)
+        },
+        None => quote! {
+            <Self as ValidateString>::parse_str(<&#lifetime str as #arbitrary_path::Arbitrary<#lifetime>>::#input)
+        },
+    };
+
+    let (arbitrary, arbitrary_take_rest) = match inner_types(ast, meta) {
+        InnerTypes::Collection(ty) => {
+            // avoid a compile error for collections which do not implement `Arbitrary`
+            where_clause
+                .get_or_insert_with(|| parse_quote!(where))
+                .predicates
+                .push(parse_quote!(for<'__bound> #ty: #arbitrary_path::Arbitrary<#lifetime>));
+
+            (
+                quote! {
+                    <Self as ValidateLength<#ty>>::parse_collection(<#ty as #arbitrary_path::Arbitrary<#lifetime>>::arbitrary(u)?)
+                },
+                quote! {
+                    <Self as ValidateLength<#ty>>::parse_collection(<#ty as #arbitrary_path::Arbitrary<#lifetime>>::arbitrary_take_rest(u)?)
+                },
+            )
+        },
+        _ => match path_to_string(meta.path()).as_str() {
+            "number" | "signed_integer" | "unsigned_integer" => match &ast.data {
+                Data::Struct(data) => match &data.fields {
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        let ty = &fields.unnamed[0].ty;
+
+                        (
+                            quote! {
+                                <Self as ValidateString>::parse_string(validators_prelude::format!("{}", <#ty as #arbitrary_path::Arbitrary<#lifetime>>::arbitrary(u)?))
+                            },
+                            quote! {
+                                <Self as ValidateString>::parse_string(validators_prelude::format!("{}", <#ty as #arbitrary_path::Arbitrary<#lifetime>>::arbitrary_take_rest(u)?))
+                            },
+                        )
+                    },
+                    _ => return TokenStream::new(),
+                },
+                _ => return TokenStream::new(),
+            },
+            _ => (parse(quote! { arbitrary(u)? }), parse(quote! { arbitrary_take_rest(u)? })),
+        },
+    };
+
+    quote! {
+        impl #impl_generics #arbitrary_path::Arbitrary<#lifetime> for #name #ty_generics #where_clause {
+            #[inline]
+            fn arbitrary(u: &mut #arbitrary_path::Unstructured<#lifetime>) -> #arbitrary_path::Result<Self> {
+                #arbitrary.map_err(|_| #arbitrary_path::Error::IncorrectFormat)
+            }
+
+            #[inline]
+            fn arbitrary_take_rest(u: #arbitrary_path::Unstructured<#lifetime>) -> #arbitrary_path::Result<Self> {
+                #arbitrary_take_rest.map_err(|_| #arbitrary_path::Error::IncorrectFormat)
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use syn::{punctuated::Punctuated, Meta, Token};
 
 use crate::{
-    common::{rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow},
+    common::{
+        custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow,
+    },
     panic,
 };
 
@@ -11,15 +14,19 @@ pub(crate) struct BaseXXAttribute {
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
 }
 
 impl BaseXXAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["padding", "serde", "rocket"];
+        let correct_parameters = ["padding", "serde", "rocket", "custom", "normalize"];
 
         let mut padding = TriAllow::Allow;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -33,6 +40,8 @@ impl BaseXXAttribute {
                 let mut padding_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -76,6 +85,32 @@ impl BaseXXAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -98,6 +133,8 @@ impl BaseXXAttribute {
             padding,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
@@ -0,0 +1,141 @@
+use syn::{punctuated::Punctuated, spanned::Spanned, Meta, Path, Token};
+
+use crate::{
+    common::{
+        custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, serde_options::SerdeOptions,
+    },
+    panic,
+};
+
+pub(crate) struct CombinatorAttribute {
+    pub(crate) validators:     Vec<Path>,
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(crate) serde_options:  SerdeOptions,
+    #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
+    pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
+}
+
+impl CombinatorAttribute {
+    /// Types of validators are written as paths, such as `all(Domain, Lowercase)`. `min` and `max` are the allowed numbers of them.
+    pub(crate) fn build_from_meta(meta: &Meta, min: usize, max: usize) -> syn::Result<Self> {
+        let correct_parameters = ["serde", "rocket", "custom", "normalize"];
+
+        let mut validators = Vec::new();
+        let mut serde_options = SerdeOptions::default();
+        let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
+
+        match meta {
+            Meta::Path(_) | Meta::NameValue(_) => {
+                return Err(panic::attribute_incorrect_format(meta.path().get_ident().unwrap()));
+            },
+            Meta::List(list) => {
+                let result =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+                let mut serde_options_is_set = false;
+                let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
+
+                let mut handler = |meta: &Meta| -> syn::Result<bool> {
+                    if let Some(ident) = meta.path().get_ident() {
+                        match ident.to_string().as_str() {
+                            "serde" => {
+                                let v = SerdeOptions::from_meta(meta)?;
+
+                                if serde_options_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                serde_options_is_set = true;
+
+                                serde_options = v;
+
+                                return Ok(true);
+                            },
+                            "rocket" => {
+                                let v = RocketOptions::from_meta(meta)?;
+
+                                if rocket_options_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                rocket_options_is_set = true;
+
+                                rocket_options = v;
+
+                                return Ok(true);
+                            },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
+                            _ => (),
+                        }
+                    }
+
+                    if let Meta::Path(path) = meta {
+                        validators.push(path.clone());
+
+                        return Ok(true);
+                    }
+
+                    Ok(false)
+                };
+
+                for p in result {
+                    if !handler(&p)? {
+                        return Err(panic::parameter_incorrect_format(
+                            p.path(),
+                            &correct_parameters,
+                        ));
+                    }
+                }
+            },
+        }
+
+        if validators.len() < min || validators.len() > max {
+            return Err(panic::combinator_validators_count(
+                meta.path().get_ident().unwrap(),
+                min,
+                max,
+                meta.span(),
+            ));
+        }
+
+        Ok(Self {
+            validators,
+            serde_options,
+            rocket_options,
+            custom,
+            normalize,
+        })
+    }
+}
@@ -4,6 +4,8 @@ use syn::{punctuated::Punctuated, Meta, Token};
 
 use crate::{
     common::{
+        custom_option::CustomOption,
+        normalize_option::NormalizeOption,
         range::RangedNumber,
         range_option::{RangeOption, RangeTokenStream},
         rocket_options::RocketOptions,
@@ -18,17 +20,21 @@ pub(crate) struct RangeAttribute {
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
 }
 
 impl RangeAttribute {
     pub(crate) fn build_from_meta<T: RangedNumber>(meta: &Meta) -> syn::Result<Self>
     where
         T::Err: Display, {
-        let correct_parameters = ["range", "serde", "rocket"];
+        let correct_parameters = ["range", "serde", "rocket", "custom", "normalize"];
 
         let mut range = RangeTokenStream::Unlimited;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -42,6 +48,8 @@ impl RangeAttribute {
                 let mut range_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -85,6 +93,32 @@ impl RangeAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -107,6 +141,8 @@ impl RangeAttribute {
             range,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
@@ -0,0 +1,35 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Meta};
+
+use crate::common::parses_owned_string;
+
+/// Implement `FromRequestParts` of `axum` for the struct, extracting it from the only path parameter of the route like `validators::web::Path` does.
+pub(crate) fn impl_from_request_parts(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    if !parses_owned_string(ast, meta) {
+        return TokenStream::new();
+    }
+
+    let name = &ast.ident;
+    let axum_path = quote! { validators_prelude::axum };
+
+    let mut generics = ast.generics.clone();
+
+    generics.params.push(parse_quote!(__S: ::core::marker::Send + ::core::marker::Sync));
+
+    let (impl_generics, ..) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #axum_path::extract::FromRequestParts<__S> for #name #ty_generics #where_clause {
+            type Rejection = validators_prelude::web::Rejection;
+
+            #[inline]
+            async fn from_request_parts(parts: &mut #axum_path::http::request::Parts, state: &__S) -> Result<Self, Self::Rejection> {
+                let validators_prelude::web::Path(v) = <validators_prelude::web::Path<Self> as #axum_path::extract::FromRequestParts<__S>>::from_request_parts(parts, state).await?;
+
+                Ok(v)
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use syn::{punctuated::Punctuated, spanned::Spanned, Expr, Ident, Lit, Meta, Token};
+
+use crate::panic;
+
+#[derive(Debug)]
+pub(crate) struct AxumOptions {
+    pub(crate) from_request_parts: bool,
+}
+
+impl Default for AxumOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            from_request_parts: true
+        }
+    }
+}
+
+impl AxumOptions {
+    #[inline]
+    pub(crate) fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        debug_assert!(meta.path().is_ident("axum"));
+
+        match meta {
+            Meta::Path(_) => {
+                #[cfg(feature = "axum")]
+                {
+                    Ok(Self::default())
+                }
+                #[cfg(not(feature = "axum"))]
+                {
+                    Err(syn::Error::new(meta.path().span(), "the `axum` feature is not enabled"))
+                }
+            },
+            Meta::NameValue(name_value) => {
+                if let Expr::Lit(lit) = &name_value.value {
+                    if let Lit::Bool(lit) = &lit.lit {
+                        let b = lit.value;
+
+                        #[cfg(not(feature = "axum"))]
+                        if b {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "the `axum` feature is not enabled, so the value cannot be `true`",
+                            ));
+                        }
+
+                        return Ok(Self {
+                            from_request_parts: b
+                        });
+                    }
+                }
+
+                Err(syn::Error::new(name_value.value.span(), "expected a bool"))
+            },
+            Meta::List(list) => {
+                let result =
+                    list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_separated_nonempty)?;
+
+                let mut from_request_parts = false;
+
+                #[cfg_attr(not(feature = "axum"), allow(clippy::never_loop))]
+                for p in result {
+                    match p.to_string().as_str() {
+                        "FromRequestParts" => {
+                            if from_request_parts {
+                                return Err(panic::parameter_reset(&p));
+                            }
+
+                            from_request_parts = true;
+                        },
+                        _ => {
+                            return Err(syn::Error::new(p.span(), "expected FromRequestParts"));
+                        },
+                    }
+
+                    #[cfg(not(feature = "axum"))]
+                    {
+                        let _ = from_request_parts;
+
+                        return Err(syn::Error::new(
+                            p.span(),
+                            format!(
+                                "cannot implement `{p}` because the `axum` feature is not enabled"
+                            ),
+                        ));
+                    }
+                }
+
+                Ok(Self {
+                    from_request_parts,
+                })
+            },
+        }
+    }
+}
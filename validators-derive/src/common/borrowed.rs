@@ -0,0 +1,263 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, GenericParam, Ident, Path, PathArguments, Type,
+};
+
+use crate::{
+    common::{
+        custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, serde_options::SerdeOptions,
+    },
+    panic,
+};
+
+/// The validators which can be used for borrowed strings, because they do not change the input.
+pub(crate) const BORROWED_VALIDATORS: [&str; 8] =
+    ["base32", "base64", "base64_url", "domain", "line", "regex", "text", "uuid"];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum BorrowedType {
+    /// `&'a str`
+    Str,
+    /// `Cow<'a, str>`
+    Cow,
+}
+
+/// Find out whether the struct is `Name<'a>(&'a str)` or `Name<'a>(Cow<'a, str>)`.
+pub(crate) fn borrowed_type(ast: &DeriveInput) -> Option<BorrowedType> {
+    let mut params = ast.generics.params.iter();
+
+    let lifetime = match (params.next(), params.next()) {
+        (Some(GenericParam::Lifetime(param)), None) => &param.lifetime,
+        _ => return None,
+    };
+
+    let field = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match &field.ty {
+        Type::Reference(reference)
+            if reference.mutability.is_none()
+                && reference.lifetime.as_ref() == Some(lifetime)
+                && is_str(&reference.elem) =>
+        {
+            Some(BorrowedType::Str)
+        },
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+
+            if segment.ident != "Cow" {
+                return None;
+            }
+
+            match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) if arguments.args.len() == 2 => {
+                    match (&arguments.args[0], &arguments.args[1]) {
+                        (GenericArgument::Lifetime(l), GenericArgument::Type(ty))
+                            if l == lifetime && is_str(ty) =>
+                        {
+                            Some(BorrowedType::Cow)
+                        },
+                        _ => None,
+                    }
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Implement the traits for a struct which borrows its string, instead of the ones for owned strings. The input is validated by the `v_parse_str` function implemented by the handler, which takes a `&str` or a `Cow<str>`, and then borrowed by the struct, so only the validators which do not change the input are supported.
+#[cfg_attr(not(all(feature = "serde", feature = "rocket")), allow(unused_variables))]
+#[cfg_attr(
+    not(any(
+        feature = "base32",
+        feature = "base64",
+        feature = "base64_url",
+        feature = "domain",
+        feature = "line",
+        feature = "regex",
+        feature = "text",
+        feature = "uuid"
+    )),
+    allow(dead_code)
+)]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn impl_borrowed(
+    token_stream: &mut TokenStream,
+    name: &Ident,
+    borrowed_type: BorrowedType,
+    error_type: &Path,
+    custom: &CustomOption,
+    normalize: Option<&NormalizeOption>,
+    serde_options: &SerdeOptions,
+    rocket_options: &RocketOptions,
+    expect: &str,
+) -> syn::Result<()> {
+    if let Some(normalize) = normalize {
+        return Err(panic::borrowed_normalize(normalize.span.unwrap_or_else(Span::call_site)));
+    }
+
+    let ok = custom.ok();
+
+    let borrow = match borrowed_type {
+        BorrowedType::Str => quote! { #name(s) },
+        BorrowedType::Cow => quote! { #name(validators_prelude::Cow::Borrowed(s)) },
+    };
+
+    token_stream.extend(quote! {
+        impl<'a> ValidateBorrowedStr<'a> for #name<'a> {
+            type Error = #error_type;
+
+            #[inline]
+            fn parse_borrowed(s: &'a str) -> Result<Self, Self::Error> {
+                Self::v_parse_str(s.into())?;
+
+                #ok(#borrow)
+            }
+        }
+    });
+
+    if borrowed_type == BorrowedType::Cow {
+        let validate_str =
+            custom.validate(quote! { <Self as ValidateString>::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref().into())?;
+
+                Ok(())
+            });
+
+        token_stream.extend(quote! {
+            impl<'a> ValidateString for #name<'a> {
+                type Error = #error_type;
+
+                #[inline]
+                fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                    let s = s.into();
+
+                    Self::v_parse_str(s.as_str().into())?;
+
+                    #ok(#name(validators_prelude::Cow::Owned(s)))
+                }
+
+                #[inline]
+                fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                    <Self as ValidateString>::parse_string(s.as_ref())
+                }
+
+                #[inline]
+                fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                    #validate_str
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    {
+        if serde_options.serialize {
+            token_stream.extend(quote! {
+                impl<'a> validators_prelude::serde::Serialize for #name<'a> {
+                    #[inline]
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                        where
+                            S: validators_prelude::serde::Serializer, {
+                        serializer.serialize_str(&self.0)
+                    }
+                }
+            });
+        }
+
+        if serde_options.deserialize {
+            let visit_str = match borrowed_type {
+                BorrowedType::Str => quote! {},
+                BorrowedType::Cow => quote! {
+                    #[inline]
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: validators_prelude::serde::de::Error, {
+                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
+                    }
+
+                    #[inline]
+                    fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
+                    where
+                        E: validators_prelude::serde::de::Error, {
+                        <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
+                    }
+                },
+            };
+
+            token_stream.extend(quote! {
+                impl<'de: 'a, 'a> validators_prelude::serde::Deserialize<'de> for #name<'a> {
+                    #[inline]
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: validators_prelude::serde::Deserializer<'de>, {
+                        struct MyVisitor;
+
+                        impl<'de> validators_prelude::serde::de::Visitor<'de> for MyVisitor {
+                            type Value = #name<'de>;
+
+                            #[inline]
+                            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                f.write_str(#expect)
+                            }
+
+                            #[inline]
+                            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+                            where
+                                E: validators_prelude::serde::de::Error, {
+                                <#name as ValidateBorrowedStr>::parse_borrowed(v).map_err(validators_prelude::de::error)
+                            }
+
+                            #visit_str
+                        }
+
+                        deserializer.deserialize_str(MyVisitor)
+                    }
+                }
+            });
+        }
+    }
+
+    #[cfg(feature = "rocket")]
+    {
+        if rocket_options.from_form_field {
+            token_stream.extend(quote! {
+                impl<'r> validators_prelude::rocket::form::FromFormField<'r> for #name<'r> {
+                    #[inline]
+                    fn from_value(v: validators_prelude::rocket::form::ValueField<'r>) -> validators_prelude::rocket::form::Result<'r, Self> {
+                        Ok(<Self as ValidateBorrowedStr<'r>>::parse_borrowed(v.value).map_err(validators_prelude::rocket::form::Error::custom)?)
+                    }
+                }
+            });
+        }
+
+        if rocket_options.from_param {
+            token_stream.extend(quote! {
+                impl<'r> validators_prelude::rocket::request::FromParam<'r> for #name<'r> {
+                    type Error = #error_type;
+
+                    #[inline]
+                    fn from_param(v: &'r str) -> Result<Self, Self::Error> {
+                        <Self as ValidateBorrowedStr<'r>>::parse_borrowed(v)
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn is_str(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("str"))
+}
@@ -1,3 +1,4 @@
+use quote::{quote, ToTokens};
 use syn::{spanned::Spanned, Expr, Ident, Meta};
 
 use crate::common::path_to_string;
@@ -82,3 +83,20 @@ impl CaseOption {
         ))
     }
 }
+
+impl ToTokens for CaseOption {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Any => {
+                token_stream.extend(quote! { validators_prelude::CaseOption::Any });
+            },
+            Self::Lower => {
+                token_stream.extend(quote! { validators_prelude::CaseOption::Lower });
+            },
+            Self::Upper => {
+                token_stream.extend(quote! { validators_prelude::CaseOption::Upper });
+            },
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use crate::common::parses_owned_string;
+
+/// Implement `ValueParserFactory` of `clap` for the struct, whose parser is `validators::cli::ValidatorParser`.
+pub(crate) fn impl_value_parser_factory(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    if !parses_owned_string(ast, meta) {
+        return TokenStream::new();
+    }
+
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics validators_prelude::clap::builder::ValueParserFactory for #name #ty_generics #where_clause {
+            type Parser = validators_prelude::cli::ValidatorParser<Self>;
+
+            #[inline]
+            fn value_parser() -> Self::Parser {
+                validators_prelude::cli::ValidatorParser::new()
+            }
+        }
+    }
+}
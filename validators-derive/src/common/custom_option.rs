@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Expr, Generics, Ident, Meta, Path};
+
+use crate::panic;
+
+/// The `custom = path::to::function` parameter, a function which checks the struct after the built-in parsing succeeds.
+#[derive(Default)]
+pub(crate) struct CustomOption(pub(crate) Option<Path>);
+
+impl CustomOption {
+    #[inline]
+    pub(crate) fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        debug_assert!(meta.path().is_ident("custom"));
+
+        match meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Path(path) if path.qself.is_none() => Ok(Self(Some(path.path.clone()))),
+                _ => Err(panic::custom_incorrect_format(name_value.value.span())),
+            },
+            _ => Err(panic::custom_incorrect_format(meta.span())),
+        }
+    }
+
+    /// The `Error` type of the traits which parse the struct. It is `CustomError<E>` if there is a custom function.
+    #[inline]
+    pub(crate) fn error_type(&self, error_path: &Path) -> Path {
+        match &self.0 {
+            Some(_) => {
+                syn::parse2(quote! { validators_prelude::CustomError<#error_path> }).unwrap()
+            },
+            None => error_path.clone(),
+        }
+    }
+
+    /// The function which the `parse_*` methods pass the parsed struct to, `Ok` or `Self::v_custom`.
+    #[inline]
+    pub(crate) fn ok(&self) -> TokenStream {
+        match &self.0 {
+            Some(_) => quote! { Self::v_custom },
+            None => quote! { Ok },
+        }
+    }
+
+    /// The body of a `validate_*` method. If there is a custom function, the struct has to be created by `parse` for the function.
+    #[inline]
+    pub(crate) fn validate(&self, parse: TokenStream, body: TokenStream) -> TokenStream {
+        match &self.0 {
+            Some(_) => quote! {
+                #parse?;
+
+                Ok(())
+            },
+            None => body,
+        }
+    }
+
+    /// Implement `v_custom` which calls the custom function, if there is one.
+    pub(crate) fn impl_custom(
+        &self,
+        token_stream: &mut TokenStream,
+        name: &Ident,
+        generics: &Generics,
+        error_path: &Path,
+    ) {
+        if let Some(custom) = &self.0 {
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            token_stream.extend(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #[inline]
+                    fn v_custom(v: Self) -> Result<Self, validators_prelude::CustomError<#error_path>> {
+                        #custom(&v).map_err(validators_prelude::CustomError::custom)?;
+
+                        Ok(v)
+                    }
+                }
+            });
+        }
+    }
+}
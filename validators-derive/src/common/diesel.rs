@@ -0,0 +1,377 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, GenericParam, Generics, Meta, WherePredicate};
+
+use crate::common::{borrowed::borrowed_type, path_to_string};
+
+/// Implement `ToSql`, `FromSql`, `AsExpression` and `Queryable` of `diesel` for the struct.
+///
+/// Integers, numbers and booleans are delegated to their inner values, so they can be used with any SQL type which their inner values support, such as `Integer` or `BigInt`. The other validated values are `Text`, made of the strings which they are serialized to, and `ToSql` is only implemented for the backends which write bytes because the strings are temporary. Decoded values are validated again. Structs holding borrowed strings, collections, decoded bytes, phone numbers and paths, which may not be valid UTF-8, are skipped.
+///
+/// MAC addresses are also `BigInt`, and UUIDs are also the `Uuid` type of Postgres if the `diesel_postgres` feature is enabled.
+pub(crate) fn impl_diesel(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    if borrowed_type(ast).is_some() {
+        return TokenStream::new();
+    }
+
+    let inner_type = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(&fields.unnamed[0].ty),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let validator = path_to_string(meta.path());
+
+    let decode = match (validator.as_str(), inner_type) {
+        (
+            "length" | "base32_decoded" | "base64_decoded" | "base64_url_decoded" | "phone"
+            | "path",
+            _,
+        ) => {
+            return TokenStream::new();
+        },
+        ("boolean", Some(ty)) => Some((ty, quote! { <Self as ValidateBoolean>::parse_bool(v) })),
+        ("unsigned_integer", Some(ty)) => {
+            Some((ty, quote! { <Self as ValidateUnsignedInteger>::parse_u128(v as u128) }))
+        },
+        ("signed_integer", Some(ty)) => {
+            Some((ty, quote! { <Self as ValidateSignedInteger>::parse_i128(v as i128) }))
+        },
+        ("number", Some(ty)) => {
+            Some((ty, quote! { <Self as ValidateNumber>::parse_f64(v as f64) }))
+        },
+        _ => None,
+    };
+
+    let name = &ast.ident;
+    let diesel_path = quote! { validators_prelude::diesel };
+    let text = quote! { #diesel_path::sql_types::Text };
+
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let queryable_generics =
+        extend_generics(&ast.generics, [parse_quote!(__ST), parse_quote!(__DB)], [
+            parse_quote!(__DB: #diesel_path::backend::Backend),
+            parse_quote!(__ST: #diesel_path::sql_types::SingleValue),
+            parse_quote!(Self: #diesel_path::deserialize::FromSql<__ST, __DB>),
+        ]);
+    let (queryable_impl_generics, _, queryable_where_clause) = queryable_generics.split_for_impl();
+
+    let queryable = quote! {
+        impl #queryable_impl_generics #diesel_path::deserialize::Queryable<__ST, __DB> for #name #ty_generics #queryable_where_clause {
+            type Row = Self;
+
+            #[inline]
+            fn build(row: Self) -> #diesel_path::deserialize::Result<Self> {
+                Ok(row)
+            }
+        }
+    };
+
+    if let Some((ty, decode)) = decode {
+        let to_sql_generics =
+            extend_generics(&ast.generics, [parse_quote!(__ST), parse_quote!(__DB)], [
+                parse_quote!(__DB: #diesel_path::backend::Backend),
+                parse_quote!(#ty: #diesel_path::serialize::ToSql<__ST, __DB>),
+                parse_quote!(for<'__d> Self: ::core::fmt::Debug),
+            ]);
+        let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+
+        let from_sql_generics =
+            extend_generics(&ast.generics, [parse_quote!(__ST), parse_quote!(__DB)], [
+                parse_quote!(__DB: #diesel_path::backend::Backend),
+                parse_quote!(#ty: #diesel_path::deserialize::FromSql<__ST, __DB>),
+            ]);
+        let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+        let as_expression_predicates: [WherePredicate; 2] = [
+            parse_quote!(__ST: #diesel_path::sql_types::SqlType + #diesel_path::expression::TypedExpressionType),
+            parse_quote!(#ty: #diesel_path::expression::AsExpression<__ST>),
+        ];
+
+        let as_expression_generics =
+            extend_generics(&ast.generics, [parse_quote!(__ST)], as_expression_predicates.clone());
+        let (as_expression_impl_generics, _, as_expression_where_clause) =
+            as_expression_generics.split_for_impl();
+
+        let ref_as_expression_generics = extend_generics(
+            &ast.generics,
+            [parse_quote!('__expr), parse_quote!(__ST)],
+            as_expression_predicates,
+        );
+        let (ref_as_expression_impl_generics, ..) = ref_as_expression_generics.split_for_impl();
+
+        return quote! {
+            impl #to_sql_impl_generics #diesel_path::serialize::ToSql<__ST, __DB> for #name #ty_generics #to_sql_where_clause {
+                #[inline]
+                fn to_sql<'__b>(&'__b self, out: &mut #diesel_path::serialize::Output<'__b, '_, __DB>) -> #diesel_path::serialize::Result {
+                    <#ty as #diesel_path::serialize::ToSql<__ST, __DB>>::to_sql(&self.0, out)
+                }
+            }
+
+            impl #from_sql_impl_generics #diesel_path::deserialize::FromSql<__ST, __DB> for #name #ty_generics #from_sql_where_clause {
+                #[inline]
+                fn from_sql(bytes: <__DB as #diesel_path::backend::Backend>::RawValue<'_>) -> #diesel_path::deserialize::Result<Self> {
+                    let v = <#ty as #diesel_path::deserialize::FromSql<__ST, __DB>>::from_sql(bytes)?;
+
+                    Ok(#decode?)
+                }
+            }
+
+            impl #as_expression_impl_generics #diesel_path::expression::AsExpression<__ST> for #name #ty_generics #as_expression_where_clause {
+                type Expression = <#ty as #diesel_path::expression::AsExpression<__ST>>::Expression;
+
+                #[inline]
+                fn as_expression(self) -> Self::Expression {
+                    <#ty as #diesel_path::expression::AsExpression<__ST>>::as_expression(self.0)
+                }
+            }
+
+            impl #ref_as_expression_impl_generics #diesel_path::expression::AsExpression<__ST> for &'__expr #name #ty_generics #as_expression_where_clause {
+                type Expression = <#ty as #diesel_path::expression::AsExpression<__ST>>::Expression;
+
+                #[inline]
+                fn as_expression(self) -> Self::Expression {
+                    <#ty as #diesel_path::expression::AsExpression<__ST>>::as_expression(self.0)
+                }
+            }
+
+            #queryable
+        };
+    }
+
+    let to_sql_generics = extend_generics(&ast.generics, [parse_quote!(__DB)], [
+        parse_quote!(for<'__c> __DB: #diesel_path::backend::Backend<BindCollector<'__c> = #diesel_path::query_builder::bind_collector::RawBytesBindCollector<__DB>>),
+        parse_quote!(for<'__d> Self: ::core::fmt::Debug),
+    ]);
+    let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+
+    let from_sql_generics = extend_generics(&ast.generics, [parse_quote!(__DB)], [
+        parse_quote!(__DB: #diesel_path::backend::Backend),
+        parse_quote!(validators_prelude::String: #diesel_path::deserialize::FromSql<#text, __DB>),
+    ]);
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+    let (impl_generics, _, where_clause) = ast.generics.split_for_impl();
+
+    let ref_generics = extend_generics(&ast.generics, [parse_quote!('__expr)], []);
+    let (ref_impl_generics, ..) = ref_generics.split_for_impl();
+
+    let as_expression = [text.clone(), quote! { #diesel_path::sql_types::Nullable<#text> }]
+        .into_iter()
+        .map(|sql_type| {
+            quote! {
+                impl #impl_generics #diesel_path::expression::AsExpression<#sql_type> for #name #ty_generics #where_clause {
+                    type Expression = <validators_prelude::String as #diesel_path::expression::AsExpression<#sql_type>>::Expression;
+
+                    #[inline]
+                    fn as_expression(self) -> Self::Expression {
+                        <validators_prelude::String as #diesel_path::expression::AsExpression<#sql_type>>::as_expression(validators_prelude::sql::canonical_text(&self))
+                    }
+                }
+
+                impl #ref_impl_generics #diesel_path::expression::AsExpression<#sql_type> for &'__expr #name #ty_generics #where_clause {
+                    type Expression = <validators_prelude::String as #diesel_path::expression::AsExpression<#sql_type>>::Expression;
+
+                    #[inline]
+                    fn as_expression(self) -> Self::Expression {
+                        <validators_prelude::String as #diesel_path::expression::AsExpression<#sql_type>>::as_expression(validators_prelude::sql::canonical_text(self))
+                    }
+                }
+            }
+        });
+
+    let other_sql_type = match validator.as_str() {
+        "mac_address" => impl_big_int(ast),
+        #[cfg(feature = "diesel_postgres")]
+        "uuid" => impl_postgres_uuid(ast),
+        _ => TokenStream::new(),
+    };
+
+    quote! {
+        impl #to_sql_impl_generics #diesel_path::serialize::ToSql<#text, __DB> for #name #ty_generics #to_sql_where_clause {
+            #[inline]
+            fn to_sql<'__b>(&'__b self, out: &mut #diesel_path::serialize::Output<'__b, '_, __DB>) -> #diesel_path::serialize::Result {
+                let s = validators_prelude::sql::canonical_string(self)?;
+
+                ::std::io::Write::write_all(out, s.as_bytes())?;
+
+                Ok(#diesel_path::serialize::IsNull::No)
+            }
+        }
+
+        impl #from_sql_impl_generics #diesel_path::deserialize::FromSql<#text, __DB> for #name #ty_generics #from_sql_where_clause {
+            #[inline]
+            fn from_sql(bytes: <__DB as #diesel_path::backend::Backend>::RawValue<'_>) -> #diesel_path::deserialize::Result<Self> {
+                let v = <validators_prelude::String as #diesel_path::deserialize::FromSql<#text, __DB>>::from_sql(bytes)?;
+
+                Ok(<Self as ValidateString>::parse_string(v)?)
+            }
+        }
+
+        #(#as_expression)*
+
+        #other_sql_type
+
+        #queryable
+    }
+}
+
+/// Implement `ToSql`, `FromSql` and `AsExpression` of `BigInt` for a MAC address, which is stored as its 48-bit integer.
+fn impl_big_int(ast: &DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let diesel_path = quote! { validators_prelude::diesel };
+    let big_int = quote! { #diesel_path::sql_types::BigInt };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let to_sql_generics = extend_generics(&ast.generics, [parse_quote!(__DB)], [
+        parse_quote!(for<'__c> __DB: #diesel_path::backend::Backend<BindCollector<'__c> = #diesel_path::query_builder::bind_collector::RawBytesBindCollector<__DB>>),
+        parse_quote!(i64: #diesel_path::serialize::ToSql<#big_int, __DB>),
+        parse_quote!(for<'__d> Self: ::core::fmt::Debug),
+    ]);
+    let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+
+    let from_sql_generics = extend_generics(&ast.generics, [parse_quote!(__DB)], [
+        parse_quote!(__DB: #diesel_path::backend::Backend),
+        parse_quote!(i64: #diesel_path::deserialize::FromSql<#big_int, __DB>),
+    ]);
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+    let ref_generics = extend_generics(&ast.generics, [parse_quote!('__expr)], []);
+    let (ref_impl_generics, ..) = ref_generics.split_for_impl();
+
+    let as_expression = [big_int.clone(), quote! { #diesel_path::sql_types::Nullable<#big_int> }]
+        .into_iter()
+        .map(|sql_type| {
+            quote! {
+                impl #impl_generics #diesel_path::expression::AsExpression<#sql_type> for #name #ty_generics #where_clause {
+                    type Expression = <i64 as #diesel_path::expression::AsExpression<#sql_type>>::Expression;
+
+                    #[inline]
+                    fn as_expression(self) -> Self::Expression {
+                        <i64 as #diesel_path::expression::AsExpression<#sql_type>>::as_expression(self.0 as i64)
+                    }
+                }
+
+                impl #ref_impl_generics #diesel_path::expression::AsExpression<#sql_type> for &'__expr #name #ty_generics #where_clause {
+                    type Expression = <i64 as #diesel_path::expression::AsExpression<#sql_type>>::Expression;
+
+                    #[inline]
+                    fn as_expression(self) -> Self::Expression {
+                        <i64 as #diesel_path::expression::AsExpression<#sql_type>>::as_expression(self.0 as i64)
+                    }
+                }
+            }
+        });
+
+    quote! {
+        impl #to_sql_impl_generics #diesel_path::serialize::ToSql<#big_int, __DB> for #name #ty_generics #to_sql_where_clause {
+            #[inline]
+            fn to_sql<'__b>(&'__b self, out: &mut #diesel_path::serialize::Output<'__b, '_, __DB>) -> #diesel_path::serialize::Result {
+                let v = self.0 as i64;
+
+                <i64 as #diesel_path::serialize::ToSql<#big_int, __DB>>::to_sql(&v, &mut out.reborrow())
+            }
+        }
+
+        impl #from_sql_impl_generics #diesel_path::deserialize::FromSql<#big_int, __DB> for #name #ty_generics #from_sql_where_clause {
+            #[inline]
+            fn from_sql(bytes: <__DB as #diesel_path::backend::Backend>::RawValue<'_>) -> #diesel_path::deserialize::Result<Self> {
+                let v = <i64 as #diesel_path::deserialize::FromSql<#big_int, __DB>>::from_sql(bytes)?;
+                let v = validators_prelude::sql::mac_address_from_i64(v)?;
+
+                Ok(<Self as ValidateString>::parse_string(<Self as ToMacAddressString>::to_mac_address_string(&Self(v)))?)
+            }
+        }
+
+        #(#as_expression)*
+    }
+}
+
+/// Implement `ToSql`, `FromSql` and `AsExpression` of the `Uuid` type of Postgres for a UUID, which is stored as its 16 bytes.
+#[cfg(feature = "diesel_postgres")]
+fn impl_postgres_uuid(ast: &DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let diesel_path = quote! { validators_prelude::diesel };
+    let uuid = quote! { #diesel_path::sql_types::Uuid };
+    let pg = quote! { #diesel_path::pg::Pg };
+    let bound = quote! { #diesel_path::internal::derives::as_expression::Bound };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let to_sql_generics =
+        extend_generics(&ast.generics, [], [parse_quote!(for<'__d> Self: ::core::fmt::Debug)]);
+    let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+
+    let ref_generics = extend_generics(&ast.generics, [parse_quote!('__expr)], []);
+    let (ref_impl_generics, ..) = ref_generics.split_for_impl();
+
+    let as_expression = [uuid.clone(), quote! { #diesel_path::sql_types::Nullable<#uuid> }]
+        .into_iter()
+        .map(|sql_type| {
+            quote! {
+                impl #to_sql_impl_generics #diesel_path::serialize::ToSql<#sql_type, #pg> for #name #ty_generics #to_sql_where_clause {
+                    #[inline]
+                    fn to_sql<'__b>(&'__b self, out: &mut #diesel_path::serialize::Output<'__b, '_, #pg>) -> #diesel_path::serialize::Result {
+                        ::std::io::Write::write_all(out, &self.0.to_be_bytes())?;
+
+                        Ok(#diesel_path::serialize::IsNull::No)
+                    }
+                }
+
+                impl #impl_generics #diesel_path::expression::AsExpression<#sql_type> for #name #ty_generics #where_clause {
+                    type Expression = #bound<#sql_type, Self>;
+
+                    #[inline]
+                    fn as_expression(self) -> Self::Expression {
+                        #bound::new(self)
+                    }
+                }
+
+                impl #ref_impl_generics #diesel_path::expression::AsExpression<#sql_type> for &'__expr #name #ty_generics #where_clause {
+                    type Expression = #bound<#sql_type, Self>;
+
+                    #[inline]
+                    fn as_expression(self) -> Self::Expression {
+                        #bound::new(self)
+                    }
+                }
+            }
+        });
+
+    quote! {
+        impl #impl_generics #diesel_path::deserialize::FromSql<#uuid, #pg> for #name #ty_generics #where_clause {
+            #[inline]
+            fn from_sql(bytes: <#pg as #diesel_path::backend::Backend>::RawValue<'_>) -> #diesel_path::deserialize::Result<Self> {
+                let bytes = <[u8; 16] as ::core::convert::TryFrom<&[u8]>>::try_from(bytes.as_bytes())?;
+
+                Ok(<Self as ValidateString>::parse_string(<Self as ToUuidString>::to_uuid_string(&Self(u128::from_be_bytes(bytes))))?)
+            }
+        }
+
+        #(#as_expression)*
+    }
+}
+
+/// Add generic parameters and `where` predicates to the generics of the struct. Lifetimes are put before the other parameters.
+fn extend_generics<const P: usize, const W: usize>(
+    generics: &Generics,
+    params: [GenericParam; P],
+    predicates: [WherePredicate; W],
+) -> Generics {
+    let mut generics = generics.clone();
+
+    for param in params {
+        match param {
+            GenericParam::Lifetime(_) => generics.params.insert(0, param),
+            _ => generics.params.push(param),
+        }
+    }
+
+    generics.make_where_clause().predicates.extend(predicates);
+
+    generics
+}
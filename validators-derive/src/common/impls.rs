@@ -0,0 +1,289 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Fields, GenericArgument, Ident,
+    Meta, Path, PathArguments, Token, Type,
+};
+
+use crate::{
+    common::{
+        borrowed::{borrowed_type, is_str, BorrowedType},
+        path_to_string,
+    },
+    panic,
+};
+
+/// The validators whose structs implement a trait giving the canonical string, and the method of the trait.
+const TO_STRING_METHODS: [(&str, &str); 8] = [
+    ("domain", "to_uri_authority_string"),
+    ("email", "to_email_string"),
+    ("host", "to_uri_authority_string"),
+    ("ip", "to_uri_authority_string"),
+    ("ipv4", "to_uri_authority_string"),
+    ("ipv6", "to_uri_authority_string"),
+    ("mac_address", "to_mac_address_string"),
+    ("uuid", "to_uuid_string"),
+];
+
+#[derive(Debug, Default)]
+pub(crate) struct ImplsOption {
+    pub(crate) display:  bool,
+    pub(crate) from_str: bool,
+    pub(crate) try_from: bool,
+    pub(crate) as_ref:   bool,
+    pub(crate) deref:    bool,
+    pub(crate) borrow:   bool,
+    pub(crate) span:     Option<Span>,
+}
+
+impl ImplsOption {
+    #[inline]
+    pub(crate) fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        debug_assert!(meta.path().is_ident("impls"));
+
+        let list = match meta {
+            Meta::List(list) => list,
+            _ => {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "expected `impls(Display, FromStr, TryFrom, AsRef, Deref, Borrow)`",
+                ))
+            },
+        };
+
+        let result =
+            list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_separated_nonempty)?;
+
+        let mut option = Self {
+            span: Some(meta.path().span()),
+            ..Self::default()
+        };
+
+        for p in result {
+            let b = match p.to_string().as_str() {
+                "Display" => &mut option.display,
+                "FromStr" => &mut option.from_str,
+                "TryFrom" => &mut option.try_from,
+                "AsRef" => &mut option.as_ref,
+                "Deref" => &mut option.deref,
+                "Borrow" => &mut option.borrow,
+                _ => {
+                    return Err(syn::Error::new(
+                        p.span(),
+                        "expected Display/FromStr/TryFrom/AsRef/Deref/Borrow",
+                    ));
+                },
+            };
+
+            if *b {
+                return Err(panic::parameter_reset(&p));
+            }
+
+            *b = true;
+        }
+
+        Ok(option)
+    }
+
+    /// Implement the standard traits for the struct by the traits of `validators` which the handler of `validator` implements.
+    pub(crate) fn impl_traits(
+        &self,
+        ast: &DeriveInput,
+        validator: &Path,
+    ) -> syn::Result<TokenStream> {
+        let span = self.span.unwrap_or_else(Span::call_site);
+
+        let name = &ast.ident;
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let validator = path_to_string(validator);
+        let borrowed_type = borrowed_type(ast);
+
+        let field = match &ast.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(&fields.unnamed[0].ty),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let mut output = TokenStream::new();
+
+        if self.display {
+            // the structs which borrow their strings only implement `ValidateBorrowedStr` and `ValidateString`
+            let to_string_method = match borrowed_type {
+                Some(_) => None,
+                None => TO_STRING_METHODS.iter().find(|(name, _)| *name == validator),
+            };
+
+            let body = match to_string_method {
+                Some((_, method)) => {
+                    let method = format_ident!("{}", method);
+
+                    quote! { f.write_str(&self.#method()) }
+                },
+                None => {
+                    if field.is_none() {
+                        return Err(panic::impls_need_single_field(span, "Display"));
+                    }
+
+                    quote! { ::core::fmt::Display::fmt(&self.0, f) }
+                },
+            };
+
+            output.extend(quote! {
+                impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        #body
+                    }
+                }
+            });
+        }
+
+        let has_validate_string = validator != "length" && borrowed_type != Some(BorrowedType::Str);
+        let has_validate_borrowed_str = borrowed_type.is_some();
+
+        if self.from_str {
+            if !has_validate_string {
+                return Err(panic::impls_need_validate_string(span, "FromStr"));
+            }
+
+            output.extend(quote! {
+                impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                    type Err = <Self as ValidateString>::Error;
+
+                    #[inline]
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        <Self as ValidateString>::parse_str(s)
+                    }
+                }
+            });
+        }
+
+        if self.try_from {
+            if !has_validate_string && !has_validate_borrowed_str {
+                return Err(panic::impls_need_validate_string(span, "TryFrom"));
+            }
+
+            if has_validate_string {
+                output.extend(quote! {
+                    impl #impl_generics ::core::convert::TryFrom<validators_prelude::String> for #name #ty_generics #where_clause {
+                        type Error = <Self as ValidateString>::Error;
+
+                        #[inline]
+                        fn try_from(s: validators_prelude::String) -> Result<Self, Self::Error> {
+                            <Self as ValidateString>::parse_string(s)
+                        }
+                    }
+                });
+            }
+
+            if has_validate_borrowed_str {
+                let lifetime = &ast.generics.lifetimes().next().unwrap().lifetime;
+
+                output.extend(quote! {
+                    impl #impl_generics ::core::convert::TryFrom<&#lifetime str> for #name #ty_generics #where_clause {
+                        type Error = <Self as ValidateBorrowedStr<#lifetime>>::Error;
+
+                        #[inline]
+                        fn try_from(s: &#lifetime str) -> Result<Self, Self::Error> {
+                            <Self as ValidateBorrowedStr<#lifetime>>::parse_borrowed(s)
+                        }
+                    }
+                });
+            } else {
+                output.extend(quote! {
+                    impl #impl_generics ::core::convert::TryFrom<&str> for #name #ty_generics #where_clause {
+                        type Error = <Self as ValidateString>::Error;
+
+                        #[inline]
+                        fn try_from(s: &str) -> Result<Self, Self::Error> {
+                            <Self as ValidateString>::parse_str(s)
+                        }
+                    }
+                });
+            }
+        }
+
+        for (enabled, trait_name) in
+            [(self.as_ref, "AsRef"), (self.deref, "Deref"), (self.borrow, "Borrow")]
+        {
+            if !enabled {
+                continue;
+            }
+
+            let field = match field {
+                Some(field) => field,
+                None => return Err(panic::impls_need_single_field(span, trait_name)),
+            };
+
+            let target = if is_string(field) {
+                quote! { str }
+            } else {
+                quote! { #field }
+            };
+
+            output.extend(match trait_name {
+                "AsRef" => quote! {
+                    impl #impl_generics ::core::convert::AsRef<#target> for #name #ty_generics #where_clause {
+                        #[inline]
+                        fn as_ref(&self) -> &#target {
+                            &self.0
+                        }
+                    }
+                },
+                "Deref" => quote! {
+                    impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+                        type Target = #target;
+
+                        #[inline]
+                        fn deref(&self) -> &Self::Target {
+                            &self.0
+                        }
+                    }
+                },
+                _ => quote! {
+                    impl #impl_generics ::core::borrow::Borrow<#target> for #name #ty_generics #where_clause {
+                        #[inline]
+                        fn borrow(&self) -> &#target {
+                            &self.0
+                        }
+                    }
+                },
+            });
+        }
+
+        Ok(output)
+    }
+}
+
+/// Whether the type is `String`, `&str` or `Cow<str>`, which can be seen as `str`.
+fn is_string(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_str(&reference.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = match path.path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+
+            if segment.ident == "String" {
+                return segment.arguments.is_empty();
+            }
+
+            if segment.ident != "Cow" {
+                return false;
+            }
+
+            match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => arguments
+                    .args
+                    .iter()
+                    .any(|argument| matches!(argument, GenericArgument::Type(ty) if is_str(ty))),
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
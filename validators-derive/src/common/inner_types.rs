@@ -0,0 +1,44 @@
+use syn::{punctuated::Punctuated, Data, DeriveInput, Fields, Meta, Path, Token, Type};
+
+use crate::common::path_to_string;
+
+/// The types whose schemas are needed to create the schema of the struct.
+pub(crate) enum InnerTypes<'a> {
+    /// The collection validated by the `length` validator.
+    Collection(&'a Type),
+    /// The validators combined by the `all`, `any` and `not` validators.
+    Validators(Vec<Path>),
+    None,
+}
+
+pub(crate) fn inner_types<'a>(ast: &'a DeriveInput, meta: &Meta) -> InnerTypes<'a> {
+    match path_to_string(meta.path()).as_str() {
+        "length" => match &ast.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    InnerTypes::Collection(&fields.unnamed[0].ty)
+                },
+                _ => InnerTypes::None,
+            },
+            _ => InnerTypes::None,
+        },
+        "all" | "any" | "not" => match meta {
+            Meta::List(list) => list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .map(|metas| {
+                    InnerTypes::Validators(
+                        metas
+                            .into_iter()
+                            .filter_map(|meta| match meta {
+                                Meta::Path(path) => Some(path),
+                                _ => None,
+                            })
+                            .collect(),
+                    )
+                })
+                .unwrap_or(InnerTypes::None),
+            _ => InnerTypes::None,
+        },
+        _ => InnerTypes::None,
+    }
+}
@@ -0,0 +1,35 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use crate::common::inner_types::{inner_types, InnerTypes};
+
+/// Implement `JsonSchema` of `schemars` for the struct by its `ValidatorSpec`.
+pub(crate) fn impl_json_schema(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let inner: Vec<TokenStream> = match inner_types(ast, meta) {
+        InnerTypes::Collection(ty) => {
+            vec![quote! { <#ty as validators_prelude::schemars::JsonSchema>::json_schema(gen) }]
+        },
+        InnerTypes::Validators(paths) => {
+            paths.iter().map(|path| quote! { gen.subschema_for::<#path>() }).collect()
+        },
+        InnerTypes::None => Vec::new(),
+    };
+
+    quote! {
+        impl #impl_generics validators_prelude::schemars::JsonSchema for #name #ty_generics #where_clause {
+            #[inline]
+            fn schema_name() -> validators_prelude::String {
+                validators_prelude::String::from(stringify!(#name))
+            }
+
+            #[inline]
+            fn json_schema(gen: &mut validators_prelude::schemars::gen::SchemaGenerator) -> validators_prelude::schemars::schema::Schema {
+                <Self as ValidatorSpec>::SPEC.to_json_schema(validators_prelude::Vec::from([#(#inner),*]))
+            }
+        }
+    }
+}
@@ -1,10 +1,43 @@
+#[cfg(feature = "actix")]
+pub(crate) mod actix;
+#[allow(dead_code)]
+pub(crate) mod actix_options;
+#[cfg(feature = "arbitrary")]
+pub(crate) mod arbitrary;
 #[allow(dead_code)]
 pub(crate) mod attributes;
+#[cfg(feature = "axum")]
+pub(crate) mod axum;
+#[allow(dead_code)]
+pub(crate) mod axum_options;
+pub(crate) mod borrowed;
+#[cfg(feature = "clap")]
+pub(crate) mod clap;
+#[allow(dead_code)]
+pub(crate) mod custom_option;
+#[cfg(feature = "diesel")]
+pub(crate) mod diesel;
+pub(crate) mod impls;
+#[cfg(any(feature = "schemars", feature = "utoipa", feature = "proptest", feature = "arbitrary"))]
+#[allow(dead_code)]
+pub(crate) mod inner_types;
+#[cfg(feature = "schemars")]
+pub(crate) mod json_schema;
+pub(crate) mod normalize_option;
+#[cfg(feature = "utoipa")]
+pub(crate) mod openapi_schema;
 #[allow(dead_code)]
 pub(crate) mod rocket_options;
 #[allow(dead_code)]
 pub(crate) mod serde_options;
 #[allow(dead_code)]
+pub(crate) mod spec;
+#[cfg(feature = "sqlx")]
+pub(crate) mod sqlx;
+#[cfg(feature = "proptest")]
+pub(crate) mod strategy;
+pub(crate) mod trait_options;
+#[allow(dead_code)]
 pub(crate) mod type_enum;
 
 #[cfg(any(
@@ -12,6 +45,7 @@ pub(crate) mod type_enum;
     feature = "domain",
     feature = "email",
     feature = "number",
+    feature = "path",
     feature = "signed_integer",
     feature = "unsigned_integer",
 ))]
@@ -40,6 +74,7 @@ pub(crate) mod length;
     feature = "length",
     feature = "line",
     feature = "number",
+    feature = "path",
     feature = "signed_integer",
     feature = "unsigned_integer",
     feature = "text",
@@ -84,6 +119,7 @@ pub(crate) mod separator_option;
     feature = "ipv4",
     feature = "ipv6",
     feature = "number",
+    feature = "path",
     feature = "signed_integer",
     feature = "unsigned_integer",
 ))]
@@ -94,9 +130,6 @@ pub(crate) mod tri_allow;
 #[allow(dead_code)]
 pub(crate) mod rocket;
 
-#[cfg(feature = "test")]
-pub(crate) mod test;
-
 use quote::ToTokens;
 use syn::Path;
 
@@ -104,3 +137,10 @@ use syn::Path;
 pub(crate) fn path_to_string(path: &Path) -> String {
     path.into_token_stream().to_string().replace(' ', "")
 }
+
+/// Whether the struct can be parsed from an owned string, which the `FromRequest`, `FromRequestParts` and `ValueParserFactory` implementations need. Structs holding borrowed strings cannot be, and neither can the collections of the `length` validator.
+#[cfg(any(feature = "actix", feature = "axum", feature = "clap"))]
+#[inline]
+pub(crate) fn parses_owned_string(ast: &syn::DeriveInput, meta: &syn::Meta) -> bool {
+    borrowed::borrowed_type(ast).is_none() && path_to_string(meta.path()) != "length"
+}
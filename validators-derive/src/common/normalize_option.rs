@@ -0,0 +1,166 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{punctuated::Punctuated, spanned::Spanned, Ident, Meta, Token};
+
+use crate::panic;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum NormalizationForm {
+    NFC,
+    NFKC,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct NormalizeOption {
+    pub(crate) trim:                bool,
+    pub(crate) collapse_whitespace: bool,
+    pub(crate) lowercase:           bool,
+    pub(crate) form:                Option<NormalizationForm>,
+    pub(crate) strip_zero_width:    bool,
+    /// Only read by the validators which support borrowed strings, to reject `normalize` for them.
+    #[cfg_attr(
+        not(any(
+            feature = "base32",
+            feature = "base64",
+            feature = "base64_url",
+            feature = "domain",
+            feature = "line",
+            feature = "regex",
+            feature = "text",
+            feature = "uuid"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) span:                Option<Span>,
+}
+
+impl NormalizeOption {
+    #[inline]
+    pub(crate) fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        debug_assert!(meta.path().is_ident("normalize"));
+
+        let list = match meta {
+            Meta::List(list) => list,
+            _ => {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "expected `normalize(trim, collapse_whitespace, lowercase, nfc, \
+                     strip_zero_width)`",
+                ))
+            },
+        };
+
+        let result =
+            list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_separated_nonempty)?;
+
+        let mut option = Self {
+            span: Some(meta.path().span()),
+            ..Self::default()
+        };
+
+        for p in result {
+            let b = match p.to_string().as_str() {
+                "trim" => &mut option.trim,
+                "collapse_whitespace" => &mut option.collapse_whitespace,
+                "lowercase" => &mut option.lowercase,
+                "strip_zero_width" => &mut option.strip_zero_width,
+                "nfc" | "nfkc" => {
+                    if option.form.is_some() {
+                        return Err(syn::Error::new(
+                            p.span(),
+                            "`nfc` and `nfkc` cannot be used together",
+                        ));
+                    }
+
+                    option.form = Some(if p == "nfc" {
+                        NormalizationForm::NFC
+                    } else {
+                        NormalizationForm::NFKC
+                    });
+
+                    continue;
+                },
+                _ => {
+                    return Err(syn::Error::new(
+                        p.span(),
+                        "expected trim/collapse_whitespace/lowercase/nfc/nfkc/strip_zero_width",
+                    ));
+                },
+            };
+
+            if *b {
+                return Err(panic::parameter_reset(&p));
+            }
+
+            *b = true;
+        }
+
+        if cfg!(feature = "normalize") {
+            Ok(option)
+        } else {
+            Err(syn::Error::new(meta.path().span(), "the `normalize` feature is not enabled"))
+        }
+    }
+}
+
+/// Shadow the input `s` of `ValidateString::parse_string` with the normalized string, if there is the `normalize` parameter.
+#[inline]
+pub(crate) fn normalize_string(normalize: Option<&NormalizeOption>) -> TokenStream {
+    match normalize {
+        Some(normalize) => quote! {
+            let s = validators_prelude::normalize_into_string(s, #normalize);
+        },
+        None => quote! {},
+    }
+}
+
+/// Like `normalize_string`, for `ValidateString::parse_str` and `ValidateString::validate_str`.
+#[inline]
+pub(crate) fn normalize_str(normalize: Option<&NormalizeOption>) -> TokenStream {
+    match normalize {
+        Some(normalize) => quote! {
+            let s = validators_prelude::normalize_as_ref_str(&s, #normalize);
+        },
+        None => quote! {},
+    }
+}
+
+impl ToTokens for NormalizationForm {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::NFC => {
+                token_stream.extend(quote! { validators_prelude::NormalizationForm::NFC });
+            },
+            Self::NFKC => {
+                token_stream.extend(quote! { validators_prelude::NormalizationForm::NFKC });
+            },
+        }
+    }
+}
+
+impl ToTokens for NormalizeOption {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        let trim = self.trim;
+        let collapse_whitespace = self.collapse_whitespace;
+        let lowercase = self.lowercase;
+        let strip_zero_width = self.strip_zero_width;
+
+        let form = match self.form {
+            Some(form) => quote! { Some(#form) },
+            None => quote! { None },
+        };
+
+        token_stream.extend(quote! {
+            validators_prelude::NormalizeOption {
+                trim: #trim,
+                collapse_whitespace: #collapse_whitespace,
+                lowercase: #lowercase,
+                form: #form,
+                strip_zero_width: #strip_zero_width,
+            }
+        });
+    }
+}
@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use crate::common::inner_types::{inner_types, InnerTypes};
+
+/// Implement `PartialSchema` and `ToSchema` of `utoipa` for the struct by its `ValidatorSpec`.
+pub(crate) fn impl_openapi_schema(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let (inner, schemas): (Vec<TokenStream>, Vec<TokenStream>) = match inner_types(ast, meta) {
+        InnerTypes::Collection(ty) => (
+            vec![quote! { <#ty as validators_prelude::utoipa::PartialSchema>::schema() }],
+            vec![quote! { <#ty as validators_prelude::utoipa::ToSchema>::schemas(schemas); }],
+        ),
+        InnerTypes::Validators(paths) => paths
+            .iter()
+            .map(|path| {
+                (
+                    quote! {
+                        validators_prelude::utoipa::openapi::RefOr::Ref(
+                            validators_prelude::utoipa::openapi::Ref::from_schema_name(<#path as validators_prelude::utoipa::ToSchema>::name())
+                        )
+                    },
+                    quote! {
+                        schemas.push((
+                            validators_prelude::String::from(<#path as validators_prelude::utoipa::ToSchema>::name()),
+                            <#path as validators_prelude::utoipa::PartialSchema>::schema(),
+                        ));
+                        <#path as validators_prelude::utoipa::ToSchema>::schemas(schemas);
+                    },
+                )
+            })
+            .unzip(),
+        InnerTypes::None => (Vec::new(), Vec::new()),
+    };
+
+    let impl_schemas = if schemas.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            #[inline]
+            fn schemas(
+                schemas: &mut validators_prelude::Vec<(
+                    validators_prelude::String,
+                    validators_prelude::utoipa::openapi::RefOr<validators_prelude::utoipa::openapi::schema::Schema>,
+                )>,
+            ) {
+                #(#schemas)*
+            }
+        }
+    };
+
+    quote! {
+        impl #impl_generics validators_prelude::utoipa::PartialSchema for #name #ty_generics #where_clause {
+            #[inline]
+            fn schema() -> validators_prelude::utoipa::openapi::RefOr<validators_prelude::utoipa::openapi::schema::Schema> {
+                <Self as ValidatorSpec>::SPEC.to_openapi_schema(validators_prelude::Vec::from([#(#inner),*]))
+            }
+        }
+
+        impl #impl_generics validators_prelude::utoipa::ToSchema for #name #ty_generics #where_clause {
+            #[inline]
+            fn name() -> validators_prelude::Cow<'static, str> {
+                validators_prelude::Cow::Borrowed(stringify!(#name))
+            }
+
+            #impl_schemas
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::{fmt::Display, ops::Add, str::FromStr};
 
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::{punctuated::Punctuated, spanned::Spanned, Meta, Token};
 #[cfg(feature = "full")]
 use syn::{Expr, ExprRange, RangeLimits};
@@ -8,7 +8,7 @@ use syn::{Expr, ExprRange, RangeLimits};
 #[cfg(feature = "full")]
 use crate::common::number::expr_lit_2_number;
 use crate::{
-    common::{boolean::meta_2_bool, number::meta_2_number},
+    common::{boolean::meta_2_bool, number::meta_2_number, spec::OptionToken},
     panic,
 };
 
@@ -256,3 +256,20 @@ pub(crate) fn range_equal<T: RangedNumber>(
         false
     }
 }
+
+impl ToTokens for Range<u128> {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        let min = OptionToken(self.min.as_ref());
+        let max = OptionToken(self.max.as_ref());
+        let inclusive = self.inclusive;
+
+        token_stream.extend(quote! {
+            validators_prelude::RangeOption::Inside {
+                min: #min,
+                max: #max,
+                inclusive: #inclusive,
+            }
+        });
+    }
+}
@@ -1,10 +1,12 @@
 use std::{fmt::Display, str::FromStr};
 
+use quote::{quote, ToTokens};
 use syn::{spanned::Spanned, Meta};
 
 use crate::common::{
     path_to_string,
     range::{range_equal, Range, RangedNumber},
+    spec::OptionToken,
 };
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -126,3 +128,94 @@ where
         }
     }
 }
+
+impl RangeTokenStream {
+    /// Convert to the tokens of `RangeOption<#ty>`, for `ValidatorSpec`.
+    pub(crate) fn to_spec_tokens(&self, ty: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let cast = |v: &Option<proc_macro2::TokenStream>| match v {
+            Some(v) => quote! { Some(#v as #ty) },
+            None => quote! { None },
+        };
+
+        match self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+                equal: _,
+            } => {
+                let (min, max) = (cast(min), cast(max));
+
+                quote! {
+                    validators_prelude::RangeOption::Inside {
+                        min: #min,
+                        max: #max,
+                        inclusive: #inclusive,
+                    }
+                }
+            },
+            Self::Outside {
+                min,
+                max,
+                inclusive,
+                equal: _,
+            } => {
+                let (min, max) = (cast(min), cast(max));
+
+                quote! {
+                    validators_prelude::RangeOption::Outside {
+                        min: #min,
+                        max: #max,
+                        inclusive: #inclusive,
+                    }
+                }
+            },
+            Self::Unlimited => quote! { validators_prelude::RangeOption::Unlimited },
+        }
+    }
+}
+
+impl ToTokens for RangeTokenStream {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+                equal: _,
+            } => {
+                let min = OptionToken(min.as_ref());
+                let max = OptionToken(max.as_ref());
+
+                token_stream.extend(quote! {
+                    validators_prelude::RangeOption::Inside {
+                        min: #min,
+                        max: #max,
+                        inclusive: #inclusive,
+                    }
+                });
+            },
+            Self::Outside {
+                min,
+                max,
+                inclusive,
+                equal: _,
+            } => {
+                let min = OptionToken(min.as_ref());
+                let max = OptionToken(max.as_ref());
+
+                token_stream.extend(quote! {
+                    validators_prelude::RangeOption::Outside {
+                        min: #min,
+                        max: #max,
+                        inclusive: #inclusive,
+                    }
+                });
+            },
+            Self::Unlimited => {
+                token_stream.extend(quote! { validators_prelude::RangeOption::Unlimited });
+            },
+        }
+    }
+}
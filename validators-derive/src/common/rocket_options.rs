@@ -62,6 +62,7 @@ impl RocketOptions {
                 let mut from_form_field = false;
                 let mut from_param = false;
 
+                #[cfg_attr(not(feature = "rocket"), allow(clippy::never_loop))]
                 for p in result {
                     match p.to_string().as_str() {
                         "FromFormField" => {
@@ -1,3 +1,4 @@
+use quote::{quote, ToTokens};
 use syn::{spanned::Spanned, Expr, Lit, LitByte, Meta};
 
 use crate::common::path_to_string;
@@ -103,3 +104,20 @@ impl SeparatorOption {
         ))
     }
 }
+
+impl ToTokens for SeparatorOption {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Must(c) => {
+                token_stream.extend(quote! { validators_prelude::SeparatorOption::Must(#c) });
+            },
+            Self::Allow(c) => {
+                token_stream.extend(quote! { validators_prelude::SeparatorOption::Allow(#c) });
+            },
+            Self::Disallow => {
+                token_stream.extend(quote! { validators_prelude::SeparatorOption::Disallow });
+            },
+        }
+    }
+}
@@ -61,6 +61,7 @@ impl SerdeOptions {
                 let mut serialize = false;
                 let mut deserialize = false;
 
+                #[cfg_attr(not(feature = "serde"), allow(clippy::never_loop))]
                 for p in result {
                     match p.to_string().as_str() {
                         "Serialize" => {
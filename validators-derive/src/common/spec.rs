@@ -0,0 +1,45 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{Generics, Ident};
+
+pub(crate) struct OptionToken<T: ToTokens>(pub(crate) Option<T>);
+
+impl<T: ToTokens> ToTokens for OptionToken<T> {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        match &self.0 {
+            Some(t) => token_stream.extend(quote! { Some(#t) }),
+            None => token_stream.extend(quote! { None }),
+        }
+    }
+}
+
+/// Create a `SpecValue` of the `validators` crate. `variant` is the name of the variant of `SpecValue`.
+#[inline]
+pub(crate) fn spec_value(variant: &str, value: impl ToTokens) -> TokenStream {
+    let variant = format_ident!("{}", variant);
+
+    quote! { validators_prelude::SpecValue::#variant(#value) }
+}
+
+/// Implement `ValidatorSpec` for the struct by the name of the validator and its options, which are created by `spec_value`.
+pub(crate) fn impl_validator_spec(
+    token_stream: &mut TokenStream,
+    name: &Ident,
+    generics: &Generics,
+    validator: &str,
+    options: &[(&str, TokenStream)],
+) {
+    let options = options.iter().map(|(option, value)| quote! { (#option, #value) });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    token_stream.extend(quote! {
+        impl #impl_generics ValidatorSpec for #name #ty_generics #where_clause {
+            const SPEC: validators_prelude::Spec = validators_prelude::Spec {
+                validator: #validator,
+                options: &[#(#options),*],
+            };
+        }
+    });
+}
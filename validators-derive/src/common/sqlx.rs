@@ -0,0 +1,144 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, Meta};
+
+use crate::common::{borrowed::borrowed_type, path_to_string};
+
+/// Implement `Type`, `Encode` and `Decode` of `sqlx` for the struct. Integers, numbers and booleans are stored as their inner values, MAC addresses as `i64`, UUIDs as `sqlx::types::Uuid` if the `sqlx_uuid` feature is enabled, and the other validated values as the strings which they are serialized to. Decoded values are validated again. Structs holding borrowed strings, collections and decoded bytes are skipped.
+pub(crate) fn impl_sqlx(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    if borrowed_type(ast).is_some() {
+        return TokenStream::new();
+    }
+
+    let inner_type = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(&fields.unnamed[0].ty),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let sqlx_path = quote! { validators_prelude::sqlx };
+
+    let (sql_type, encode, decode) = match (path_to_string(meta.path()).as_str(), inner_type) {
+        ("length" | "base32_decoded" | "base64_decoded" | "base64_url_decoded", _) => {
+            return TokenStream::new();
+        },
+        ("boolean", _) => (
+            quote! { bool },
+            quote! { encode_by_ref(&self.0, buf) },
+            quote! { <Self as ValidateBoolean>::parse_bool(v) },
+        ),
+        ("unsigned_integer", Some(ty)) => (
+            quote! { #ty },
+            quote! { encode_by_ref(&self.0, buf) },
+            quote! { <Self as ValidateUnsignedInteger>::parse_u128(v as u128) },
+        ),
+        ("signed_integer", Some(ty)) => (
+            quote! { #ty },
+            quote! { encode_by_ref(&self.0, buf) },
+            quote! { <Self as ValidateSignedInteger>::parse_i128(v as i128) },
+        ),
+        ("number", Some(ty)) => (
+            quote! { #ty },
+            quote! { encode_by_ref(&self.0, buf) },
+            quote! { <Self as ValidateNumber>::parse_f64(v as f64) },
+        ),
+        ("mac_address", _) => (quote! { i64 }, quote! { encode(self.0 as i64, buf) }, quote! {
+            {
+                let v = validators_prelude::sql::mac_address_from_i64(v)?;
+
+                <Self as ValidateString>::parse_string(
+                    <Self as ToMacAddressString>::to_mac_address_string(&Self(v)),
+                )
+            }
+        }),
+        #[cfg(feature = "sqlx_uuid")]
+        ("uuid", _) => (
+            quote! { #sqlx_path::types::Uuid },
+            quote! { encode(#sqlx_path::types::Uuid::from_u128(self.0), buf) },
+            quote! {
+                <Self as ValidateString>::parse_string(
+                    <Self as ToUuidString>::to_uuid_string(&Self(v.as_u128())),
+                )
+            },
+        ),
+        _ => (
+            quote! { validators_prelude::String },
+            quote! { encode(validators_prelude::sql::canonical_string(self)?, buf) },
+            quote! { <Self as ValidateString>::parse_string(v) },
+        ),
+    };
+
+    let name = &ast.ident;
+
+    let mut type_generics = ast.generics.clone();
+    type_generics.params.push(parse_quote!(__DB: #sqlx_path::Database));
+
+    let mut encode_generics = type_generics.clone();
+    encode_generics.params.insert(0, parse_quote!('__q));
+
+    let mut decode_generics = type_generics.clone();
+    decode_generics.params.insert(0, parse_quote!('__r));
+
+    let (type_impl_generics, ..) = type_generics.split_for_impl();
+    let (encode_impl_generics, ..) = encode_generics.split_for_impl();
+    let (decode_impl_generics, ..) = decode_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let type_where_clause =
+        extend_where_clause(where_clause, quote! { #sql_type: #sqlx_path::Type<__DB> });
+    let encode_where_clause =
+        extend_where_clause(where_clause, quote! { #sql_type: #sqlx_path::Encode<'__q, __DB> });
+    let decode_where_clause =
+        extend_where_clause(where_clause, quote! { #sql_type: #sqlx_path::Decode<'__r, __DB> });
+
+    quote! {
+        impl #type_impl_generics #sqlx_path::Type<__DB> for #name #ty_generics #type_where_clause {
+            #[inline]
+            fn type_info() -> <__DB as #sqlx_path::Database>::TypeInfo {
+                <#sql_type as #sqlx_path::Type<__DB>>::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &<__DB as #sqlx_path::Database>::TypeInfo) -> bool {
+                <#sql_type as #sqlx_path::Type<__DB>>::compatible(ty)
+            }
+        }
+
+        impl #encode_impl_generics #sqlx_path::Encode<'__q, __DB> for #name #ty_generics #encode_where_clause {
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                buf: &mut <__DB as #sqlx_path::Database>::ArgumentBuffer<'__q>,
+            ) -> Result<#sqlx_path::encode::IsNull, #sqlx_path::error::BoxDynError> {
+                <#sql_type as #sqlx_path::Encode<'__q, __DB>>::#encode
+            }
+        }
+
+        impl #decode_impl_generics #sqlx_path::Decode<'__r, __DB> for #name #ty_generics #decode_where_clause {
+            #[inline]
+            fn decode(
+                value: <__DB as #sqlx_path::Database>::ValueRef<'__r>,
+            ) -> Result<Self, #sqlx_path::error::BoxDynError> {
+                let v = <#sql_type as #sqlx_path::Decode<'__r, __DB>>::decode(value)?;
+
+                Ok(#decode?)
+            }
+        }
+    }
+}
+
+fn extend_where_clause(
+    where_clause: Option<&syn::WhereClause>,
+    predicate: TokenStream,
+) -> TokenStream {
+    match where_clause {
+        Some(where_clause) if !where_clause.predicates.is_empty() => {
+            let predicates = &where_clause.predicates;
+
+            quote! { where #predicates, #predicate }
+        },
+        _ => quote! { where #predicate },
+    }
+}
@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, Meta};
+
+use crate::common::{
+    inner_types::{inner_types, InnerTypes},
+    path_to_string,
+};
+
+/// Implement `ValidatorStrategy` by the `ValidatorSpec` of the struct and `Arbitrary` of `proptest` by filtering the generated values with the validator. Structs holding borrowed strings are skipped because generated values must be owned.
+pub(crate) fn impl_strategy(ast: &DeriveInput, meta: &Meta) -> TokenStream {
+    if crate::common::borrowed::borrowed_type(ast).is_some() {
+        return TokenStream::new();
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    // `Arbitrary` requires `Debug`, which is not required by the `Validator` derive, so the bound is made higher-ranked to avoid a compile error when it does not hold
+    let mut arbitrary_where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+
+    arbitrary_where_clause
+        .predicates
+        .push(parse_quote!(for<'__validators> #name #ty_generics: ::core::fmt::Debug));
+
+    let arbitrary_path = quote! { validators_prelude::proptest::arbitrary };
+    let strategy_path = quote! { validators_prelude::proptest::strategy };
+
+    let inner_types = inner_types(ast, meta);
+
+    let (valid_inner, invalid_inner): (Vec<TokenStream>, Vec<TokenStream>) = match inner_types {
+        InnerTypes::Collection(ty) => {
+            arbitrary_where_clause
+                .predicates
+                .push(parse_quote!(for<'__validators> #ty: #arbitrary_path::Arbitrary));
+
+            return quote! {
+                impl #impl_generics #arbitrary_path::Arbitrary for #name #ty_generics #arbitrary_where_clause {
+                    type Parameters = ();
+                    type Strategy = #strategy_path::BoxedStrategy<Self>;
+
+                    #[inline]
+                    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                        #strategy_path::Strategy::boxed(#strategy_path::Strategy::prop_filter_map(
+                            #arbitrary_path::any::<#ty>(),
+                            "rejected by the validator",
+                            |v| <Self as ValidateLength<#ty>>::parse_collection(v).ok(),
+                        ))
+                    }
+                }
+            };
+        },
+        InnerTypes::Validators(paths) => {
+            let valid =
+                paths.iter().map(|path| quote! { <#path as ValidatorStrategy>::valid_strings() });
+            let invalid =
+                paths.iter().map(|path| quote! { <#path as ValidatorStrategy>::invalid_strings() });
+
+            // strings rejected by the inner validator are accepted by `not`, and vice versa
+            if path_to_string(meta.path()) == "not" {
+                (invalid.collect(), valid.collect())
+            } else {
+                (valid.collect(), invalid.collect())
+            }
+        },
+        InnerTypes::None => match path_to_string(meta.path()).as_str() {
+            "number" | "signed_integer" | "unsigned_integer" => match &ast.data {
+                Data::Struct(data) => match &data.fields {
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        let ty = &fields.unnamed[0].ty;
+
+                        let values = quote! {
+                            #strategy_path::Strategy::boxed(#strategy_path::Strategy::prop_map(
+                                #arbitrary_path::any::<#ty>(),
+                                |v| validators_prelude::format!("{}", v),
+                            ))
+                        };
+
+                        (vec![values.clone()], vec![values])
+                    },
+                    _ => (Vec::new(), Vec::new()),
+                },
+                _ => (Vec::new(), Vec::new()),
+            },
+            _ => (Vec::new(), Vec::new()),
+        },
+    };
+
+    quote! {
+        impl #impl_generics ValidatorStrategy for #name #ty_generics #where_clause {
+            #[inline]
+            fn valid_strings() -> #strategy_path::BoxedStrategy<validators_prelude::String> {
+                #strategy_path::Strategy::boxed(#strategy_path::Strategy::prop_filter(
+                    <Self as ValidatorSpec>::SPEC.valid_strings(validators_prelude::Vec::from([#(#valid_inner),*])),
+                    "rejected by the validator",
+                    |s| <Self as ValidateString>::validate_str(s).is_ok(),
+                ))
+            }
+
+            #[inline]
+            fn invalid_strings() -> #strategy_path::BoxedStrategy<validators_prelude::String> {
+                #strategy_path::Strategy::boxed(#strategy_path::Strategy::prop_filter(
+                    <Self as ValidatorSpec>::SPEC.invalid_strings(
+                        <Self as ValidatorStrategy>::valid_strings(),
+                        validators_prelude::Vec::from([#(#invalid_inner),*]),
+                    ),
+                    "accepted by the validator",
+                    |s| <Self as ValidateString>::validate_str(s).is_err(),
+                ))
+            }
+        }
+
+        impl #impl_generics #arbitrary_path::Arbitrary for #name #ty_generics #arbitrary_where_clause {
+            type Parameters = ();
+            type Strategy = #strategy_path::BoxedStrategy<Self>;
+
+            #[inline]
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                #strategy_path::Strategy::boxed(#strategy_path::Strategy::prop_filter_map(
+                    <Self as ValidatorStrategy>::valid_strings(),
+                    "rejected by the validator",
+                    |s| <Self as ValidateString>::parse_string(s).ok(),
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, Meta, Token};
+
+use crate::{
+    common::{actix_options::ActixOptions, axum_options::AxumOptions, impls::ImplsOption},
+    panic,
+};
+
+/// The parameters which every validator accepts for the traits implemented outside of its handler.
+#[derive(Default)]
+pub(crate) struct TraitOptions {
+    pub(crate) impls: Option<ImplsOption>,
+    pub(crate) actix: Option<ActixOptions>,
+    pub(crate) axum:  Option<AxumOptions>,
+}
+
+impl TraitOptions {
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.impls.is_none() && self.actix.is_none() && self.axum.is_none()
+    }
+}
+
+/// Take the `impls(...)`, `actix(...)` and `axum(...)` parameters out of the parameters of a validator, so that the handler of the validator does not have to know them.
+pub(crate) fn take_trait_options(meta: Meta) -> syn::Result<(Meta, TraitOptions)> {
+    let mut options = TraitOptions::default();
+
+    let mut list = match meta {
+        Meta::List(list) => list,
+        _ => return Ok((meta, options)),
+    };
+
+    // leave the parameters which cannot be parsed to the handler
+    let metas = match list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(metas) => metas,
+        Err(_) => return Ok((Meta::List(list), options)),
+    };
+
+    let mut rest: Punctuated<Meta, Token![,]> = Punctuated::new();
+
+    for meta in metas {
+        if let Some(ident) = meta.path().get_ident() {
+            if ident == "impls" {
+                if options.impls.is_some() {
+                    return Err(panic::parameter_reset(ident));
+                }
+
+                options.impls = Some(ImplsOption::from_meta(&meta)?);
+
+                continue;
+            } else if ident == "actix" {
+                if options.actix.is_some() {
+                    return Err(panic::parameter_reset(ident));
+                }
+
+                options.actix = Some(ActixOptions::from_meta(&meta)?);
+
+                continue;
+            } else if ident == "axum" {
+                if options.axum.is_some() {
+                    return Err(panic::parameter_reset(ident));
+                }
+
+                options.axum = Some(AxumOptions::from_meta(&meta)?);
+
+                continue;
+            }
+        }
+
+        rest.push(meta);
+    }
+
+    if options.is_empty() {
+        return Ok((Meta::List(list), options));
+    }
+
+    if rest.is_empty() {
+        return Ok((Meta::Path(list.path), options));
+    }
+
+    list.tokens = rest.into_token_stream();
+
+    Ok((Meta::List(list), options))
+}
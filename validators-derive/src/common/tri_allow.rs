@@ -1,4 +1,5 @@
 use proc_macro2::Ident;
+use quote::{quote, ToTokens};
 use syn::{spanned::Spanned, Expr, Meta};
 
 use crate::common::path_to_string;
@@ -81,3 +82,20 @@ impl TriAllow {
         ))
     }
 }
+
+impl ToTokens for TriAllow {
+    #[inline]
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Must => {
+                token_stream.extend(quote! { validators_prelude::TriAllow::Must });
+            },
+            Self::Allow => {
+                token_stream.extend(quote! { validators_prelude::TriAllow::Allow });
+            },
+            Self::Disallow => {
+                token_stream.extend(quote! { validators_prelude::TriAllow::Disallow });
+            },
+        }
+    }
+}
@@ -25,8 +25,10 @@ pub enum TypeEnum {
     VersionReq,
     Url,
     PhoneNumber,
+    PathBuf,
     HashMapPhoneNumber,
     CollectionLength,
+    FirstValidator,
 }
 
 impl TypeEnum {
@@ -56,11 +58,13 @@ impl TypeEnum {
             TypeEnum::VersionReq => "crate::semver::VersionReq",
             TypeEnum::Url => "url::Url",
             TypeEnum::PhoneNumber => "crate::phonenumber::PhoneNumber",
+            TypeEnum::PathBuf => "std::path::PathBuf",
             TypeEnum::HashMapPhoneNumber => {
                 "std::collection::HashMap<crate::phonenumber::country::Id, \
                  crate::phonenumber::PhoneNumber>"
             },
             TypeEnum::CollectionLength => "T: crate::validators::traits::CollectionLength",
+            TypeEnum::FirstValidator => "the type of the first validator",
         }
     }
 }
@@ -10,6 +10,7 @@ mod common;
 #[allow(unused)]
 mod panic;
 mod supported_validators;
+mod validate_fields;
 mod validator_handlers;
 
 use proc_macro::TokenStream;
@@ -52,154 +53,208 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     }
 
     if let Some((validator, meta)) = use_validator {
-        match validator {
-            #[cfg(feature = "base32")]
-            Validator::base32 => {
-                return validator_handlers::base32::Base32Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "base32_decoded")]
-            Validator::base32_decoded => {
-                return validator_handlers::base32_decoded::Base32DecodedHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "base64")]
-            Validator::base64 => {
-                return validator_handlers::base64::Base64Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "base64_decoded")]
-            Validator::base64_decoded => {
-                return validator_handlers::base64_decoded::Base64DecodedHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "base64_url")]
-            Validator::base64_url => {
-                return validator_handlers::base64_url::Base64UrlHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "base64_url_decoded")]
-            Validator::base64_url_decoded => {
-                return validator_handlers::base64_url_decoded::Base64UrlDecodedHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "bit")]
-            Validator::bit => {
-                return validator_handlers::bit::BitHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "boolean")]
-            Validator::boolean => {
-                return validator_handlers::boolean::BooleanHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "byte")]
-            Validator::byte => {
-                return validator_handlers::byte::ByteHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "domain")]
-            Validator::domain => {
-                return validator_handlers::domain::DomainHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "email")]
-            Validator::email => {
-                return validator_handlers::email::EmailHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "host")]
-            Validator::host => {
-                return validator_handlers::host::HostHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "http_url")]
-            Validator::http_url => {
-                return validator_handlers::http_url::HttpUrlHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "http_ftp_url")]
-            Validator::http_ftp_url => {
-                return validator_handlers::http_ftp_url::HttpFtpUrlHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "ip")]
-            Validator::ip => {
-                return validator_handlers::ip::IpHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "ipv4")]
-            Validator::ipv4 => {
-                return validator_handlers::ipv4::Ipv4Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "ipv6")]
-            Validator::ipv6 => {
-                return validator_handlers::ipv6::Ipv6Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "json")]
-            Validator::json => {
-                return validator_handlers::json::JsonHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "length")]
-            Validator::length => {
-                return validator_handlers::length::LengthHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "line")]
-            Validator::line => {
-                return validator_handlers::line::LineHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "mac_address")]
-            Validator::mac_address => {
-                return validator_handlers::mac_address::MacAddressHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "number")]
-            Validator::number => {
-                return validator_handlers::number::NumberHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "phone")]
-            Validator::phone => {
-                return validator_handlers::phone::PhoneHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "regex")]
-            Validator::regex => {
-                return validator_handlers::regex::RegexHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "semver")]
-            Validator::semver => {
-                return validator_handlers::semver::SemverHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "semver_req")]
-            Validator::semver_req => {
-                return validator_handlers::semver_req::SemverReqHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "signed_integer")]
-            Validator::signed_integer => {
-                return validator_handlers::signed_integer::SignedIntegerHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "text")]
-            Validator::text => {
-                return validator_handlers::text::TextHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "unsigned_integer")]
-            Validator::unsigned_integer => {
-                return validator_handlers::unsigned_integer::UnsignedIntegerHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "url")]
-            Validator::url => {
-                return validator_handlers::url::UrlHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "uuid")]
-            Validator::uuid => {
-                return validator_handlers::uuid::UuidHandler::meta_handler(ast, meta);
-            },
-            Validator::_Nothing => {
-                // avoid unused warnings
-                let _ = meta;
-                unreachable!();
-            },
+        let (meta, mut options) = common::trait_options::take_trait_options(meta)?;
+
+        if common::borrowed::borrowed_type(&ast).is_some()
+            && !common::borrowed::BORROWED_VALIDATORS
+                .contains(&common::path_to_string(meta.path()).as_str())
+        {
+            return Err(panic::borrowed_unsupported_validator(meta.path()));
+        }
+
+        let impls = match options.impls.take() {
+            Some(impls) => Some(impls.impl_traits(&ast, meta.path())?),
+            None => None,
+        };
+
+        let actix_options = options.actix.take().unwrap_or_default();
+
+        let axum_options = options.axum.take().unwrap_or_default();
+
+        #[cfg(feature = "schemars")]
+        let json_schema = common::json_schema::impl_json_schema(&ast, &meta);
+
+        #[cfg(feature = "utoipa")]
+        let openapi_schema = common::openapi_schema::impl_openapi_schema(&ast, &meta);
+
+        #[cfg(feature = "proptest")]
+        let strategy = common::strategy::impl_strategy(&ast, &meta);
+
+        #[cfg(feature = "arbitrary")]
+        let arbitrary = common::arbitrary::impl_arbitrary(&ast, &meta);
+
+        #[cfg(feature = "axum")]
+        let from_request_parts = if axum_options.from_request_parts {
+            common::axum::impl_from_request_parts(&ast, &meta)
+        } else {
+            proc_macro2::TokenStream::new()
+        };
+
+        #[cfg(not(feature = "axum"))]
+        let _ = axum_options;
+
+        #[cfg(feature = "clap")]
+        let value_parser_factory = common::clap::impl_value_parser_factory(&ast, &meta);
+
+        #[cfg(feature = "sqlx")]
+        let sqlx = common::sqlx::impl_sqlx(&ast, &meta);
+
+        #[cfg(feature = "diesel")]
+        let diesel = common::diesel::impl_diesel(&ast, &meta);
+
+        #[cfg(feature = "actix")]
+        let from_request = if actix_options.from_request {
+            common::actix::impl_from_request(&ast, &meta)
+        } else {
+            proc_macro2::TokenStream::new()
+        };
+
+        #[cfg(not(feature = "actix"))]
+        let _ = actix_options;
+
+        let mut token_stream = meta_handler(validator, ast, meta)?;
+
+        if let Some(impls) = impls {
+            token_stream.extend(impls);
         }
+
+        #[cfg(feature = "schemars")]
+        token_stream.extend(json_schema);
+
+        #[cfg(feature = "utoipa")]
+        token_stream.extend(openapi_schema);
+
+        #[cfg(feature = "proptest")]
+        token_stream.extend(strategy);
+
+        #[cfg(feature = "arbitrary")]
+        token_stream.extend(arbitrary);
+
+        #[cfg(feature = "axum")]
+        token_stream.extend(from_request_parts);
+
+        #[cfg(feature = "actix")]
+        token_stream.extend(from_request);
+
+        #[cfg(feature = "clap")]
+        token_stream.extend(value_parser_factory);
+
+        #[cfg(feature = "sqlx")]
+        token_stream.extend(sqlx);
+
+        #[cfg(feature = "diesel")]
+        token_stream.extend(diesel);
+
+        return Ok(token_stream);
     }
 
     Err(panic::derive_attribute_not_set_up_yet())
 }
 
+fn meta_handler(
+    validator: Validator,
+    ast: DeriveInput,
+    meta: Meta,
+) -> syn::Result<proc_macro2::TokenStream> {
+    match validator {
+        #[cfg(feature = "all")]
+        Validator::all => validator_handlers::all::AllHandler::meta_handler(ast, meta),
+        #[cfg(feature = "any")]
+        Validator::any => validator_handlers::any::AnyHandler::meta_handler(ast, meta),
+        #[cfg(feature = "base32")]
+        Validator::base32 => validator_handlers::base32::Base32Handler::meta_handler(ast, meta),
+        #[cfg(feature = "base32_decoded")]
+        Validator::base32_decoded => {
+            validator_handlers::base32_decoded::Base32DecodedHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "base64")]
+        Validator::base64 => validator_handlers::base64::Base64Handler::meta_handler(ast, meta),
+        #[cfg(feature = "base64_decoded")]
+        Validator::base64_decoded => {
+            validator_handlers::base64_decoded::Base64DecodedHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "base64_url")]
+        Validator::base64_url => {
+            validator_handlers::base64_url::Base64UrlHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "base64_url_decoded")]
+        Validator::base64_url_decoded => {
+            validator_handlers::base64_url_decoded::Base64UrlDecodedHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "bit")]
+        Validator::bit => validator_handlers::bit::BitHandler::meta_handler(ast, meta),
+        #[cfg(feature = "boolean")]
+        Validator::boolean => validator_handlers::boolean::BooleanHandler::meta_handler(ast, meta),
+        #[cfg(feature = "byte")]
+        Validator::byte => validator_handlers::byte::ByteHandler::meta_handler(ast, meta),
+        #[cfg(feature = "domain")]
+        Validator::domain => validator_handlers::domain::DomainHandler::meta_handler(ast, meta),
+        #[cfg(feature = "email")]
+        Validator::email => validator_handlers::email::EmailHandler::meta_handler(ast, meta),
+        #[cfg(feature = "host")]
+        Validator::host => validator_handlers::host::HostHandler::meta_handler(ast, meta),
+        #[cfg(feature = "http_url")]
+        Validator::http_url => {
+            validator_handlers::http_url::HttpUrlHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "http_ftp_url")]
+        Validator::http_ftp_url => {
+            validator_handlers::http_ftp_url::HttpFtpUrlHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "ip")]
+        Validator::ip => validator_handlers::ip::IpHandler::meta_handler(ast, meta),
+        #[cfg(feature = "ipv4")]
+        Validator::ipv4 => validator_handlers::ipv4::Ipv4Handler::meta_handler(ast, meta),
+        #[cfg(feature = "ipv6")]
+        Validator::ipv6 => validator_handlers::ipv6::Ipv6Handler::meta_handler(ast, meta),
+        #[cfg(feature = "json")]
+        Validator::json => validator_handlers::json::JsonHandler::meta_handler(ast, meta),
+        #[cfg(feature = "length")]
+        Validator::length => validator_handlers::length::LengthHandler::meta_handler(ast, meta),
+        #[cfg(feature = "line")]
+        Validator::line => validator_handlers::line::LineHandler::meta_handler(ast, meta),
+        #[cfg(feature = "mac_address")]
+        Validator::mac_address => {
+            validator_handlers::mac_address::MacAddressHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "not")]
+        Validator::not => validator_handlers::not::NotHandler::meta_handler(ast, meta),
+        #[cfg(feature = "number")]
+        Validator::number => validator_handlers::number::NumberHandler::meta_handler(ast, meta),
+        #[cfg(feature = "path")]
+        Validator::path => validator_handlers::path::PathHandler::meta_handler(ast, meta),
+        #[cfg(feature = "phone")]
+        Validator::phone => validator_handlers::phone::PhoneHandler::meta_handler(ast, meta),
+        #[cfg(feature = "regex")]
+        Validator::regex => validator_handlers::regex::RegexHandler::meta_handler(ast, meta),
+        #[cfg(feature = "semver")]
+        Validator::semver => validator_handlers::semver::SemverHandler::meta_handler(ast, meta),
+        #[cfg(feature = "semver_req")]
+        Validator::semver_req => {
+            validator_handlers::semver_req::SemverReqHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "signed_integer")]
+        Validator::signed_integer => {
+            validator_handlers::signed_integer::SignedIntegerHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "text")]
+        Validator::text => validator_handlers::text::TextHandler::meta_handler(ast, meta),
+        #[cfg(feature = "unsigned_integer")]
+        Validator::unsigned_integer => {
+            validator_handlers::unsigned_integer::UnsignedIntegerHandler::meta_handler(ast, meta)
+        },
+        #[cfg(feature = "url")]
+        Validator::url => validator_handlers::url::UrlHandler::meta_handler(ast, meta),
+        #[cfg(feature = "uuid")]
+        Validator::uuid => validator_handlers::uuid::UuidHandler::meta_handler(ast, meta),
+        Validator::_Nothing => {
+            // avoid unused warnings
+            let _ = (ast, meta);
+            unreachable!();
+        },
+    }
+}
+
 #[proc_macro_derive(Validator, attributes(validator))]
 pub fn validator_derive(input: TokenStream) -> TokenStream {
     struct MyDeriveInput(proc_macro2::TokenStream);
@@ -218,3 +273,23 @@ pub fn validator_derive(input: TokenStream) -> TokenStream {
 
     derive_input.0.into()
 }
+
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn validate_derive(input: TokenStream) -> TokenStream {
+    struct MyDeriveInput(proc_macro2::TokenStream);
+
+    impl Parse for MyDeriveInput {
+        #[inline]
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            let token_stream =
+                validate_fields::derive_input_handler(input.parse::<DeriveInput>()?)?;
+
+            Ok(Self(token_stream))
+        }
+    }
+
+    // Parse the token stream
+    let derive_input = parse_macro_input!(input as MyDeriveInput);
+
+    derive_input.0.into()
+}
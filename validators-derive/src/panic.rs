@@ -7,7 +7,10 @@ use std::fmt::Debug;
 use proc_macro2::Span;
 use syn::{spanned::Spanned, Ident, Path};
 
-use crate::{common::path_to_string, Validator};
+use crate::{
+    common::{borrowed::BORROWED_VALIDATORS, path_to_string},
+    Validator,
+};
 
 struct DisplayStringSlice<'a>(&'a [&'static str]);
 
@@ -79,6 +82,66 @@ pub(crate) fn validator_only_one_at_a_time(span: Span) -> syn::Error {
     syn::Error::new(span, "`validator` can be used only one at a time")
 }
 
+#[inline]
+pub(crate) fn combinator_validators_count(
+    name: &Ident,
+    min: usize,
+    max: usize,
+    span: Span,
+) -> syn::Error {
+    if min == max {
+        syn::Error::new(span, format!("the `{name}` validator needs exactly {min} validator"))
+    } else {
+        syn::Error::new(span, format!("the `{name}` validator needs at least {min} validators"))
+    }
+}
+
+#[inline]
+pub(crate) fn custom_incorrect_format(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `custom` parameter should be the path of a function, such as `custom = \
+         path::to::function`",
+    )
+}
+
+#[inline]
+pub(crate) fn borrowed_unsupported_validator(name: &Path) -> syn::Error {
+    let name_string = path_to_string(name);
+
+    syn::Error::new(
+        name.span(),
+        format!(
+            "the `{name_string}` validator cannot be used for borrowed strings, available \
+             validators:{}",
+            DisplayStringSlice(&BORROWED_VALIDATORS)
+        ),
+    )
+}
+
+#[inline]
+pub(crate) fn borrowed_normalize(span: Span) -> syn::Error {
+    syn::Error::new(span, "the `normalize` parameter cannot be used for borrowed strings")
+}
+
+#[inline]
+pub(crate) fn impls_need_validate_string(span: Span, trait_name: &str) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("`{trait_name}` can only be implemented for validators which parse strings"),
+    )
+}
+
+#[inline]
+pub(crate) fn impls_need_single_field(span: Span, trait_name: &str) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "`{trait_name}` can only be implemented for structs with exactly one unnamed field"
+        ),
+    )
+}
+
 #[inline]
 pub(crate) fn validator_for_specific_item(name: &Ident, item: impl Debug) -> syn::Error {
     syn::Error::new(
@@ -87,6 +150,23 @@ pub(crate) fn validator_for_specific_item(name: &Ident, item: impl Debug) -> syn
     )
 }
 
+#[inline]
+pub(crate) fn validate_for_named_struct(span: Span) -> syn::Error {
+    syn::Error::new(span, "`Validate` can only be derived for structs with named fields")
+}
+
+#[inline]
+pub(crate) fn validate_field_unsupported_type(span: Span, field: &Ident) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "the field `{field}` should be a type derived by `Validator`, which parses the raw \
+             string input with `ValidateString` (number, integer and boolean validators are also \
+             parsed from strings), instead of a primitive type, a string or a collection"
+        ),
+    )
+}
+
 #[inline]
 pub(crate) fn attribute_incorrect_format(name: &Ident) -> syn::Error {
     syn::Error::new(
@@ -1,4 +1,6 @@
 #[cfg(not(any(
+    feature = "all",
+    feature = "any",
     feature = "base32",
     feature = "base32_decoded",
     feature = "base64",
@@ -20,7 +22,9 @@
     feature = "length",
     feature = "line",
     feature = "mac_address",
+    feature = "not",
     feature = "number",
+    feature = "path",
     feature = "phone",
     feature = "regex",
     feature = "semver",
@@ -41,6 +45,10 @@ use syn::Path;
 #[ordinalize(impl_trait = false)]
 #[ordinalize(variants(pub(crate) const VARIANTS))]
 pub(crate) enum Validator {
+    #[cfg(feature = "all")]
+    all,
+    #[cfg(feature = "any")]
+    any,
     #[cfg(feature = "base32")]
     base32,
     #[cfg(feature = "base32_decoded")]
@@ -83,8 +91,12 @@ pub(crate) enum Validator {
     line,
     #[cfg(feature = "mac_address")]
     mac_address,
+    #[cfg(feature = "not")]
+    not,
     #[cfg(feature = "number")]
     number,
+    #[cfg(feature = "path")]
+    path,
     #[cfg(feature = "phone")]
     phone,
     #[cfg(feature = "regex")]
@@ -116,6 +128,10 @@ impl Validator {
         };
 
         match ident_string.as_str() {
+            #[cfg(feature = "all")]
+            "all" => Some(Self::all),
+            #[cfg(feature = "any")]
+            "any" => Some(Self::any),
             #[cfg(feature = "base32")]
             "base32" => Some(Self::base32),
             #[cfg(feature = "base32_decoded")]
@@ -158,8 +174,12 @@ impl Validator {
             "line" => Some(Self::line),
             #[cfg(feature = "mac_address")]
             "mac_address" => Some(Self::mac_address),
+            #[cfg(feature = "not")]
+            "not" => Some(Self::not),
             #[cfg(feature = "number")]
             "number" => Some(Self::number),
+            #[cfg(feature = "path")]
+            "path" => Some(Self::path),
             #[cfg(feature = "phone")]
             "phone" => Some(Self::phone),
             #[cfg(feature = "regex")]
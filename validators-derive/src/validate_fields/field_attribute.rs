@@ -0,0 +1,71 @@
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Expr, Lit, Meta, Token};
+
+use crate::panic;
+
+pub(crate) struct FieldAttribute {
+    pub(crate) rename: Option<String>,
+}
+
+impl FieldAttribute {
+    pub(crate) fn build_from_attributes(attrs: &[Attribute]) -> syn::Result<Self> {
+        let correct_parameters = ["rename"];
+
+        let mut rename: Option<String> = None;
+
+        for attr in attrs.iter() {
+            let path = attr.path();
+
+            if !path.is_ident("validate") {
+                continue;
+            }
+
+            let list = match &attr.meta {
+                Meta::List(list) => list,
+                _ => return Err(panic::attribute_incorrect_format(path.get_ident().unwrap())),
+            };
+
+            let result = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+            let mut handler = |meta: &Meta| -> syn::Result<bool> {
+                if let Some(ident) = meta.path().get_ident() {
+                    if ident == "rename" {
+                        let v = meta_2_string(meta)?;
+
+                        if rename.is_some() {
+                            return Err(panic::parameter_reset(ident));
+                        }
+
+                        rename = Some(v);
+
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            };
+
+            for p in result {
+                if !handler(&p)? {
+                    return Err(panic::parameter_incorrect_format(p.path(), &correct_parameters));
+                }
+            }
+        }
+
+        Ok(Self {
+            rename,
+        })
+    }
+}
+
+#[inline]
+fn meta_2_string(meta: &Meta) -> syn::Result<String> {
+    if let Meta::NameValue(name_value) = meta {
+        if let Expr::Lit(lit) = &name_value.value {
+            if let Lit::Str(s) = &lit.lit {
+                return Ok(s.value());
+            }
+        }
+    }
+
+    Err(syn::Error::new(meta.span(), "expected a string literal, such as `rename = \"name\"`"))
+}
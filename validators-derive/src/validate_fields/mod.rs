@@ -0,0 +1,263 @@
+mod field_attribute;
+
+use field_attribute::FieldAttribute;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+use crate::panic;
+
+struct FieldInfo<'a> {
+    ident:     &'a syn::Ident,
+    key:       String,
+    ty:        &'a Type,
+    is_option: bool,
+}
+
+pub(crate) fn derive_input_handler(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let named = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => named,
+            _ => return Err(panic::validate_for_named_struct(ast.ident.span())),
+        },
+        _ => return Err(panic::validate_for_named_struct(ast.ident.span())),
+    };
+
+    let mut field_infos = Vec::with_capacity(named.named.len());
+
+    for field in named.named.iter() {
+        let ident = field.ident.as_ref().unwrap();
+
+        let field_attribute = FieldAttribute::build_from_attributes(&field.attrs)?;
+
+        let key = field_attribute.rename.unwrap_or_else(|| {
+            let s = ident.to_string();
+
+            match s.strip_prefix("r#") {
+                Some(s) => String::from(s),
+                None => s,
+            }
+        });
+
+        let (ty, is_option) = match option_inner_type(&field.ty) {
+            Some(ty) => (ty, true),
+            None => (&field.ty, false),
+        };
+
+        if is_unsupported_type(ty) {
+            return Err(panic::validate_field_unsupported_type(ty.span(), ident));
+        }
+
+        field_infos.push(FieldInfo {
+            ident,
+            key,
+            ty,
+            is_option,
+        });
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    // local variables should not collide with the names of fields
+    let fields = syn::Ident::new("fields", Span::mixed_site());
+    let errors = syn::Ident::new("errors", Span::mixed_site());
+
+    let variables = (0..field_infos.len())
+        .map(|i| format_ident!("v_{}", i, span = Span::mixed_site()))
+        .collect::<Vec<_>>();
+
+    let mut parse_fields = TokenStream::new();
+    let mut validate_fields = TokenStream::new();
+
+    for (field_info, variable) in field_infos.iter().zip(variables.iter()) {
+        let key = &field_info.key;
+        let ty = field_info.ty;
+
+        // errors are reported under the name which the source looks the field up by
+        let (parsed, parse_missing, validate_missing) = if field_info.is_option {
+            (
+                quote! { Some(Some(v)) },
+                quote! { validators_prelude::FieldInput::Missing { .. } => Some(None), },
+                quote! { validators_prelude::FieldInput::Missing { .. } => (), },
+            )
+        } else {
+            (
+                quote! { Some(v) },
+                quote! {
+                    validators_prelude::FieldInput::Missing { name } => {
+                        #errors.push(name.into_owned(), validators_prelude::MissingFieldError);
+
+                        None
+                    },
+                },
+                quote! {
+                    validators_prelude::FieldInput::Missing { name } => {
+                        #errors.push(name.into_owned(), validators_prelude::MissingFieldError);
+                    },
+                },
+            )
+        };
+
+        parse_fields.extend(quote! {
+            let #variable = match validators_prelude::FieldSource::lookup_field(#fields, #key) {
+                validators_prelude::FieldInput::Present { name, value } => match <#ty as validators_prelude::ValidateString>::parse_str(value) {
+                    Ok(v) => #parsed,
+                    Err(error) => {
+                        #errors.push(name.into_owned(), error);
+
+                        None
+                    },
+                },
+                validators_prelude::FieldInput::NotUnicode { name } => {
+                    #errors.push(name.into_owned(), validators_prelude::NotUnicodeFieldError);
+
+                    None
+                },
+                #parse_missing
+            };
+        });
+
+        validate_fields.extend(quote! {
+            match validators_prelude::FieldSource::lookup_field(#fields, #key) {
+                validators_prelude::FieldInput::Present { name, value } => {
+                    if let Err(error) = <#ty as validators_prelude::ValidateString>::validate_str(value) {
+                        #errors.push(name.into_owned(), error);
+                    }
+                },
+                validators_prelude::FieldInput::NotUnicode { name } => {
+                    #errors.push(name.into_owned(), validators_prelude::NotUnicodeFieldError);
+                },
+                #validate_missing
+            }
+        });
+    }
+
+    let idents = field_infos.iter().map(|field_info| field_info.ident);
+
+    let construct = if field_infos.is_empty() {
+        quote! {
+            let _ = #fields;
+
+            Ok(Self {})
+        }
+    } else {
+        quote! {
+            let mut #errors = validators_prelude::ValidationErrors::new();
+
+            #parse_fields
+
+            if let (#(Some(#variables),)*) = (#(#variables,)*) {
+                return Ok(Self {
+                    #(#idents: #variables,)*
+                });
+            }
+
+            Err(#errors)
+        }
+    };
+
+    let validate = if field_infos.is_empty() {
+        quote! {
+            let _ = #fields;
+
+            Ok(())
+        }
+    } else {
+        quote! {
+            let mut #errors = validators_prelude::ValidationErrors::new();
+
+            #validate_fields
+
+            if #errors.is_empty() {
+                Ok(())
+            } else {
+                Err(#errors)
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics validators_prelude::ValidateFields for #name #ty_generics #where_clause {
+            type Error = validators_prelude::ValidationErrors;
+
+            #[inline]
+            fn parse_fields<F: validators_prelude::FieldSource + ?Sized>(#fields: &F) -> Result<Self, Self::Error> {
+                #construct
+            }
+
+            #[inline]
+            fn validate_fields<F: validators_prelude::FieldSource + ?Sized>(#fields: &F) -> Result<(), Self::Error> {
+                #validate
+            }
+        }
+    })
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty {
+        if type_path.qself.is_some() {
+            return None;
+        }
+
+        let segment = type_path.path.segments.last()?;
+
+        if segment.ident != "Option" {
+            return None;
+        }
+
+        if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+            if arguments.args.len() == 1 {
+                if let Some(GenericArgument::Type(ty)) = arguments.args.first() {
+                    return Some(ty);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Whether the type of a field obviously cannot be parsed by `ValidateString`, such as a primitive type, a string or a collection.
+fn is_unsupported_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            if type_path.qself.is_some() {
+                return false;
+            }
+
+            match type_path.path.segments.last() {
+                Some(segment) => matches!(
+                    segment.ident.to_string().as_str(),
+                    "bool"
+                        | "char"
+                        | "i8"
+                        | "i16"
+                        | "i32"
+                        | "i64"
+                        | "i128"
+                        | "isize"
+                        | "u8"
+                        | "u16"
+                        | "u32"
+                        | "u64"
+                        | "u128"
+                        | "usize"
+                        | "f32"
+                        | "f64"
+                        | "str"
+                        | "String"
+                        | "Cow"
+                        | "Vec"
+                        | "HashMap"
+                        | "BTreeMap"
+                        | "HashSet"
+                        | "BTreeSet"
+                ),
+                None => false,
+            }
+        },
+        Type::Reference(_) | Type::Slice(_) | Type::Array(_) | Type::Tuple(_) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,213 @@
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, Path};
+
+use super::ValidatorHandler;
+use crate::{
+    common::{attributes::combinator_attribute::CombinatorAttribute, type_enum::TypeEnum},
+    panic,
+};
+
+pub(crate) struct AllHandler;
+
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct Struct(TypeEnum);
+
+const ITEM: Struct = Struct(TypeEnum::FirstValidator);
+
+impl ValidatorHandler for AllHandler {
+    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+        let type_attribute = CombinatorAttribute::build_from_meta(&meta, 2, usize::MAX)?;
+
+        if let Data::Struct(data) = ast.data {
+            if let Fields::Unnamed(_) = &data.fields {
+                if data.fields.len() == 1 {
+                    let mut token_stream = proc_macro2::TokenStream::new();
+
+                    let name = ast.ident;
+
+                    let error_path: Path =
+                        syn::parse2(quote! { validators_prelude::AllError }).unwrap();
+
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    let v_validators: Vec<String> = type_attribute
+                        .validators
+                        .iter()
+                        .map(crate::common::path_to_string)
+                        .collect();
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "all",
+                        &[(
+                            "validators",
+                            crate::common::spec::spec_value(
+                                "Strs",
+                                quote! { &[#(#v_validators),*] },
+                            ),
+                        )],
+                    );
+
+                    let first = &type_attribute.validators[0];
+
+                    let rest = type_attribute.validators[1..].iter().enumerate().map(|(i, p)| {
+                        let index = i + 1;
+
+                        quote! {
+                            <#p as ValidateString>::validate_str(s).map_err(|error| #error_path::new(#index, error))?;
+                        }
+                    });
+
+                    token_stream.extend(quote! {
+                        impl #name {
+                            #[inline]
+                            fn v_validate_rest(s: &str) -> Result<(), #error_path> {
+                                #( #rest )*
+
+                                Ok(())
+                            }
+                        }
+                    });
+
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            let s = s.as_ref();
+
+                            <#first as ValidateString>::validate_str(s).map_err(|error| #error_path::new(0, error))?;
+
+                            Self::v_validate_rest(s)
+                        },
+                    );
+
+                    token_stream.extend(quote! {
+                        impl ValidateString for #name {
+                            type Error = #error_type;
+
+                            #[inline]
+                            fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_string
+
+                                Self::parse_str(s.into())
+                            }
+
+                            #[inline]
+                            fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_str
+
+                                let s = s.as_ref();
+
+                                let v = <#first as ValidateString>::parse_str(s).map_err(|error| #error_path::new(0, error))?;
+
+                                Self::v_validate_rest(s)?;
+
+                                #ok(#name(v))
+                            }
+
+                            #[inline]
+                            fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                                #validate_str
+                            }
+                        }
+                    });
+
+                    #[cfg(feature = "serde")]
+                    {
+                        if type_attribute.serde_options.serialize {
+                            token_stream.extend(quote! {
+                                impl validators_prelude::serde::Serialize for #name {
+                                    #[inline]
+                                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                                        where
+                                            S: validators_prelude::serde::Serializer, {
+                                        validators_prelude::serde::Serialize::serialize(&self.0, serializer)
+                                    }
+                                }
+                            });
+                        }
+
+                        if type_attribute.serde_options.deserialize {
+                            let expect = "a string valid for all of the validators";
+
+                            token_stream.extend(quote! {
+                                impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
+                                    #[inline]
+                                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                                    where
+                                        D: validators_prelude::serde::Deserializer<'de>, {
+                                        struct MyVisitor;
+
+                                        impl<'de> validators_prelude::serde::de::Visitor<'de> for MyVisitor {
+                                            type Value = #name;
+
+                                            #[inline]
+                                            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                                f.write_str(#expect)
+                                            }
+
+                                            #[inline]
+                                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
+                                            }
+
+                                            #[inline]
+                                            fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
+                                            }
+                                        }
+
+                                        deserializer.deserialize_string(MyVisitor)
+                                    }
+                                }
+                            });
+                        }
+                    }
+
+                    #[cfg(feature = "rocket")]
+                    {
+                        if type_attribute.rocket_options.from_form_field {
+                            crate::common::rocket::impl_from_form_field(&mut token_stream, &name);
+                        }
+
+                        if type_attribute.rocket_options.from_param {
+                            crate::common::rocket::impl_from_param(
+                                &mut token_stream,
+                                &name,
+                                &error_type,
+                            );
+                        }
+                    }
+
+                    return Ok(token_stream);
+                }
+            }
+        }
+
+        Err(panic::validator_for_specific_item(meta.path().get_ident().unwrap(), ITEM))
+    }
+}
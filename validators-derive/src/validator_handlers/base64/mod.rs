@@ -3,7 +3,9 @@ use syn::{Data, DeriveInput, Fields, Meta, Path};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{attributes::base_xx_attribute::BaseXXAttribute, type_enum::TypeEnum},
+    common::{
+        attributes::base_xx_attribute::BaseXXAttribute, tri_allow::TriAllow, type_enum::TypeEnum,
+    },
     panic,
 };
 
@@ -19,6 +21,8 @@ impl ValidatorHandler for Base64Handler {
     fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = BaseXXAttribute::build_from_meta(&meta)?;
 
+        let borrowed_type = crate::common::borrowed::borrowed_type(&ast);
+
         if let Data::Struct(data) = ast.data {
             if let Fields::Unnamed(_) = &data.fields {
                 if data.fields.len() == 1 {
@@ -26,15 +30,39 @@ impl ValidatorHandler for Base64Handler {
 
                     let name = ast.ident;
 
+                    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::Base64Error }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "base64",
+                        &[(
+                            "padding",
+                            crate::common::spec::spec_value("TriAllow", type_attribute.padding),
+                        )],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_padding = type_attribute.padding;
 
                         token_stream.extend(quote! {
-                            impl #name {
+                            impl #impl_generics #name #ty_generics #where_clause {
                                 pub(crate) const V_PADDING: validators_prelude::TriAllow = #v_padding;
                             }
                         });
@@ -77,7 +105,7 @@ impl ValidatorHandler for Base64Handler {
                     };
 
                     token_stream.extend(quote! {
-                        impl #name {
+                        impl #impl_generics #name #ty_generics #where_clause {
                             #[inline]
                             fn v_parse_str(s: &str) -> Result<(), #error_path> {
                                 Self::v_parse_u8_slice(s.as_bytes())
@@ -138,38 +166,89 @@ impl ValidatorHandler for Base64Handler {
                         }
                     });
 
+                    let expect = match type_attribute.padding {
+                        TriAllow::Allow => "a Base64 string or data",
+                        TriAllow::Must => "a Base64 string or data with padding",
+                        TriAllow::Disallow => "a Base64 string or data without padding",
+                    };
+
+                    if let Some(borrowed_type) = borrowed_type {
+                        crate::common::borrowed::impl_borrowed(
+                            &mut token_stream,
+                            &name,
+                            borrowed_type,
+                            &error_type,
+                            &type_attribute.custom,
+                            type_attribute.normalize.as_ref(),
+                            &type_attribute.serde_options,
+                            &type_attribute.rocket_options,
+                            expect,
+                        )?;
+
+                        return Ok(token_stream);
+                    }
+
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u8_slice = type_attribute.custom.validate(
+                        quote! { <Self as ValidateBytes>::parse_u8_slice(v) },
+                        quote! {
+                            Self::v_parse_u8_slice(v.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_string
+
                                 let s = s.into();
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(Self(s))
+                                #ok(Self(s))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_str
+
                                 let s = s.as_ref();
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(Self(validators_prelude::String::from(s)))
+                                #ok(Self(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateBytes for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_vec_u8<V: Into<validators_prelude::Vec<u8>>>(v: V) -> Result<Self, Self::Error> {
@@ -177,7 +256,7 @@ impl ValidatorHandler for Base64Handler {
 
                                 Self::v_parse_u8_slice(v.as_slice())?;
 
-                                Ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v) }))
+                                #ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v) }))
                             }
 
                             #[inline]
@@ -186,14 +265,12 @@ impl ValidatorHandler for Base64Handler {
 
                                 Self::v_parse_u8_slice(v)?;
 
-                                Ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v.to_vec()) }))
+                                #ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v.to_vec()) }))
                             }
 
                             #[inline]
                             fn validate_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<(), Self::Error> {
-                                Self::v_parse_u8_slice(v.as_ref())?;
-
-                                Ok(())
+                                #validate_u8_slice
                             }
                         }
                     });
@@ -214,14 +291,6 @@ impl ValidatorHandler for Base64Handler {
                         }
 
                         if type_attribute.serde_options.deserialize {
-                            use crate::common::tri_allow::TriAllow;
-
-                            let expect = match type_attribute.padding {
-                                TriAllow::Allow => "a Base64 string or data",
-                                TriAllow::Must => "a Base64 string or data with padding",
-                                TriAllow::Disallow => "a Base64 string or data without padding",
-                            };
-
                             token_stream.extend(quote! {
                                 impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
                                     #[inline]
@@ -242,28 +311,28 @@ impl ValidatorHandler for Base64Handler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_byte_buf<E>(self, v: validators_prelude::Vec<u8>) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -284,7 +353,7 @@ impl ValidatorHandler for Base64Handler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
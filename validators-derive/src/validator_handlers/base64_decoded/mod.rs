@@ -31,6 +31,28 @@ impl ValidatorHandler for Base64DecodedHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::Base64DecodedError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "base64_decoded",
+                        &[(
+                            "padding",
+                            crate::common::spec::spec_value("TriAllow", type_attribute.padding),
+                        )],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_padding = type_attribute.padding;
@@ -108,9 +130,7 @@ impl ValidatorHandler for Base64DecodedHandler {
                             }
 
                             fn v_parse_u8_slice(v: &[u8]) -> Result<validators_prelude::Vec<u8>, #error_path> {
-                                if v.is_empty() {
-                                    return Err(#error_path::Invalid);
-                                }
+                                Self::v_check_u8_slice(v)?;
 
                                 #decode
                             }
@@ -121,6 +141,13 @@ impl ValidatorHandler for Base64DecodedHandler {
                             }
 
                             fn v_validate_u8_slice(v: &[u8]) -> Result<(), #error_path> {
+                                Self::v_check_u8_slice(v)?;
+
+                                // the length of the last block and the trailing bits are only checked by decoding
+                                #decode.map(|_| ())
+                            }
+
+                            fn v_check_u8_slice(v: &[u8]) -> Result<(), #error_path> {
                                 let length = v.len();
 
                                 if length == 0 {
@@ -175,46 +202,73 @@ impl ValidatorHandler for Base64DecodedHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_validate_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u8_slice = type_attribute.custom.validate(
+                        quote! { <Self as ValidateBytes>::parse_u8_slice(v) },
+                        quote! {
+                            Self::v_validate_u8_slice(v.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_validate_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateBytes for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_vec_u8<V: Into<validators_prelude::Vec<u8>>>(v: V) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u8_slice(v.into().as_slice())?))
+                                #ok(Self(Self::v_parse_u8_slice(v.into().as_slice())?))
                             }
 
                             #[inline]
                             fn parse_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u8_slice(v.as_ref())?))
+                                #ok(Self(Self::v_parse_u8_slice(v.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<(), Self::Error> {
-                                Self::v_validate_u8_slice(v.as_ref())?;
-
-                                Ok(())
+                                #validate_u8_slice
                             }
                         }
 
@@ -268,28 +322,28 @@ impl ValidatorHandler for Base64DecodedHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_byte_buf<E>(self, v: validators_prelude::Vec<u8>) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -310,7 +364,7 @@ impl ValidatorHandler for Base64DecodedHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
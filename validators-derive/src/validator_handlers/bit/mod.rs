@@ -32,6 +32,28 @@ impl ValidatorHandler for BitHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::BitError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "bit",
+                        &[(
+                            "range",
+                            crate::common::spec::spec_value("UnsignedRange", &type_attribute.range),
+                        )],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_range = &type_attribute.range;
@@ -49,14 +71,35 @@ impl ValidatorHandler for BitHandler {
                         type_attribute.range.inclusive,
                     );
 
+                    let too_small = |min: u128| {
+                        quote! {
+                            #error_path::TooSmall {
+                                min: #min, value: v.as_u128()
+                            }
+                        }
+                    };
+
+                    let too_large = |max: u128, inclusive: bool| {
+                        quote! {
+                            #error_path::TooLarge {
+                                max: #max, inclusive: #inclusive, value: v.as_u128()
+                            }
+                        }
+                    };
+
                     let handle_range = if equal {
                         let min = type_attribute.range.min.unwrap();
+                        let too_small = too_small(min);
+                        let too_large = too_large(
+                            type_attribute.range.max.unwrap(),
+                            type_attribute.range.inclusive,
+                        );
 
                         quote! {
                             match ::core::cmp::PartialOrd::partial_cmp(&v, &#min) {
                                 Some(::core::cmp::Ordering::Equal) => (),
-                                Some(::core::cmp::Ordering::Less) => return Err(#error_path::TooSmall),
-                                Some(::core::cmp::Ordering::Greater) => return Err(#error_path::TooLarge),
+                                Some(::core::cmp::Ordering::Less) => return Err(#too_small),
+                                Some(::core::cmp::Ordering::Greater) => return Err(#too_large),
                                 None => unreachable!(),
                             }
                         }
@@ -64,24 +107,28 @@ impl ValidatorHandler for BitHandler {
                         let mut token_stream = proc_macro2::TokenStream::new();
 
                         if let Some(min) = type_attribute.range.min {
+                            let too_small = too_small(min);
+
                             token_stream.extend(quote! {
                                 if v < #min {
-                                    return Err(#error_path::TooSmall);
+                                    return Err(#too_small);
                                 }
                             });
                         }
 
                         if let Some(max) = type_attribute.range.max {
+                            let too_large = too_large(max, type_attribute.range.inclusive);
+
                             token_stream.extend(if type_attribute.range.inclusive {
                                 quote! {
                                     if v > #max {
-                                        return Err(#error_path::TooLarge);
+                                        return Err(#too_large);
                                     }
                                 }
                             } else {
                                 quote! {
                                     if v >= #max {
-                                        return Err(#error_path::TooLarge);
+                                        return Err(#too_large);
                                     }
                                 }
                             });
@@ -108,49 +155,91 @@ impl ValidatorHandler for BitHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
 
+                    let validate_u128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u128(u) },
+                        quote! {
+                            let v = validators_prelude::byte_unit::Bit::from_u128(u).ok_or(#error_path::TooLarge {
+                                max: validators_prelude::byte_unit::Bit::MAX.as_u128(), inclusive: true, value: u
+                            })?;
+
+                            Self::v_parse_v(v)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u64(u) },
+                        quote! {
+                            let v = validators_prelude::byte_unit::Bit::from_u64(u);
+
+                            Self::v_parse_v(v)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
-                                let v = validators_prelude::byte_unit::Bit::from_u128(u).ok_or(#error_path::TooLarge)?;
+                                let v = validators_prelude::byte_unit::Bit::from_u128(u).ok_or(#error_path::TooLarge {
+                                    max: validators_prelude::byte_unit::Bit::MAX.as_u128(), inclusive: true, value: u
+                                })?;
 
                                 Self::v_parse_v(v)?;
 
-                                Ok(Self(v))
+                                #ok(Self(v))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                let v = validators_prelude::byte_unit::Bit::from_u128(u).ok_or(#error_path::TooLarge)?;
-
-                                Self::v_parse_v(v)?;
-
-                                Ok(())
+                                #validate_u128
                             }
 
                             #[inline]
@@ -159,16 +248,12 @@ impl ValidatorHandler for BitHandler {
 
                                 Self::v_parse_v(v)?;
 
-                                Ok(Self(v))
+                                #ok(Self(v))
                             }
 
                             #[inline]
                             fn validate_u64(u: u64) -> Result<(), Self::Error> {
-                                let v = validators_prelude::byte_unit::Bit::from_u64(u);
-
-                                Self::v_parse_v(v)?;
-
-                                Ok(())
+                                #validate_u64
                             }
                         }
                     });
@@ -197,9 +282,7 @@ impl ValidatorHandler for BitHandler {
                                         D: validators_prelude::serde::Deserializer<'de>, {
                                         let v: validators_prelude::byte_unit::Bit = validators_prelude::serde::Deserialize::deserialize(deserializer)?;
 
-                                        Self::v_parse_v(v).map_err(validators_prelude::serde::de::Error::custom)?;
-
-                                        Ok(Self(v))
+                                        <Self as ValidateUnsignedInteger>::parse_u128(v.as_u128()).map_err(validators_prelude::de::error)
                                     }
                                 }
                             });
@@ -216,7 +299,7 @@ impl ValidatorHandler for BitHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
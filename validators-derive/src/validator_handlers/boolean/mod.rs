@@ -30,6 +30,25 @@ impl ValidatorHandler for BooleanHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::BooleanError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "boolean",
+                        &[],
+                    );
+
                     token_stream.extend(quote! {
                         impl #name {
                             fn v_parse_str(s: &str) -> Result<bool, #error_path> {
@@ -212,89 +231,137 @@ impl ValidatorHandler for BooleanHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_char = type_attribute.custom.validate(
+                        quote! { <Self as ValidateChar>::parse_char(c) },
+                        quote! {
+                            Self::v_parse_char(c)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i128(i) },
+                        quote! {
+                            Self::v_parse_i128(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u128(u) },
+                        quote! {
+                            Self::v_parse_u128(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_bool = type_attribute.custom.validate(
+                        quote! { <Self as ValidateBoolean>::parse_bool(b) },
+                        quote! {
+                            Self::v_parse_bool(b)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateChar for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_char(c: char) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_char(c)?))
+                                #ok(Self(Self::v_parse_char(c)?))
                             }
 
                             #[inline]
                             fn validate_char(c: char) -> Result<(), Self::Error> {
-                                Self::v_parse_char(c)?;
-
-                                Ok(())
+                                #validate_char
                             }
                         }
 
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_i128(i)?))
+                                #ok(Self(Self::v_parse_i128(i)?))
                             }
 
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
-                                Self::v_parse_i128(i)?;
-
-                                Ok(())
+                                #validate_i128
                             }
                         }
 
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u128(u)?))
+                                #ok(Self(Self::v_parse_u128(u)?))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                Self::v_parse_u128(u)?;
-
-                                Ok(())
+                                #validate_u128
                             }
                         }
 
                         impl ValidateBoolean for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_bool(b: bool) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_bool(b)?))
+                                #ok(Self(Self::v_parse_bool(b)?))
                             }
 
                             #[inline]
                             fn validate_bool(b: bool) -> Result<(), Self::Error> {
-                                Self::v_parse_bool(b)?;
-
-                                Ok(())
+                                #validate_bool
                             }
                         }
                     });
@@ -339,41 +406,41 @@ impl ValidatorHandler for BooleanHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
 
                                             fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                Ok(#name(v))
+                                                <#name as ValidateBoolean>::parse_bool(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateChar>::parse_char(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateChar>::parse_char(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                 <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                 <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
@@ -381,7 +448,7 @@ impl ValidatorHandler for BooleanHandler {
                                             where
                                                 E: validators_prelude::serde::de::Error,
                                             {
-                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
@@ -389,7 +456,7 @@ impl ValidatorHandler for BooleanHandler {
                                             where
                                                 E: validators_prelude::serde::de::Error,
                                             {
-                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -410,7 +477,7 @@ impl ValidatorHandler for BooleanHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
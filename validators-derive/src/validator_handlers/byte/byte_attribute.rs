@@ -2,8 +2,8 @@ use syn::{punctuated::Punctuated, Meta, Token};
 
 use crate::{
     common::{
-        boolean::meta_2_bool, range::Range, rocket_options::RocketOptions,
-        serde_options::SerdeOptions,
+        boolean::meta_2_bool, custom_option::CustomOption, normalize_option::NormalizeOption,
+        range::Range, rocket_options::RocketOptions, serde_options::SerdeOptions,
     },
     panic,
 };
@@ -15,16 +15,20 @@ pub(crate) struct ByteAttribute {
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
 }
 
 impl ByteAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["range", "ignore_case", "serde", "rocket"];
+        let correct_parameters = ["range", "ignore_case", "serde", "rocket", "custom", "normalize"];
 
         let mut range = Range::new();
         let mut ignore_case = true;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -39,6 +43,8 @@ impl ByteAttribute {
                 let mut ignore_case_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -95,6 +101,32 @@ impl ByteAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -118,6 +150,8 @@ impl ByteAttribute {
             ignore_case,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
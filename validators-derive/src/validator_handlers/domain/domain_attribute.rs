@@ -2,8 +2,8 @@ use syn::{punctuated::Punctuated, Meta, Token};
 
 use crate::{
     common::{
-        allow::Allow, rocket_options::RocketOptions, serde_options::SerdeOptions,
-        tri_allow::TriAllow,
+        allow::Allow, custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow,
     },
     panic,
 };
@@ -18,12 +18,23 @@ pub(crate) struct DomainAttribute {
     pub(crate) serde_options:       SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options:      RocketOptions,
+    pub(crate) custom:              CustomOption,
+    pub(crate) normalize:           Option<NormalizeOption>,
 }
 
 impl DomainAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters =
-            ["ipv4", "local", "port", "at_least_two_labels", "conflict", "serde", "rocket"];
+        let correct_parameters = [
+            "ipv4",
+            "local",
+            "port",
+            "at_least_two_labels",
+            "conflict",
+            "serde",
+            "rocket",
+            "custom",
+            "normalize",
+        ];
 
         let mut ipv4 = TriAllow::Allow;
         let mut local = TriAllow::Allow;
@@ -32,6 +43,8 @@ impl DomainAttribute {
         let mut conflict = Allow::Disallow;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -49,6 +62,8 @@ impl DomainAttribute {
                 let mut conflict_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -144,6 +159,32 @@ impl DomainAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -170,6 +211,8 @@ impl DomainAttribute {
             conflict,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
@@ -39,13 +39,6 @@ pub struct StructAllowPortAllowLocal {
     port:     TypeEnum,
 }
 
-#[derive(Educe)]
-#[educe(Debug(name = "Struct"))]
-pub struct StructAllowIPv4 {
-    domain:  TypeEnum,
-    is_ipv4: TypeEnum,
-}
-
 #[derive(Educe)]
 #[educe(Debug(name = "Struct"))]
 pub struct StructAllowIPv4AllowLocal {
@@ -54,14 +47,6 @@ pub struct StructAllowIPv4AllowLocal {
     is_local: TypeEnum,
 }
 
-#[derive(Educe)]
-#[educe(Debug(name = "Struct"))]
-pub struct StructAllowIPv4AllowPort {
-    domain:  TypeEnum,
-    is_ipv4: TypeEnum,
-    port:    TypeEnum,
-}
-
 #[derive(Educe)]
 #[educe(Debug(name = "Struct"))]
 pub struct StructAllowIPv4AllowPortAllowLocal {
@@ -118,6 +103,8 @@ impl ValidatorHandler for DomainHandler {
     fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = DomainAttribute::build_from_meta(&meta)?;
 
+        let borrowed_type = crate::common::borrowed::borrowed_type(&ast);
+
         if let Data::Struct(data) = ast.data {
             let mut meta_is_conflict = false;
 
@@ -491,8 +478,39 @@ impl ValidatorHandler for DomainHandler {
 
             let name = ast.ident;
 
+            let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
             let error_path: Path = syn::parse2(quote! { validators_prelude::DomainError }).unwrap();
 
+            let error_type = type_attribute.custom.error_type(&error_path);
+
+            let ok = type_attribute.custom.ok();
+
+            type_attribute.custom.impl_custom(&mut token_stream, &name, &ast.generics, &error_path);
+
+            crate::common::spec::impl_validator_spec(
+                &mut token_stream,
+                &name,
+                &ast.generics,
+                "domain",
+                &[
+                    ("ipv4", crate::common::spec::spec_value("TriAllow", type_attribute.ipv4)),
+                    ("local", crate::common::spec::spec_value("TriAllow", type_attribute.local)),
+                    ("port", crate::common::spec::spec_value("TriAllow", type_attribute.port)),
+                    (
+                        "at_least_two_labels",
+                        crate::common::spec::spec_value(
+                            "TriAllow",
+                            type_attribute.at_least_two_labels,
+                        ),
+                    ),
+                    (
+                        "conflict",
+                        crate::common::spec::spec_value("TriAllow", type_attribute.conflict),
+                    ),
+                ],
+            );
+
             #[cfg(feature = "test")]
             {
                 let v_ipv4 = type_attribute.ipv4;
@@ -501,7 +519,7 @@ impl ValidatorHandler for DomainHandler {
                 let v_at_least_two_labels = type_attribute.at_least_two_labels;
 
                 token_stream.extend(quote! {
-                    impl #name {
+                    impl #impl_generics #name #ty_generics #where_clause {
                         pub(crate) const V_IPV4: validators_prelude::TriAllow = #v_ipv4;
                         pub(crate) const V_LOCAL: validators_prelude::TriAllow = #v_local;
                         pub(crate) const V_PORT: validators_prelude::TriAllow = #v_port;
@@ -679,6 +697,11 @@ impl ValidatorHandler for DomainHandler {
                                 .to_ascii(domain_str)
                             {
                                 Ok(ascii_domain) => {
+                                    // IDNA may also map the domain to an IPv4 address, e.g. subscript digits, which are not checked as one
+                                    if validators_prelude::parse_ipv4_allow_an_ended_dot(&ascii_domain).is_ok() {
+                                        return Err(#error_path::Invalid);
+                                    }
+
                                     let port = #handle_port;
 
                                     let is_local = #handle_local_domain;
@@ -713,8 +736,19 @@ impl ValidatorHandler for DomainHandler {
                 quote! {}
             };
 
+            // a borrowed struct stores the input as it is, so the input must be what IDNA would produce
+            let check_canonical = if borrowed_type.is_some() {
+                quote! {
+                    if bytes.iter().any(|e| !e.is_ascii() || e.is_ascii_uppercase()) {
+                        return Err(#error_path::Invalid);
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
             token_stream.extend(quote! {
-                impl #name {
+                impl #impl_generics #name #ty_generics #where_clause {
                     #conflict_meta
                     fn v_parse_str(s: validators_prelude::Cow<str>) -> Result<(validators_prelude::String, Option<u16>, bool, bool), #error_path> {
                         let bytes = s.as_bytes();
@@ -723,6 +757,8 @@ impl ValidatorHandler for DomainHandler {
                             return Err(#error_path::Invalid);
                         }
 
+                        #check_canonical
+
                         let (domain_str, port_str) = match bytes.iter().copied().rposition(|e| e == b':') {
                             #handle_domain_and_port
                             None => {
@@ -859,29 +895,136 @@ impl ValidatorHandler for DomainHandler {
                 }
             };
 
+            let expect = {
+                let mut s = String::from("a correct ");
+
+                match type_attribute.ipv4 {
+                    TriAllow::Allow => {
+                        match type_attribute.at_least_two_labels {
+                            TriAllow::Allow => (),
+                            TriAllow::Must => {
+                                s.push_str("at-least-two-labels ");
+                            },
+                            TriAllow::Disallow => {
+                                s.push_str("one-label ");
+                            },
+                        }
+
+                        s.push_str("domain name");
+                    },
+                    TriAllow::Must => {
+                        s.push_str("IPv4 string");
+                    },
+                    TriAllow::Disallow => {
+                        if type_attribute.at_least_two_labels.must() {
+                            s.push_str("at-least-two-labels and ");
+                        }
+
+                        s.push_str("non-IPv4 domain name");
+                    },
+                }
+
+                match type_attribute.local {
+                    TriAllow::Allow => match type_attribute.port {
+                        TriAllow::Allow => {
+                            s.push_str(" with an optional port");
+                        },
+                        TriAllow::Must => {
+                            s.push_str(" with a port");
+                        },
+                        TriAllow::Disallow => {
+                            s.push_str(" without ports");
+                        },
+                    },
+                    TriAllow::Must => {
+                        s.push_str(" which must be local");
+
+                        match type_attribute.port {
+                            TriAllow::Allow => (),
+                            TriAllow::Must => {
+                                s.push_str(" and with a port");
+                            },
+                            TriAllow::Disallow => {
+                                s.push_str(" and without ports");
+                            },
+                        }
+                    },
+                    TriAllow::Disallow => {
+                        s.push_str(" which must not be local");
+
+                        match type_attribute.port {
+                            TriAllow::Allow => (),
+                            TriAllow::Must => {
+                                s.push_str(" and must be with a port");
+                            },
+                            TriAllow::Disallow => {
+                                s.push_str(" and must be without ports");
+                            },
+                        }
+                    },
+                }
+
+                s
+            };
+
+            if let Some(borrowed_type) = borrowed_type {
+                crate::common::borrowed::impl_borrowed(
+                    &mut token_stream,
+                    &name,
+                    borrowed_type,
+                    &error_type,
+                    &type_attribute.custom,
+                    type_attribute.normalize.as_ref(),
+                    &type_attribute.serde_options,
+                    &type_attribute.rocket_options,
+                    &expect,
+                )?;
+
+                return Ok(token_stream);
+            }
+
+            let normalize_string = crate::common::normalize_option::normalize_string(
+                type_attribute.normalize.as_ref(),
+            );
+            let normalize_str =
+                crate::common::normalize_option::normalize_str(type_attribute.normalize.as_ref());
+
+            let validate_str = type_attribute.custom.validate(
+                quote! { <Self as ValidateString>::parse_str(s) },
+                quote! {
+                    #normalize_str
+
+                    Self::v_parse_str(validators_prelude::Cow::Borrowed(s.as_ref()))?;
+
+                    Ok(())
+                },
+            );
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_string
+
                         let (domain, _port, _is_ipv4, _is_local) = Self::v_parse_str(validators_prelude::Cow::Owned(s.into()))?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_str
+
                         let (domain, _port, _is_ipv4, _is_local) = Self::v_parse_str(validators_prelude::Cow::Borrowed(s.as_ref()))?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(validators_prelude::Cow::Borrowed(s.as_ref()))?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
@@ -934,7 +1077,7 @@ impl ValidatorHandler for DomainHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 match self.port {
                                     Some(port) => {
                                         let non_fully_qualified_domain = QualifyDomain::get_domain_non_fully_qualified(self);
@@ -951,7 +1094,7 @@ impl ValidatorHandler for DomainHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let non_fully_qualified_domain = QualifyDomain::get_domain_non_fully_qualified(self);
                                 let port = self.port;
 
@@ -964,7 +1107,7 @@ impl ValidatorHandler for DomainHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 validators_prelude::Cow::Borrowed(self.get_domain_non_fully_qualified())
                             }
                         }
@@ -988,78 +1131,6 @@ impl ValidatorHandler for DomainHandler {
                 }
 
                 if type_attribute.serde_options.deserialize {
-                    let expect = {
-                        let mut s = String::from("a correct ");
-
-                        match type_attribute.ipv4 {
-                            TriAllow::Allow => {
-                                match type_attribute.at_least_two_labels {
-                                    TriAllow::Allow => (),
-                                    TriAllow::Must => {
-                                        s.push_str("at-least-two-labels ");
-                                    },
-                                    TriAllow::Disallow => {
-                                        s.push_str("one-label ");
-                                    },
-                                }
-
-                                s.push_str("domain name");
-                            },
-                            TriAllow::Must => {
-                                s.push_str("IPv4 string");
-                            },
-                            TriAllow::Disallow => {
-                                if type_attribute.at_least_two_labels.must() {
-                                    s.push_str("at-least-two-labels and ");
-                                }
-
-                                s.push_str("non-IPv4 domain name");
-                            },
-                        }
-
-                        match type_attribute.local {
-                            TriAllow::Allow => match type_attribute.port {
-                                TriAllow::Allow => {
-                                    s.push_str(" with an optional port");
-                                },
-                                TriAllow::Must => {
-                                    s.push_str(" with a port");
-                                },
-                                TriAllow::Disallow => {
-                                    s.push_str(" without ports");
-                                },
-                            },
-                            TriAllow::Must => {
-                                s.push_str(" which must be local");
-
-                                match type_attribute.port {
-                                    TriAllow::Allow => (),
-                                    TriAllow::Must => {
-                                        s.push_str(" and with a port");
-                                    },
-                                    TriAllow::Disallow => {
-                                        s.push_str(" and without ports");
-                                    },
-                                }
-                            },
-                            TriAllow::Disallow => {
-                                s.push_str(" which must not be local");
-
-                                match type_attribute.port {
-                                    TriAllow::Allow => (),
-                                    TriAllow::Must => {
-                                        s.push_str(" and must be with a port");
-                                    },
-                                    TriAllow::Disallow => {
-                                        s.push_str(" and must be without ports");
-                                    },
-                                }
-                            },
-                        }
-
-                        s
-                    };
-
                     token_stream.extend(quote! {
                         impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
                             #[inline]
@@ -1080,14 +1151,14 @@ impl ValidatorHandler for DomainHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                     }
 
                                     #[inline]
                                     fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                     }
                                 }
 
@@ -1105,7 +1176,7 @@ impl ValidatorHandler for DomainHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
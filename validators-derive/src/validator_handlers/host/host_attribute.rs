@@ -1,7 +1,10 @@
 use syn::{punctuated::Punctuated, Meta, Token};
 
 use crate::{
-    common::{rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow},
+    common::{
+        custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow,
+    },
     panic,
 };
 
@@ -13,17 +16,22 @@ pub(crate) struct HostAttribute {
     pub(crate) serde_options:       SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options:      RocketOptions,
+    pub(crate) custom:              CustomOption,
+    pub(crate) normalize:           Option<NormalizeOption>,
 }
 
 impl HostAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["local", "port", "at_least_two_labels", "serde", "rocket"];
+        let correct_parameters =
+            ["local", "port", "at_least_two_labels", "serde", "rocket", "custom", "normalize"];
 
         let mut local = TriAllow::Allow;
         let mut port = TriAllow::Allow;
         let mut at_least_two_labels = TriAllow::Allow;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -39,6 +47,8 @@ impl HostAttribute {
                 let mut at_least_two_labels_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -108,6 +118,32 @@ impl HostAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -132,6 +168,8 @@ impl HostAttribute {
             at_least_two_labels,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
@@ -244,12 +244,36 @@ impl ValidatorHandler for HostHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::HostError }).unwrap();
 
+            let error_type = type_attribute.custom.error_type(&error_path);
+
+            let ok = type_attribute.custom.ok();
+
+            type_attribute.custom.impl_custom(&mut token_stream, &name, &ast.generics, &error_path);
+
+            crate::common::spec::impl_validator_spec(
+                &mut token_stream,
+                &name,
+                &ast.generics,
+                "host",
+                &[
+                    ("local", crate::common::spec::spec_value("TriAllow", type_attribute.local)),
+                    ("port", crate::common::spec::spec_value("TriAllow", type_attribute.port)),
+                    (
+                        "at_least_two_labels",
+                        crate::common::spec::spec_value(
+                            "TriAllow",
+                            type_attribute.at_least_two_labels,
+                        ),
+                    ),
+                ],
+            );
+
+            let v_local = type_attribute.local;
+            let v_port = type_attribute.port;
+            let v_at_least_two_labels = type_attribute.at_least_two_labels;
+
             #[cfg(feature = "test")]
             {
-                let v_local = type_attribute.local;
-                let v_port = type_attribute.port;
-                let v_at_least_two_labels = type_attribute.at_least_two_labels;
-
                 token_stream.extend(quote! {
                     impl #name {
                         pub(crate) const V_LOCAL: validators_prelude::TriAllow = #v_local;
@@ -259,306 +283,11 @@ impl ValidatorHandler for HostHandler {
                 });
             }
 
-            let check_local = {
-                match type_attribute.local {
-                    TriAllow::Allow => quote! {},
-                    TriAllow::Must => {
-                        quote! {
-                            if !is_local {
-                                return Err(#error_path::LocalMust);
-                            }
-                        }
-                    },
-                    TriAllow::Disallow => {
-                        quote! {
-                            if is_local {
-                                return Err(#error_path::LocalDisallow);
-                            }
-                        }
-                    },
-                }
-            };
-
-            let handle_local_ipv6 = if type_attribute.at_least_two_labels == TriAllow::Allow
-                && type_attribute.local == TriAllow::Allow
-            {
-                quote! {
-                    false
-                }
-            } else {
-                quote! {
-                    validators_prelude::is_local_ipv6(ip)
-                }
-            };
-
-            let handle_ipv6_without_port = if type_attribute.port.must() {
-                quote! {
-                    return Err(#error_path::PortMust);
-                }
-            } else {
-                quote! {
-                    let ip_str = unsafe { ::core::str::from_utf8_unchecked(&bytes[1..last_index]) };
-
-                    match ::std::net::Ipv6Addr::from_str(ip_str) {
-                        Ok(ip) => {
-                            let is_local = #handle_local_ipv6;
-
-                            #check_local
-
-                            (validators_prelude::Host::IPv6(ip), None, is_local)
-                        }
-                        Err(_) => return Err(#error_path::Invalid),
-                    }
-                }
-            };
-
-            let handle_ipv6_with_port = if type_attribute.port.disallow() {
-                quote! {
-                    return Err(#error_path::PortDisallow);
-                }
-            } else {
-                quote! {
-                    match bytes.iter().copied().rposition(|e| e == b':') {
-                        Some(colon_index) => {
-                            if colon_index > 2 && bytes[colon_index - 1] == b']' {
-                                let ip_str = unsafe { ::core::str::from_utf8_unchecked(&bytes[1..(colon_index - 1)]) };
-
-                                match ::std::net::Ipv6Addr::from_str(ip_str) {
-                                    Ok(ip) => {
-                                        let port_str = unsafe { ::core::str::from_utf8_unchecked(&bytes[(colon_index + 1)..]) };
-
-                                        match port_str.parse::<u16>() {
-                                            Ok(port) => {
-                                                let is_local = #handle_local_ipv6;
-
-                                                #check_local
-
-                                                (validators_prelude::Host::IPv6(ip), Some(port), is_local)
-                                            }
-                                            Err(_) => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                    Err(_) => return Err(#error_path::Invalid),
-                                }
-                            } else {
-                                return Err(#error_path::Invalid);
-                            }
-                        }
-                        None => return Err(#error_path::Invalid),
-                    }
-                }
-            };
-
-            let handle_ipv6_bracket = if type_attribute.at_least_two_labels.disallow() {
-                quote! {
-                    return Err(#error_path::AtLeastTwoLabelsDisallow);
-                }
-            } else {
-                quote! {
-                    let last_index = bytes.len() - 1;
-
-                    if bytes[last_index] == b']' {
-                        #handle_ipv6_without_port
-                    } else {
-                        #handle_ipv6_with_port
-                    }
-                }
-            };
-
-            let handle_ipv6_non_bracket = if type_attribute.at_least_two_labels.disallow() {
-                quote! {
-                    Ok(_) => {
-                        return Err(#error_path::AtLeastTwoLabelsDisallow);
-                    }
-                }
-            } else if type_attribute.port.must() {
-                quote! {
-                    Ok(_) => {
-                        return Err(#error_path::PortMust);
-                    }
-                }
-            } else {
-                quote! {
-                    Ok(ip) => {
-                        let is_local = #handle_local_ipv6;
-
-                        #check_local
-
-                        (validators_prelude::Host::IPv6(ip), None, is_local)
-                    }
-                }
-            };
-
-            let handle_domain_and_port = if type_attribute.port.disallow() {
-                quote! {
-                    Some(_) => {
-                        return Err(#error_path::PortDisallow);
-                    }
-                }
-            } else {
-                quote! {
-                    Some(colon_index) => {
-                        (
-                            unsafe { ::core::str::from_utf8_unchecked(&bytes[..colon_index]) },
-                            Some(unsafe { ::core::str::from_utf8_unchecked(&bytes[(colon_index + 1)..]) }),
-                        )
-                    }
-                }
-            };
-
-            let handle_domain_none_port = if type_attribute.port.must() {
-                quote! {
-                    return Err(#error_path::PortMust);
-                }
-            } else {
-                quote! {
-                    (unsafe { ::core::str::from_utf8_unchecked(bytes) }, None::<&str>)
-                }
-            };
-
-            let handle_port = if type_attribute.port.disallow() {
-                quote! {
-                    None::<u16>
-                }
-            } else {
-                quote! {
-                    match port_str {
-                        Some(port_str) => {
-                            match port_str.parse::<u16>() {
-                                Ok(port) => Some(port),
-                                Err(_) => return Err(#error_path::Invalid),
-                            }
-                        }
-                        None => None,
-                    }
-                }
-            };
-
-            let handle_local_ipv4 = if type_attribute.at_least_two_labels == TriAllow::Allow
-                && type_attribute.local == TriAllow::Allow
-            {
-                quote! {
-                    false
-                }
-            } else {
-                quote! {
-                    validators_prelude::is_local_ipv4(ip)
-                }
-            };
-
-            let handle_ipv4 = if type_attribute.at_least_two_labels.disallow() {
-                quote! {
-                    Ok(_) => {
-                        return Err(#error_path::AtLeastTwoLabelsDisallow);
-                    }
-                }
-            } else {
-                quote! {
-                    Ok(ip) => {
-                        let port = #handle_port;
-
-                        let is_local = #handle_local_ipv4;
-
-                        #check_local
-
-                        (validators_prelude::Host::IPv4(ip), port, is_local)
-                    }
-                }
-            };
-
-            let handle_none_ipv4 = {
-                let check_at_least_two_labels = {
-                    match type_attribute.at_least_two_labels {
-                        TriAllow::Allow => quote! {},
-                        TriAllow::Must => {
-                            quote! {
-                                if !is_local && !validators_prelude::is_at_least_two_labels_domain(&ascii_domain) {
-                                    return Err(#error_path::AtLeastTwoLabelsMust);
-                                }
-                            }
-                        },
-                        TriAllow::Disallow => {
-                            quote! {
-                                if !is_local && validators_prelude::is_at_least_two_labels_domain(&ascii_domain) {
-                                    return Err(#error_path::AtLeastTwoLabelsDisallow);
-                                }
-                            }
-                        },
-                    }
-                };
-
-                let handle_local_domain = if type_attribute.at_least_two_labels == TriAllow::Allow
-                    && type_attribute.local == TriAllow::Allow
-                {
-                    quote! {
-                        false
-                    }
-                } else {
-                    quote! {
-                        validators_prelude::is_local_domain(&ascii_domain)
-                    }
-                };
-
-                quote! {
-                    match validators_prelude::idna::Config::default()
-                        .use_std3_ascii_rules(true)
-                        .verify_dns_length(true)
-                        .check_hyphens(true)
-                        .to_ascii(domain_str)
-                    {
-                        Ok(ascii_domain) => {
-                            let port = #handle_port;
-
-                            let is_local = #handle_local_domain;
-
-                            #check_at_least_two_labels
-
-                            #check_local
-
-                            (validators_prelude::Host::Domain(ascii_domain), port, is_local)
-                        }
-                        Err(_) => return Err(#error_path::Invalid),
-                    }
-                }
-            };
-
             token_stream.extend(quote! {
                 impl #name {
+                    #[inline]
                     fn v_parse_str(s: &str) -> Result<(validators_prelude::Host, Option<u16>, bool), #error_path> {
-                        use ::core::str::FromStr;
-
-                        let bytes = s.as_bytes();
-
-                        if bytes.is_empty() {
-                            return Err(#error_path::Invalid);
-                        }
-
-                        Ok(if bytes[0] == b'[' {
-                            #handle_ipv6_bracket
-                        } else {
-                            match ::std::net::Ipv6Addr::from_str(s) {
-                                #handle_ipv6_non_bracket
-                                Err(_) => {
-                                    let (domain_str, port_str) = match bytes.iter().copied().rposition(|e| e == b':') {
-                                        #handle_domain_and_port
-                                        None => {
-                                            #handle_domain_none_port
-                                        }
-                                    };
-
-                                    if domain_str.ends_with('.') {
-                                        return Err(#error_path::Invalid);
-                                    }
-
-                                    match ::std::net::Ipv4Addr::from_str(domain_str) {
-                                        #handle_ipv4
-                                        Err(_) => {
-                                            #handle_none_ipv4
-                                        }
-                                    }
-                                }
-                            }
-                        })
+                        validators_prelude::parse_host(s, #v_local, #v_port, #v_at_least_two_labels)
                     }
                 }
             });
@@ -622,29 +351,48 @@ impl ValidatorHandler for HostHandler {
                 }
             };
 
+            let normalize_string = crate::common::normalize_option::normalize_string(
+                type_attribute.normalize.as_ref(),
+            );
+            let normalize_str =
+                crate::common::normalize_option::normalize_str(type_attribute.normalize.as_ref());
+
+            let validate_str = type_attribute.custom.validate(
+                quote! { <Self as ValidateString>::parse_str(s) },
+                quote! {
+                    #normalize_str
+
+                    Self::v_parse_str(s.as_ref())?;
+
+                    Ok(())
+                },
+            );
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_string
+
                         let (host, _port, _is_local) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_str
+
                         let (host, _port, _is_local) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
@@ -657,7 +405,7 @@ impl ValidatorHandler for HostHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 match &self.0 {
                                     validators_prelude::Host::IPv4(ip) => validators_prelude::Cow::Owned(validators_prelude::format!("{}", ip)),
                                     validators_prelude::Host::IPv6(ip) => validators_prelude::Cow::Owned(validators_prelude::format!("[{}]", ip)),
@@ -672,7 +420,7 @@ impl ValidatorHandler for HostHandler {
                             quote! {
                                 impl ToUriAuthorityString for #name {
                                     #[inline]
-                                    fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                                    fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                         match &self.host {
                                             validators_prelude::Host::IPv4(ip) => {
                                                 match self.port {
@@ -701,7 +449,7 @@ impl ValidatorHandler for HostHandler {
                             quote! {
                                 impl ToUriAuthorityString for #name {
                                     #[inline]
-                                    fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                                    fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                         let port = self.port;
 
                                         match &self.host {
@@ -717,7 +465,7 @@ impl ValidatorHandler for HostHandler {
                             quote! {
                                 impl ToUriAuthorityString for #name {
                                     #[inline]
-                                    fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                                    fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                         match &self.host {
                                             validators_prelude::Host::IPv4(ip) => validators_prelude::Cow::Owned(validators_prelude::format!("{}", ip)),
                                             validators_prelude::Host::IPv6(ip) => validators_prelude::Cow::Owned(validators_prelude::format!("[{}]", ip)),
@@ -825,7 +573,7 @@ impl ValidatorHandler for HostHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                     }
                                 }
 
@@ -843,7 +591,7 @@ impl ValidatorHandler for HostHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -37,6 +37,28 @@ impl ValidatorHandler for HttpUrlHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::HttpURLError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "http_url",
+                        &[(
+                            "local",
+                            crate::common::spec::spec_value("TriAllow", type_attribute.local),
+                        )],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_local = type_attribute.local;
@@ -109,15 +131,35 @@ impl ValidatorHandler for HttpUrlHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_string
+
                                 let (url, is_https) = Self::v_parse_str(s.into().as_str())?;
 
-                                Ok(Self {
+                                #ok(Self {
                                     url,
                                     is_https,
                                 })
@@ -125,9 +167,11 @@ impl ValidatorHandler for HttpUrlHandler {
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_str
+
                                 let (url, is_https) = Self::v_parse_str(s.as_ref())?;
 
-                                Ok(Self {
+                                #ok(Self {
                                     url,
                                     is_https,
                                 })
@@ -135,9 +179,7 @@ impl ValidatorHandler for HttpUrlHandler {
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -194,7 +236,7 @@ impl ValidatorHandler for HttpUrlHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -215,7 +257,7 @@ impl ValidatorHandler for HttpUrlHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
@@ -122,6 +122,23 @@ impl ValidatorHandler for IpHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::IpError }).unwrap();
 
+            let error_type = type_attribute.custom.error_type(&error_path);
+
+            let ok = type_attribute.custom.ok();
+
+            type_attribute.custom.impl_custom(&mut token_stream, &name, &ast.generics, &error_path);
+
+            crate::common::spec::impl_validator_spec(
+                &mut token_stream,
+                &name,
+                &ast.generics,
+                "ip",
+                &[
+                    ("local", crate::common::spec::spec_value("TriAllow", type_attribute.local)),
+                    ("port", crate::common::spec::spec_value("TriAllow", type_attribute.port)),
+                ],
+            );
+
             #[cfg(feature = "test")]
             {
                 let v_local = type_attribute.local;
@@ -365,29 +382,48 @@ impl ValidatorHandler for IpHandler {
                 }
             };
 
+            let normalize_string = crate::common::normalize_option::normalize_string(
+                type_attribute.normalize.as_ref(),
+            );
+            let normalize_str =
+                crate::common::normalize_option::normalize_str(type_attribute.normalize.as_ref());
+
+            let validate_str = type_attribute.custom.validate(
+                quote! { <Self as ValidateString>::parse_str(s) },
+                quote! {
+                    #normalize_str
+
+                    Self::v_parse_str(s.as_ref())?;
+
+                    Ok(())
+                },
+            );
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_string
+
                         let (ip, _port, _is_local) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_str
+
                         let (ip, _port, _is_local) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
@@ -397,7 +433,7 @@ impl ValidatorHandler for IpHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 match &self.ip {
                                     ::std::net::IpAddr::V4(ip) => {
                                         match self.port {
@@ -420,7 +456,7 @@ impl ValidatorHandler for IpHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let port = self.port;
 
                                 match &self.ip {
@@ -435,7 +471,7 @@ impl ValidatorHandler for IpHandler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 match &self.0 {
                                     ::std::net::IpAddr::V4(ip) => validators_prelude::Cow::Owned(validators_prelude::format!("{}", ip)),
                                     ::std::net::IpAddr::V6(ip) => validators_prelude::Cow::Owned(validators_prelude::format!("[{}]", ip)),
@@ -528,7 +564,7 @@ impl ValidatorHandler for IpHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                     }
                                 }
 
@@ -546,7 +582,7 @@ impl ValidatorHandler for IpHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
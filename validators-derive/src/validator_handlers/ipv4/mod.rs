@@ -122,6 +122,23 @@ impl ValidatorHandler for Ipv4Handler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::Ipv4Error }).unwrap();
 
+            let error_type = type_attribute.custom.error_type(&error_path);
+
+            let ok = type_attribute.custom.ok();
+
+            type_attribute.custom.impl_custom(&mut token_stream, &name, &ast.generics, &error_path);
+
+            crate::common::spec::impl_validator_spec(
+                &mut token_stream,
+                &name,
+                &ast.generics,
+                "ipv4",
+                &[
+                    ("local", crate::common::spec::spec_value("TriAllow", type_attribute.local)),
+                    ("port", crate::common::spec::spec_value("TriAllow", type_attribute.port)),
+                ],
+            );
+
             #[cfg(feature = "test")]
             {
                 let v_local = type_attribute.local;
@@ -264,29 +281,48 @@ impl ValidatorHandler for Ipv4Handler {
                 }
             };
 
+            let normalize_string = crate::common::normalize_option::normalize_string(
+                type_attribute.normalize.as_ref(),
+            );
+            let normalize_str =
+                crate::common::normalize_option::normalize_str(type_attribute.normalize.as_ref());
+
+            let validate_str = type_attribute.custom.validate(
+                quote! { <Self as ValidateString>::parse_str(s) },
+                quote! {
+                    #normalize_str
+
+                    Self::v_parse_str(s.as_ref())?;
+
+                    Ok(())
+                },
+            );
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_string
+
                         let (ipv4, _port, _is_local) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_str
+
                         let (ipv4, _port, _is_local) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
@@ -296,7 +332,7 @@ impl ValidatorHandler for Ipv4Handler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let ip = self.ipv4;
 
                                 match self.port {
@@ -311,7 +347,7 @@ impl ValidatorHandler for Ipv4Handler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let ip = self.ipv4;
                                 let port = self.port;
 
@@ -324,7 +360,7 @@ impl ValidatorHandler for Ipv4Handler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let ip = self.0;
 
                                 validators_prelude::Cow::Owned(validators_prelude::format!("{}", ip))
@@ -416,7 +452,7 @@ impl ValidatorHandler for Ipv4Handler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                     }
                                 }
 
@@ -434,7 +470,7 @@ impl ValidatorHandler for Ipv4Handler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -122,6 +122,23 @@ impl ValidatorHandler for Ipv6Handler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::Ipv6Error }).unwrap();
 
+            let error_type = type_attribute.custom.error_type(&error_path);
+
+            let ok = type_attribute.custom.ok();
+
+            type_attribute.custom.impl_custom(&mut token_stream, &name, &ast.generics, &error_path);
+
+            crate::common::spec::impl_validator_spec(
+                &mut token_stream,
+                &name,
+                &ast.generics,
+                "ipv6",
+                &[
+                    ("local", crate::common::spec::spec_value("TriAllow", type_attribute.local)),
+                    ("port", crate::common::spec::spec_value("TriAllow", type_attribute.port)),
+                ],
+            );
+
             #[cfg(feature = "test")]
             {
                 let v_local = type_attribute.local;
@@ -296,29 +313,48 @@ impl ValidatorHandler for Ipv6Handler {
                 }
             };
 
+            let normalize_string = crate::common::normalize_option::normalize_string(
+                type_attribute.normalize.as_ref(),
+            );
+            let normalize_str =
+                crate::common::normalize_option::normalize_str(type_attribute.normalize.as_ref());
+
+            let validate_str = type_attribute.custom.validate(
+                quote! { <Self as ValidateString>::parse_str(s) },
+                quote! {
+                    #normalize_str
+
+                    Self::v_parse_str(s.as_ref())?;
+
+                    Ok(())
+                },
+            );
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_string
+
                         let (ipv6, _port, _is_local) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #normalize_str
+
                         let (ipv6, _port, _is_local) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
@@ -328,7 +364,7 @@ impl ValidatorHandler for Ipv6Handler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let ip = self.ipv6;
 
                                 match self.port {
@@ -343,7 +379,7 @@ impl ValidatorHandler for Ipv6Handler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let ip = self.ipv6;
                                 let port = self.port;
 
@@ -356,7 +392,7 @@ impl ValidatorHandler for Ipv6Handler {
                     quote! {
                         impl ToUriAuthorityString for #name {
                             #[inline]
-                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<str> {
+                            fn to_uri_authority_string(&self) -> validators_prelude::Cow<'_, str> {
                                 let ip = self.0;
 
                                 validators_prelude::Cow::Owned(validators_prelude::format!("[{}]", ip))
@@ -448,7 +484,7 @@ impl ValidatorHandler for Ipv6Handler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                     }
                                 }
 
@@ -466,7 +502,7 @@ impl ValidatorHandler for Ipv6Handler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
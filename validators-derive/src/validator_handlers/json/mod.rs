@@ -32,6 +32,25 @@ impl ValidatorHandler for JsonHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::JsonError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "json",
+                        &[],
+                    );
+
                     token_stream.extend(quote! {
                         impl #name {
                             fn v_parse_str(s: &str) -> Result<#data_type, #error_path> {
@@ -87,129 +106,198 @@ impl ValidatorHandler for JsonHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i128(i) },
+                        quote! {
+                            Self::v_parse_i128(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i64(i) },
+                        quote! {
+                            Self::v_parse_i64(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u128(u) },
+                        quote! {
+                            Self::v_parse_u128(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u64(u) },
+                        quote! {
+                            Self::v_parse_u64(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_f64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateNumber>::parse_f64(f) },
+                        quote! {
+                            Self::v_parse_f64(f)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_bool = type_attribute.custom.validate(
+                        quote! { <Self as ValidateBoolean>::parse_bool(b) },
+                        quote! {
+                            Self::v_parse_bool(b)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_json_value = type_attribute.custom.validate(
+                        quote! { <Self as ValidateJsonValue>::parse_json_value(v) },
+                        quote! {
+                            Self::v_parse_json_value(v)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_i128(i)?))
+                                #ok(Self(Self::v_parse_i128(i)?))
                             }
 
                             #[inline]
                             fn parse_i64(i: i64) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_i64(i)?))
+                                #ok(Self(Self::v_parse_i64(i)?))
                             }
 
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
-                                Self::v_parse_i128(i)?;
-
-                                Ok(())
+                                #validate_i128
                             }
 
                             #[inline]
                             fn validate_i64(i: i64) -> Result<(), Self::Error> {
-                                Self::v_parse_i64(i)?;
-
-                                Ok(())
+                                #validate_i64
                             }
                         }
 
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u128(u)?))
+                                #ok(Self(Self::v_parse_u128(u)?))
                             }
 
                             #[inline]
                             fn parse_u64(u: u64) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u64(u)?))
+                                #ok(Self(Self::v_parse_u64(u)?))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                Self::v_parse_u128(u)?;
-
-                                Ok(())
+                                #validate_u128
                             }
 
                             #[inline]
                             fn validate_u64(u: u64) -> Result<(), Self::Error> {
-                                Self::v_parse_u64(u)?;
-
-                                Ok(())
+                                #validate_u64
                             }
                         }
 
                         impl ValidateNumber for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_f64(f: f64) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_f64(f)?))
+                                #ok(Self(Self::v_parse_f64(f)?))
                             }
 
                             #[inline]
                             fn validate_f64(f: f64) -> Result<(), Self::Error> {
-                                Self::v_parse_f64(f)?;
-
-                                Ok(())
+                                #validate_f64
                             }
                         }
 
                         impl ValidateBoolean for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_bool(b: bool) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_bool(b)?))
+                                #ok(Self(Self::v_parse_bool(b)?))
                             }
 
                             #[inline]
                             fn validate_bool(b: bool) -> Result<(), Self::Error> {
-                                Self::v_parse_bool(b)?;
-
-                                Ok(())
+                                #validate_bool
                             }
                         }
 
                         impl ValidateJsonValue for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_json_value(v: validators_prelude::serde_json::Value) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_json_value(v)?))
+                                #ok(Self(Self::v_parse_json_value(v)?))
                             }
 
                             #[inline]
                             fn validate_json_value(v: validators_prelude::serde_json::Value) -> Result<(), Self::Error> {
-                                Self::v_parse_json_value(v)?;
-
-                                Ok(())
+                                #validate_json_value
                             }
                         }
                     });
@@ -242,6 +330,15 @@ impl ValidatorHandler for JsonHandler {
                         }
 
                         if type_attribute.serde_options.deserialize {
+                            let deserialized = match &type_attribute.custom.0 {
+                                Some(_) => quote! {
+                                    Self::v_custom(v).map_err(validators_prelude::de::error)
+                                },
+                                None => quote! {
+                                    Ok(v)
+                                },
+                            };
+
                             token_stream.extend(quote! {
                                 impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
                                     #[inline]
@@ -250,7 +347,9 @@ impl ValidatorHandler for JsonHandler {
                                         D: validators_prelude::serde::Deserializer<'de>, {
                                         let value = validators_prelude::serde::Deserialize::deserialize(deserializer)?;
 
-                                        Ok(Self(validators_prelude::serde_json::from_value(value).map_err(validators_prelude::serde::de::Error::custom)?))
+                                        let v = Self(validators_prelude::serde_json::from_value(value).map_err(validators_prelude::serde::de::Error::custom)?);
+
+                                        #deserialized
                                     }
                                 }
                             });
@@ -267,7 +366,7 @@ impl ValidatorHandler for JsonHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
@@ -1,7 +1,7 @@
 use syn::{punctuated::Punctuated, spanned::Spanned, Meta, Token};
 
 use crate::{
-    common::{number::meta_2_number, serde_options::SerdeOptions},
+    common::{custom_option::CustomOption, number::meta_2_number, serde_options::SerdeOptions},
     panic,
 };
 
@@ -10,15 +10,17 @@ pub(crate) struct LengthAttribute {
     pub(crate) max:           Option<usize>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
     pub(crate) serde_options: SerdeOptions,
+    pub(crate) custom:        CustomOption,
 }
 
 impl LengthAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["min", "max", "serde"];
+        let correct_parameters = ["min", "max", "serde", "custom"];
 
         let mut min = None;
         let mut max = None;
         let mut serde_options = SerdeOptions::default();
+        let mut custom = CustomOption::default();
 
         match meta {
             Meta::Path(_) => (),
@@ -32,6 +34,7 @@ impl LengthAttribute {
                 let mut min_is_set = false;
                 let mut max_is_set = false;
                 let mut serde_options_is_set = false;
+                let mut custom_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -75,6 +78,19 @@ impl LengthAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -108,6 +124,7 @@ impl LengthAttribute {
             min,
             max,
             serde_options,
+            custom,
         })
     }
 }
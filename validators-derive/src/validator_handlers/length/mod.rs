@@ -31,6 +31,40 @@ impl ValidatorHandler for LengthHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::LengthError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "length",
+                        &[
+                            (
+                                "min",
+                                crate::common::spec::spec_value(
+                                    "Length",
+                                    crate::common::spec::OptionToken(type_attribute.min),
+                                ),
+                            ),
+                            (
+                                "max",
+                                crate::common::spec::spec_value(
+                                    "Length",
+                                    crate::common::spec::OptionToken(type_attribute.max),
+                                ),
+                            ),
+                        ],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let min_expr = {
@@ -77,10 +111,12 @@ impl ValidatorHandler for LengthHandler {
                                 if let Some(max) = type_attribute.max {
                                     if min == max {
                                         quote! {
+                                            let length = CollectionLength::len(v);
+
                                             match ::core::cmp::Ord::cmp(&length, &#min) {
                                                 ::core::cmp::Ordering::Equal => (),
-                                                ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall),
-                                                ::core::cmp::Ordering::Greater => return Err(#error_path::TooLarge),
+                                                ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall { min: #min, length }),
+                                                ::core::cmp::Ordering::Greater => return Err(#error_path::TooLarge { max: #max, length }),
                                             }
                                         }
                                     } else {
@@ -88,11 +124,11 @@ impl ValidatorHandler for LengthHandler {
                                             let length = CollectionLength::len(v);
 
                                             if length < #min {
-                                                return Err(#error_path::TooSmall);
+                                                return Err(#error_path::TooSmall { min: #min, length });
                                             }
 
                                             if length > #max {
-                                                return Err(#error_path::TooLarge);
+                                                return Err(#error_path::TooLarge { max: #max, length });
                                             }
                                         }
                                     }
@@ -101,7 +137,7 @@ impl ValidatorHandler for LengthHandler {
                                         let length = CollectionLength::len(v);
 
                                         if length < #min {
-                                            return Err(#error_path::TooSmall);
+                                            return Err(#error_path::TooSmall { min: #min, length });
                                         }
                                     }
                                 }
@@ -112,7 +148,7 @@ impl ValidatorHandler for LengthHandler {
                                         let length = CollectionLength::len(v);
 
                                         if length > #max {
-                                            return Err(#error_path::TooLarge);
+                                            return Err(#error_path::TooLarge { max: #max, length });
                                         }
                                     }
                                 },
@@ -135,23 +171,30 @@ impl ValidatorHandler for LengthHandler {
                         }
                     });
 
+                    let validate_collection = type_attribute.custom.validate(
+                        quote! { <Self as ValidateLength<#data_type>>::parse_collection(::core::clone::Clone::clone(v)) },
+                        quote! {
+                            Self::v_parse_v(v)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateLength<#data_type> for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_collection(v: #data_type) -> Result<Self, Self::Error> {
                                 Self::v_parse_v(&v)?;
 
-                                Ok(Self(v))
+                                #ok(Self(v))
                             }
 
                             #[allow(clippy::ptr_arg)]
                             #[inline]
                             fn validate_collection(v: &#data_type) -> Result<(), Self::Error> {
-                                Self::v_parse_v(v)?;
-
-                                Ok(())
+                                #validate_collection
                             }
                         }
                     });
@@ -180,9 +223,7 @@ impl ValidatorHandler for LengthHandler {
                                         D: validators_prelude::serde::Deserializer<'de>, {
                                         let v: #data_type = validators_prelude::serde::Deserialize::deserialize(deserializer)?;
 
-                                        Self::v_parse_v(&v).map_err(validators_prelude::serde::de::Error::custom)?;
-
-                                        Ok(Self(v))
+                                        <Self as ValidateLength<#data_type>>::parse_collection(v).map_err(validators_prelude::de::error)
                                     }
                                 }
                             });
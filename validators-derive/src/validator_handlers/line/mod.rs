@@ -19,6 +19,8 @@ impl ValidatorHandler for LineHandler {
     fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = Utf8Attribute::build_from_meta(&meta)?;
 
+        let borrowed_type = crate::common::borrowed::borrowed_type(&ast);
+
         if let Data::Struct(data) = ast.data {
             if let Fields::Unnamed(_) = &data.fields {
                 if data.fields.len() == 1 {
@@ -26,9 +28,22 @@ impl ValidatorHandler for LineHandler {
 
                     let name = ast.ident;
 
+                    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::LineError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
                     let (is_byte_length, min, trimmed_min, max) = if let Some((
                         is_byte_length,
                         Length {
@@ -43,17 +58,57 @@ impl ValidatorHandler for LineHandler {
                         (false, None, None, None)
                     };
 
+                    {
+                        let unit = if is_byte_length { "byte" } else { "char" };
+
+                        let (v_min, v_trimmed_min, v_max) = (
+                            format!("{unit}_length_min"),
+                            format!("{unit}_length_trimmed_min"),
+                            format!("{unit}_length_max"),
+                        );
+
+                        crate::common::spec::impl_validator_spec(
+                            &mut token_stream,
+                            &name,
+                            &ast.generics,
+                            "line",
+                            &[
+                                (
+                                    &v_min,
+                                    crate::common::spec::spec_value(
+                                        "Length",
+                                        crate::common::spec::OptionToken(min),
+                                    ),
+                                ),
+                                (
+                                    &v_trimmed_min,
+                                    crate::common::spec::spec_value(
+                                        "Length",
+                                        crate::common::spec::OptionToken(trimmed_min),
+                                    ),
+                                ),
+                                (
+                                    &v_max,
+                                    crate::common::spec::spec_value(
+                                        "Length",
+                                        crate::common::spec::OptionToken(max),
+                                    ),
+                                ),
+                            ],
+                        );
+                    }
+
                     #[cfg(feature = "test")]
                     {
-                        let v_min = crate::common::test::OptionToken(min);
+                        let v_min = crate::common::spec::OptionToken(min);
 
-                        let v_trimmed_min = crate::common::test::OptionToken(trimmed_min);
+                        let v_trimmed_min = crate::common::spec::OptionToken(trimmed_min);
 
-                        let v_max = crate::common::test::OptionToken(max);
+                        let v_max = crate::common::spec::OptionToken(max);
 
                         token_stream.extend(if is_byte_length {
                             quote! {
-                                impl #name {
+                                impl #impl_generics #name #ty_generics #where_clause {
                                     pub(crate) const V_BYTE_LENGTH_MIN: Option<usize> = #v_min;
                                     pub(crate) const V_BYTE_LENGTH_TRIMMED_MIN: Option<usize> = #v_trimmed_min;
                                     pub(crate) const V_BYTE_LENGTH_MAX: Option<usize> = #v_max;
@@ -61,7 +116,7 @@ impl ValidatorHandler for LineHandler {
                             }
                         } else {
                             quote! {
-                                impl #name {
+                                impl #impl_generics #name #ty_generics #where_clause {
                                     pub(crate) const V_CHAR_LENGTH_MIN: Option<usize> = #v_min;
                                     pub(crate) const V_CHAR_LENGTH_TRIMMED_MIN: Option<usize> = #v_trimmed_min;
                                     pub(crate) const V_CHAR_LENGTH_MAX: Option<usize> = #v_max;
@@ -71,691 +126,118 @@ impl ValidatorHandler for LineHandler {
                     }
 
                     let handle_str = {
-                        match max {
-                            Some(max) => match min {
-                                Some(min) => match trimmed_min {
-                                    Some(trimmed_min) => {
-                                        if is_byte_length {
-                                            let handle_trimmed_empty = if trimmed_min == 0 {
-                                                quote! {
-                                                    if counter < #min {
-                                                        Err(#error_path::TooShort)
-                                                    } else {
-                                                        Ok(())
-                                                    }
-                                                }
-                                            } else {
-                                                quote! {
-                                                    Err(#error_path::TooShort)
-                                                }
-                                            };
-
-                                            quote! {
-                                                let mut chars = s.chars();
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    let len = c.len_utf8();
-
-                                                    counter += len;
-
-                                                    if counter > #max {
-                                                        return Err(#error_path::TooLong);
-                                                    }
-
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = len;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    let len = c.len_utf8();
-
-                                                                    counter += len;
-
-                                                                    if counter > #max {
-                                                                        return Err(#error_path::TooLong);
-                                                                    }
-
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += len;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + len;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min && counter >= #min {
-                                                            if counter <= #max {
-                                                                return Ok(());
-                                                            } else {
-                                                                return Err(#error_path::TooLong);
-                                                            }
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        } else {
-                                            let handle_trimmed_empty = if trimmed_min == 0 {
-                                                quote! {
-                                                    if counter < #min {
-                                                        Err(#error_path::TooShort)
-                                                    } else if counter > #max {
-                                                        Err(#error_path::TooLong)
-                                                    } else {
-                                                        Ok(())
-                                                    }
-                                                }
-                                            } else {
-                                                quote! {
-                                                    Err(#error_path::TooShort)
-                                                }
-                                            };
-
-                                            quote! {
-                                                let mut chars = s.chars().take(#max + 1);
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    counter += 1;
-
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = 1;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    counter += 1;
-
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += 1;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + 1;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min && counter >= #min {
-                                                            if counter <= #max {
-                                                                return Ok(());
-                                                            } else {
-                                                                return Err(#error_path::TooLong);
-                                                            }
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        }
-                                    },
-                                    None => {
-                                        if is_byte_length {
-                                            quote! {
-                                                if s.len() > #max {
-                                                    return Err(#error_path::TooLong);
-                                                } else if s.len() < #min {
-                                                    return Err(#error_path::TooShort);
-                                                }
-
-                                                for e in s.bytes() {
-                                                    match e {
-                                                        b'\x00'..=b'\x08' | b'\x0A'..=b'\x1F' | b'\x7F' => {
-                                                            return Err(#error_path::Invalid);
-                                                        }
-                                                        _ => (),
-                                                    }
-                                                }
-
-                                                Ok(())
-                                            }
-                                        } else {
-                                            quote! {
-                                                let mut chars = s.chars().take(#max + 1);
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    match c {
-                                                        '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                            return Err(#error_path::Invalid);
-                                                        }
-                                                        _ => counter += 1,
-                                                    }
-                                                }
-
-                                                if counter < #min {
-                                                    Err(#error_path::TooShort)
-                                                } else if counter > #max {
-                                                    Err(#error_path::TooLong)
-                                                } else {
-                                                    Ok(())
-                                                }
-                                            }
-                                        }
-                                    },
-                                },
-                                None => match trimmed_min {
-                                    Some(trimmed_min) => {
-                                        if is_byte_length {
-                                            let handle_trimmed_empty = if trimmed_min == 0 {
-                                                quote! {
-                                                    Ok(())
-                                                }
-                                            } else {
-                                                quote! {
-                                                    Err(#error_path::TooShort)
-                                                }
-                                            };
-
-                                            quote! {
-                                                let mut chars = s.chars();
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    let len = c.len_utf8();
-
-                                                    counter += len;
-
-                                                    if counter > #max {
-                                                        return Err(#error_path::TooLong);
-                                                    }
-
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = len;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    let len = c.len_utf8();
-
-                                                                    counter += len;
-
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += len;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + len;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min {
-                                                            if counter <= #max {
-                                                                return Ok(());
-                                                            } else {
-                                                                return Err(#error_path::TooLong);
-                                                            }
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        } else {
-                                            let handle_trimmed_empty = if trimmed_min == 0 {
-                                                quote! {
-                                                    if counter <= #max {
-                                                        Ok(())
-                                                    } else {
-                                                        Err(#error_path::TooLong)
-                                                    }
-                                                }
-                                            } else {
-                                                quote! {
-                                                    Err(#error_path::TooShort)
-                                                }
-                                            };
-
-                                            quote! {
-                                                let mut chars = s.chars().take(#max + 1);
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    counter += 1;
-
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = 1;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    counter += 1;
-
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += 1;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + 1;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min {
-                                                            if counter <= #max {
-                                                                return Ok(());
-                                                            } else {
-                                                                return Err(#error_path::TooLong);
-                                                            }
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        }
-                                    },
-                                    None => {
-                                        if is_byte_length {
-                                            quote! {
-                                                if s.len() > #max {
-                                                    return Err(#error_path::TooLong);
-                                                }
-
-                                                for e in s.bytes() {
-                                                    match e {
-                                                        b'\x00'..=b'\x08' | b'\x0A'..=b'\x1F' | b'\x7F' => {
-                                                            return Err(#error_path::Invalid);
-                                                        }
-                                                        _ => (),
-                                                    }
-                                                }
-
-                                                Ok(())
-                                            }
-                                        } else {
-                                            quote! {
-                                                let mut chars = s.chars().take(#max + 1);
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    match c {
-                                                        '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                            return Err(#error_path::Invalid);
-                                                        }
-                                                        _ => counter += 1,
-                                                    }
-                                                }
-
-                                                if counter <= #max {
-                                                    Ok(())
-                                                } else {
-                                                    Err(#error_path::TooLong)
-                                                }
-                                            }
-                                        }
-                                    },
-                                },
-                            },
-                            None => match min {
-                                Some(min) => match trimmed_min {
-                                    Some(trimmed_min) => {
-                                        let handle_trimmed_empty = if trimmed_min == 0 {
-                                            quote! {
-                                                if counter >= #min {
-                                                    Ok(())
-                                                } else {
-                                                    Err(#error_path::TooShort)
-                                                }
-                                            }
-                                        } else {
-                                            quote! {
-                                                Err(#error_path::TooShort)
-                                            }
-                                        };
-
-                                        if is_byte_length {
-                                            quote! {
-                                                let mut chars = s.chars();
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    let len = c.len_utf8();
-
-                                                    counter += len;
-
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = len;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    let len = c.len_utf8();
-
-                                                                    counter += len;
-
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += len;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + len;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min && counter >= #min {
-                                                            return Ok(());
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        } else {
-                                            quote! {
-                                                let mut chars = s.chars();
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    counter += 1;
-
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = 1;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    counter += 1;
-
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += 1;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + 1;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min && counter >= #min {
-                                                            return Ok(());
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        }
-                                    },
-                                    None => {
-                                        if is_byte_length {
-                                            quote! {
-                                                if s.len() < #min {
-                                                    return Err(#error_path::TooShort);
-                                                }
-
-                                                for e in s.bytes() {
-                                                    match e {
-                                                        b'\x00'..=b'\x08' | b'\x0A'..=b'\x1F' | b'\x7F' => {
-                                                            return Err(#error_path::Invalid);
-                                                        }
-                                                        _ => (),
-                                                    }
-                                                }
-
-                                                Ok(())
-                                            }
-                                        } else {
-                                            quote! {
-                                                let mut chars = s.chars();
-                                                let mut counter = 0;
-
-                                                while let Some(c) = chars.next() {
-                                                    match c {
-                                                        '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                            return Err(#error_path::Invalid);
-                                                        }
-                                                        _ => counter += 1,
-                                                    }
-                                                }
-
-                                                if counter >= #min {
-                                                    Ok(())
-                                                } else {
-                                                    Err(#error_path::TooShort)
-                                                }
-                                            }
-                                        }
-                                    },
-                                },
-                                None => match trimmed_min {
-                                    Some(trimmed_min) => {
-                                        let handle_trimmed_empty = if trimmed_min == 0 {
-                                            quote! {
-                                                Ok(())
-                                            }
-                                        } else {
-                                            quote! {
-                                                Err(#error_path::TooShort)
-                                            }
-                                        };
-
-                                        if is_byte_length {
-                                            quote! {
-                                                let mut chars = s.chars();
-
-                                                while let Some(c) = chars.next() {
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = c.len_utf8();
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += c.len_utf8();
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + c.len_utf8();
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min {
-                                                            return Ok(());
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        } else {
-                                            quote! {
-                                                let mut chars = s.chars();
-
-                                                while let Some(c) = chars.next() {
-                                                    if !c.is_whitespace() {
-                                                        match c {
-                                                            '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                return Err(#error_path::Invalid);
-                                                            }
-                                                            _ => (),
-                                                        }
-
-                                                        let mut trimmed_counter = 1;
-                                                        let mut temp_counter = 0;
-
-                                                        while let Some(c) = chars.next() {
-                                                            match c {
-                                                                '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F' => {
-                                                                    return Err(#error_path::Invalid);
-                                                                }
-                                                                _ => {
-                                                                    if c.is_whitespace() {
-                                                                        temp_counter += 1;
-                                                                    } else {
-                                                                        trimmed_counter += temp_counter + 1;
-                                                                        temp_counter = 0;
-                                                                    }
-                                                                },
-                                                            }
-                                                        }
-
-                                                        if trimmed_counter >= #trimmed_min {
-                                                            return Ok(());
-                                                        } else {
-                                                            return Err(#error_path::TooShort);
-                                                        }
-                                                    }
-                                                }
-
-                                                #handle_trimmed_empty
-                                            }
-                                        }
-                                    },
-                                    None => {
-                                        quote! {
-                                            for e in s.bytes() {
-                                                match e {
-                                                    b'\x00'..=b'\x08' | b'\x0A'..=b'\x1F' | b'\x7F' => {
-                                                        return Err(#error_path::Invalid);
-                                                    }
-                                                    _ => (),
-                                                }
-                                            }
+                        let unit = if is_byte_length {
+                            quote! { validators_prelude::LengthUnit::Byte }
+                        } else {
+                            quote! { validators_prelude::LengthUnit::Char }
+                        };
 
-                                            Ok(())
-                                        }
-                                    },
-                                },
-                            },
+                        let min = crate::common::spec::OptionToken(min);
+                        let trimmed_min = crate::common::spec::OptionToken(trimmed_min);
+                        let max = crate::common::spec::OptionToken(max);
+
+                        quote! {
+                            validators_prelude::validate_line(s, #unit, #min, #trimmed_min, #max)
                         }
                     };
 
                     token_stream.extend(quote! {
-                        impl #name {
+                        impl #impl_generics #name #ty_generics #where_clause {
                             fn v_parse_str(s: &str) -> Result<(), #error_path> {
                                 #handle_str
                             }
                         }
                     });
 
+                    let expect = {
+                        use std::fmt::Write;
+
+                        let mut s = String::from("a line");
+
+                        if min.is_some() || max.is_some() || trimmed_min.is_some() {
+                            s.push_str(" in ");
+
+                            if let Some(min) = min {
+                                s.write_fmt(format_args!("{min}")).unwrap();
+                            }
+
+                            if let Some(trimmed_min) = trimmed_min {
+                                s.write_fmt(format_args!("({trimmed_min})")).unwrap();
+                            }
+
+                            s.push_str("..");
+
+                            if let Some(max) = max {
+                                s.write_fmt(format_args!("={max}")).unwrap();
+                            }
+                        }
+
+                        s
+                    };
+
+                    if let Some(borrowed_type) = borrowed_type {
+                        crate::common::borrowed::impl_borrowed(
+                            &mut token_stream,
+                            &name,
+                            borrowed_type,
+                            &error_type,
+                            &type_attribute.custom,
+                            type_attribute.normalize.as_ref(),
+                            &type_attribute.serde_options,
+                            &type_attribute.rocket_options,
+                            &expect,
+                        )?;
+
+                        return Ok(token_stream);
+                    }
+
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_string
+
                                 let s = s.into();
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(Self(s))
+                                #ok(Self(s))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_str
+
                                 let s = s.as_ref();
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(Self(validators_prelude::String::from(s)))
+                                #ok(Self(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -776,32 +258,6 @@ impl ValidatorHandler for LineHandler {
                         }
 
                         if type_attribute.serde_options.deserialize {
-                            use std::fmt::Write;
-
-                            let expect = {
-                                let mut s = String::from("a line");
-
-                                if min.is_some() || max.is_some() || trimmed_min.is_some() {
-                                    s.push_str(" in ");
-
-                                    if let Some(min) = min {
-                                        s.write_fmt(format_args!("{min}")).unwrap();
-                                    }
-
-                                    if let Some(trimmed_min) = trimmed_min {
-                                        s.write_fmt(format_args!("({trimmed_min})")).unwrap();
-                                    }
-
-                                    s.push_str("..");
-
-                                    if let Some(max) = max {
-                                        s.write_fmt(format_args!("={max}")).unwrap();
-                                    }
-                                }
-
-                                s
-                            };
-
                             token_stream.extend(quote! {
                                 impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
                                     #[inline]
@@ -822,14 +278,14 @@ impl ValidatorHandler for LineHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -850,7 +306,7 @@ impl ValidatorHandler for LineHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
@@ -5,10 +5,9 @@ use quote::quote;
 use syn::{Data, DeriveInput, Fields, Meta, Path};
 
 use super::ValidatorHandler;
-use crate::{
-    common::{case_option::CaseOption, separator_option::SeparatorOption, type_enum::TypeEnum},
-    panic,
-};
+#[cfg(feature = "serde")]
+use crate::common::{case_option::CaseOption, separator_option::SeparatorOption};
+use crate::{common::type_enum::TypeEnum, panic};
 
 pub(crate) struct MacAddressHandler;
 
@@ -32,6 +31,34 @@ impl ValidatorHandler for MacAddressHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::MacAddressError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "mac_address",
+                        &[
+                            ("case", crate::common::spec::spec_value("Case", type_attribute.case)),
+                            (
+                                "separator",
+                                crate::common::spec::spec_value(
+                                    "Separator",
+                                    type_attribute.separator,
+                                ),
+                            ),
+                        ],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_case = type_attribute.case;
@@ -45,237 +72,64 @@ impl ValidatorHandler for MacAddressHandler {
                         });
                     }
 
-                    let handle_iter = {
-                        match type_attribute.separator {
-                            SeparatorOption::Allow(separator) => {
-                                quote! {
-                                    if !(12..=17).contains(&length) {
-                                        return Err(#error_path::Invalid);
-                                    }
-
-                                    let first = &bytes[0..2];
-
-                                    let mut no_colon_counter = if bytes[2] != #separator {
-                                        1
-                                    } else {
-                                        0
-                                    };
-
-                                    let second = &bytes[(3 - no_colon_counter)..(5 - no_colon_counter)];
-
-                                    if bytes[5 - no_colon_counter] != #separator {
-                                        no_colon_counter += 1;
-                                    }
-
-                                    let third = &bytes[(6 - no_colon_counter)..(8 - no_colon_counter)];
-
-                                    if bytes[8 - no_colon_counter] != #separator {
-                                        no_colon_counter += 1;
-                                    }
-
-                                    let forth = &bytes[(9 - no_colon_counter)..(11 - no_colon_counter)];
-
-                                    if bytes[11 - no_colon_counter] != #separator {
-                                        no_colon_counter += 1;
-                                    }
-
-                                    let fifth = &bytes[(12 - no_colon_counter)..(14 - no_colon_counter)];
-
-                                    if bytes[14 - no_colon_counter] != #separator {
-                                        no_colon_counter += 1;
-                                    }
-
-                                    let sixth = &bytes[(15 - no_colon_counter)..];
-
-                                    if sixth.len() != 2 {
-                                        return Err(#error_path::Invalid);
-                                    }
-
-                                    first.iter().chain(second).chain(third).chain(forth).chain(fifth).chain(sixth).copied()
-                                }
-                            },
-                            SeparatorOption::Must(separator) => {
-                                quote! {
-                                    if length != 17 {
-                                        return Err(#error_path::SeparatorMust);
-                                    }
-
-                                    if bytes[2] != #separator
-                                        || bytes[5] != #separator
-                                        || bytes[8] != #separator
-                                        || bytes[11] != #separator
-                                        || bytes[14] != #separator
-                                    {
-                                        return Err(#error_path::Invalid);
-                                    }
-
-                                    let first = &bytes[0..2];
-                                    let second = &bytes[3..5];
-                                    let third = &bytes[6..8];
-                                    let forth = &bytes[9..11];
-                                    let fifth = &bytes[12..14];
-                                    let sixth = &bytes[15..];
-
-                                    first.iter().chain(second).chain(third).chain(forth).chain(fifth).chain(sixth).copied()
-                                }
-                            },
-                            SeparatorOption::Disallow => {
-                                quote! {
-                                    if length != 12 {
-                                        return Err(#error_path::SeparatorDisallow);
-                                    }
-
-                                    bytes.iter().copied()
-                                }
-                            },
-                        }
-                    };
-
-                    let handle_decode = {
-                        match type_attribute.case {
-                            CaseOption::Any => {
-                                quote! {
-                                    for e in iter {
-                                        mac_address_decoded <<= 4;
-
-                                        match e {
-                                            b'0'..=b'9' => {
-                                                mac_address_decoded |= u64::from(e - b'0');
-                                            }
-                                            b'a'..=b'f' => {
-                                                mac_address_decoded |= u64::from(e - (b'a' - 10));
-                                            }
-                                            b'A'..=b'F' => {
-                                                mac_address_decoded |= u64::from(e - (b'A' - 10));
-                                            }
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                            CaseOption::Upper => {
-                                quote! {
-                                    for e in iter {
-                                        mac_address_decoded <<= 4;
-
-                                        match e {
-                                            b'0'..=b'9' => {
-                                                mac_address_decoded |= u64::from(e - b'0');
-                                            }
-                                            b'A'..=b'F' => {
-                                                mac_address_decoded |= u64::from(e - (b'A' - 10));
-                                            }
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                            CaseOption::Lower => {
-                                quote! {
-                                    for e in iter {
-                                        mac_address_decoded <<= 4;
-
-                                        match e {
-                                            b'0'..=b'9' => {
-                                                mac_address_decoded |= u64::from(e - b'0');
-                                            }
-                                            b'a'..=b'f' => {
-                                                mac_address_decoded |= u64::from(e - (b'a' - 10));
-                                            }
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                        }
-                    };
-
-                    let handle_check = {
-                        match type_attribute.case {
-                            CaseOption::Any => {
-                                quote! {
-                                    for e in iter {
-                                        match e {
-                                            b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F' => (),
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                            CaseOption::Upper => {
-                                quote! {
-                                    for e in iter {
-                                        match e {
-                                            b'0'..=b'9' | b'A'..=b'F' => (),
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                            CaseOption::Lower => {
-                                quote! {
-                                    for e in iter {
-                                        match e {
-                                            b'0'..=b'9' | b'a'..=b'f' => (),
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                        }
-                    };
+                    let case = type_attribute.case;
+                    let separator = type_attribute.separator;
 
                     token_stream.extend(quote! {
                         impl #name {
+                            #[inline]
                             fn v_parse_str(s: &str) -> Result<u64, #error_path> {
-                                let bytes = s.as_bytes();
-                                let length = bytes.len();
-
-                                let iter = {
-                                    #handle_iter
-                                };
-
-                                let mut mac_address_decoded = 0u64;
-
-                                #handle_decode
-
-                                Ok(mac_address_decoded)
+                                validators_prelude::parse_mac_address(s, #case, #separator)
                             }
 
+                            #[inline]
                             fn v_validate_str(s: &str) -> Result<(), #error_path> {
-                                let bytes = s.as_bytes();
-                                let length = bytes.len();
-
-                                let iter = {
-                                    #handle_iter
-                                };
-
-                                #handle_check
+                                validators_prelude::parse_mac_address(s, #case, #separator)?;
 
                                 Ok(())
                             }
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_validate_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_validate_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -290,7 +144,7 @@ impl ValidatorHandler for MacAddressHandler {
 
                                         validators_prelude::format!(
                                             "{:02X}{separator}{:02X}{separator}{:02X}{separator}{:02X}{separator}{:02X}{separator}{:02X}",
-                                            bytes[5], bytes[4], bytes[3], bytes[2], bytes[1], bytes[0], separator = #separator
+                                            bytes[5], bytes[4], bytes[3], bytes[2], bytes[1], bytes[0], separator = #separator as char
                                         )
                                     }
                                 }
@@ -314,7 +168,7 @@ impl ValidatorHandler for MacAddressHandler {
 
                                     validators_prelude::format!(
                                         "{:02x}{separator}{:02x}{separator}{:02x}{separator}{:02x}{separator}{:02x}{separator}{:02x}",
-                                        bytes[5], bytes[4], bytes[3], bytes[2], bytes[1], bytes[0], separator = #separator
+                                        bytes[5], bytes[4], bytes[3], bytes[2], bytes[1], bytes[0], separator = #separator as char
                                     )
                                 }
                             }
@@ -404,7 +258,7 @@ impl ValidatorHandler for MacAddressHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -425,7 +279,7 @@ impl ValidatorHandler for MacAddressHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
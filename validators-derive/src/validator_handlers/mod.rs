@@ -1,5 +1,11 @@
 use syn::{DeriveInput, Meta};
 
+#[cfg(feature = "all")]
+pub(crate) mod all;
+
+#[cfg(feature = "any")]
+pub(crate) mod any;
+
 #[cfg(feature = "base32")]
 pub(crate) mod base32;
 
@@ -63,9 +69,15 @@ pub(crate) mod line;
 #[cfg(feature = "mac_address")]
 pub(crate) mod mac_address;
 
+#[cfg(feature = "not")]
+pub(crate) mod not;
+
 #[cfg(feature = "number")]
 pub(crate) mod number;
 
+#[cfg(feature = "path")]
+pub(crate) mod path;
+
 #[cfg(feature = "phone")]
 pub(crate) mod phone;
 
@@ -67,6 +67,44 @@ impl ValidatorHandler for NumberHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::NumberError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "number",
+                        &[
+                            (
+                                "nan",
+                                crate::common::spec::spec_value("TriAllow", type_attribute.nan),
+                            ),
+                            (
+                                "range",
+                                crate::common::spec::spec_value(
+                                    "NumberRange",
+                                    type_attribute.range.to_spec_tokens(quote! { f64 }),
+                                ),
+                            ),
+                            (
+                                "conflict",
+                                crate::common::spec::spec_value(
+                                    "TriAllow",
+                                    type_attribute.conflict,
+                                ),
+                            ),
+                        ],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_nan = type_attribute.nan;
@@ -80,115 +118,15 @@ impl ValidatorHandler for NumberHandler {
                         });
                     }
 
-                    let handle_range = {
-                        match &type_attribute.range {
-                            RangeTokenStream::Inside {
-                                min,
-                                max,
-                                inclusive,
-                                equal,
-                            } => {
-                                if *equal {
-                                    quote! {
-                                        match ::core::cmp::PartialOrd::partial_cmp(&f, &#min) {
-                                            Some(::core::cmp::Ordering::Equal) | None => (),
-                                            Some(::core::cmp::Ordering::Less) => return Err(#error_path::TooSmall),
-                                            Some(::core::cmp::Ordering::Greater) => return Err(#error_path::TooLarge),
-                                        }
-                                    }
-                                } else {
-                                    let mut token_stream = proc_macro2::TokenStream::new();
-
-                                    if let Some(min) = min {
-                                        token_stream.extend(quote! {
-                                            if f < #min {
-                                                return Err(#error_path::TooSmall);
-                                            }
-                                        });
-                                    }
-
-                                    if let Some(max) = max {
-                                        token_stream.extend(if *inclusive {
-                                            quote! {
-                                                if f > #max {
-                                                    return Err(#error_path::TooLarge);
-                                                }
-                                            }
-                                        } else {
-                                            quote! {
-                                                if f >= #max {
-                                                    return Err(#error_path::TooLarge);
-                                                }
-                                            }
-                                        });
-                                    }
+                    let handle_range = match &type_attribute.range {
+                        RangeTokenStream::Unlimited => quote! {},
+                        range => {
+                            let range = range.to_spec_tokens(quote! { f64 });
 
-                                    token_stream
-                                }
-                            },
-                            RangeTokenStream::Outside {
-                                min,
-                                max,
-                                inclusive,
-                                equal,
-                            } => {
-                                if *equal {
-                                    quote! {
-                                        if f == #min {
-                                            return Err(#error_path::Forbidden);
-                                        }
-                                    }
-                                } else {
-                                    match min {
-                                        Some(min) => match max {
-                                            Some(max) => {
-                                                if *inclusive {
-                                                    quote! {
-                                                        if (#min..=#max).contains(&f){
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                } else {
-                                                    quote! {
-                                                        if (#min..#max).contains(&f){
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                }
-                                            },
-                                            None => {
-                                                quote! {
-                                                    if f >= #min {
-                                                        return Err(#error_path::Forbidden);
-                                                    }
-                                                }
-                                            },
-                                        },
-                                        None => match max {
-                                            Some(max) => {
-                                                if *inclusive {
-                                                    quote! {
-                                                        if f <= #max {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                } else {
-                                                    quote! {
-                                                        if f < #max {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                }
-                                            },
-                                            None => {
-                                                quote! {}
-                                            },
-                                        },
-                                    }
-                                }
-                            },
-                            RangeTokenStream::Unlimited => quote! {},
-                        }
+                            quote! {
+                                validators_prelude::check_range(&#range, f as f64)?;
+                            }
+                        },
                     };
 
                     let handle_nan = {
@@ -233,47 +171,83 @@ impl ValidatorHandler for NumberHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
 
+                    let validate_f64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateNumber>::parse_f64(f) },
+                        quote! {
+                            Self::v_parse_f(f)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_f32 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateNumber>::parse_f32(f) },
+                        quote! {
+                            Self::v_parse_f(f)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(match number_type {
                         NumberType::F64 => {
                             quote! {
                                 impl ValidateNumber for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_f64(f: f64) -> Result<Self, Self::Error> {
                                         Self::v_parse_f(f)?;
 
-                                        Ok(Self(f))
+                                        #ok(Self(f))
                                     }
 
                                     #[inline]
                                     fn validate_f64(f: f64) -> Result<(), Self::Error> {
-                                        Self::v_parse_f(f)?;
-
-                                        Ok(())
+                                        #validate_f64
                                     }
                                 }
                             }
@@ -281,7 +255,7 @@ impl ValidatorHandler for NumberHandler {
                         NumberType::F32 => {
                             quote! {
                                 impl ValidateNumber for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_f64(f: f64) -> Result<Self, Self::Error> {
@@ -297,14 +271,12 @@ impl ValidatorHandler for NumberHandler {
                                     fn parse_f32(f: f32) -> Result<Self, Self::Error> {
                                         Self::v_parse_f(f)?;
 
-                                        Ok(Self(f))
+                                        #ok(Self(f))
                                     }
 
                                     #[inline]
                                     fn validate_f32(f: f32) -> Result<(), Self::Error> {
-                                        Self::v_parse_f(f)?;
-
-                                        Ok(())
+                                        #validate_f32
                                     }
                                 }
                             }
@@ -413,28 +385,28 @@ impl ValidatorHandler for NumberHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateNumber>::parse_f64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateNumber>::parse_f64(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateNumber>::parse_f32(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateNumber>::parse_f32(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -455,7 +427,7 @@ impl ValidatorHandler for NumberHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
@@ -5,6 +5,8 @@ use syn::{punctuated::Punctuated, Meta, Token};
 use crate::{
     common::{
         allow::Allow,
+        custom_option::CustomOption,
+        normalize_option::NormalizeOption,
         range::RangedNumber,
         range_option::{RangeOption, RangeTokenStream},
         rocket_options::RocketOptions,
@@ -22,19 +24,24 @@ pub(crate) struct NumberAttribute {
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
 }
 
 impl NumberAttribute {
     pub(crate) fn build_from_meta<T: RangedNumber>(meta: &Meta) -> syn::Result<Self>
     where
         T::Err: Display, {
-        let correct_parameters = ["range", "nan", "conflict", "serde", "rocket"];
+        let correct_parameters =
+            ["range", "nan", "conflict", "serde", "rocket", "custom", "normalize"];
 
         let mut range = RangeTokenStream::Unlimited;
         let mut nan = TriAllow::Allow;
         let mut conflict = Allow::Disallow;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -50,6 +57,8 @@ impl NumberAttribute {
                 let mut conflict_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -119,6 +128,32 @@ impl NumberAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -143,6 +178,8 @@ impl NumberAttribute {
             conflict,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
@@ -0,0 +1,402 @@
+mod path_attribute;
+
+use path_attribute::PathAttribute;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, Path};
+
+use super::ValidatorHandler;
+use crate::{
+    common::{tri_allow::TriAllow, type_enum::TypeEnum},
+    panic,
+};
+
+pub(crate) struct PathHandler;
+
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct Struct(TypeEnum);
+
+const ITEM: Struct = Struct(TypeEnum::PathBuf);
+
+impl ValidatorHandler for PathHandler {
+    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+        let type_attribute = PathAttribute::build_from_meta(&meta)?;
+
+        if let Data::Struct(data) = ast.data {
+            if let Fields::Unnamed(_) = &data.fields {
+                if data.fields.len() == 1 {
+                    let mut token_stream = proc_macro2::TokenStream::new();
+
+                    let name = ast.ident;
+
+                    let error_path: Path =
+                        syn::parse2(quote! { validators_prelude::PathError }).unwrap();
+
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    {
+                        let mut options = vec![
+                            (
+                                "absolute",
+                                crate::common::spec::spec_value(
+                                    "TriAllow",
+                                    type_attribute.absolute,
+                                ),
+                            ),
+                            (
+                                "root",
+                                crate::common::spec::spec_value("TriAllow", type_attribute.root),
+                            ),
+                            (
+                                "parent",
+                                crate::common::spec::spec_value("TriAllow", type_attribute.parent),
+                            ),
+                            (
+                                "windows_reserved",
+                                crate::common::spec::spec_value(
+                                    "TriAllow",
+                                    type_attribute.windows_reserved,
+                                ),
+                            ),
+                            (
+                                "component_length_max",
+                                crate::common::spec::spec_value(
+                                    "Length",
+                                    crate::common::spec::OptionToken(
+                                        type_attribute.component_length_max,
+                                    ),
+                                ),
+                            ),
+                        ];
+
+                        if let Some(extensions) = &type_attribute.extensions {
+                            options.push((
+                                "extensions",
+                                crate::common::spec::spec_value(
+                                    "Strs",
+                                    quote! { &[#(#extensions),*] },
+                                ),
+                            ));
+                        }
+
+                        crate::common::spec::impl_validator_spec(
+                            &mut token_stream,
+                            &name,
+                            &ast.generics,
+                            "path",
+                            &options,
+                        );
+                    }
+
+                    #[cfg(feature = "test")]
+                    {
+                        let v_absolute = type_attribute.absolute;
+                        let v_root = type_attribute.root;
+                        let v_parent = type_attribute.parent;
+                        let v_windows_reserved = type_attribute.windows_reserved;
+                        let v_extensions = crate::common::spec::OptionToken(
+                            type_attribute
+                                .extensions
+                                .as_ref()
+                                .map(|extensions| quote! { &[#(#extensions),*] }),
+                        );
+                        let v_component_length_max =
+                            crate::common::spec::OptionToken(type_attribute.component_length_max);
+
+                        token_stream.extend(quote! {
+                            impl #name {
+                                pub(crate) const V_ABSOLUTE: validators_prelude::TriAllow = #v_absolute;
+                                pub(crate) const V_ROOT: validators_prelude::TriAllow = #v_root;
+                                pub(crate) const V_PARENT: validators_prelude::TriAllow = #v_parent;
+                                pub(crate) const V_WINDOWS_RESERVED: validators_prelude::TriAllow = #v_windows_reserved;
+                                pub(crate) const V_EXTENSIONS: Option<&'static [&'static str]> = #v_extensions;
+                                pub(crate) const V_COMPONENT_LENGTH_MAX: Option<usize> = #v_component_length_max;
+                            }
+                        });
+                    }
+
+                    let check_absolute = match type_attribute.absolute {
+                        TriAllow::Allow => quote! {},
+                        TriAllow::Must => quote! {
+                            if !path.is_absolute() {
+                                return Err(#error_path::AbsoluteMust);
+                            }
+                        },
+                        TriAllow::Disallow => quote! {
+                            if path.is_absolute() {
+                                return Err(#error_path::AbsoluteDisallow);
+                            }
+                        },
+                    };
+
+                    let check_components = {
+                        let mut arms = Vec::new();
+
+                        if type_attribute.root.disallow() {
+                            arms.push(quote! {
+                                ::std::path::Component::Prefix(_) | ::std::path::Component::RootDir => {
+                                    return Err(#error_path::RootDisallow);
+                                },
+                            });
+                        }
+
+                        if type_attribute.parent.disallow() {
+                            arms.push(quote! {
+                                ::std::path::Component::ParentDir => {
+                                    return Err(#error_path::ParentDisallow);
+                                },
+                            });
+                        }
+
+                        let check_component_length = match type_attribute.component_length_max {
+                            Some(max) => quote! {
+                                let length = name.len();
+
+                                if length > #max {
+                                    return Err(#error_path::ComponentTooLong {
+                                        max: #max, length
+                                    });
+                                }
+                            },
+                            None => quote! {},
+                        };
+
+                        let check_windows_reserved = if type_attribute.windows_reserved.disallow() {
+                            quote! {
+                                if validators_prelude::is_windows_reserved_name(name) {
+                                    return Err(#error_path::WindowsReservedDisallow);
+                                }
+                            }
+                        } else {
+                            quote! {}
+                        };
+
+                        if type_attribute.component_length_max.is_some()
+                            || type_attribute.windows_reserved.disallow()
+                        {
+                            arms.push(quote! {
+                                ::std::path::Component::Normal(name) => {
+                                    #check_component_length
+
+                                    #check_windows_reserved
+                                },
+                            });
+                        }
+
+                        if arms.is_empty() {
+                            quote! {}
+                        } else {
+                            quote! {
+                                for component in path.components() {
+                                    match component {
+                                        #(#arms)*
+                                        _ => (),
+                                    }
+                                }
+                            }
+                        }
+                    };
+
+                    let check_extension = match &type_attribute.extensions {
+                        Some(extensions) => quote! {
+                            match path.extension().and_then(|extension| extension.to_str()) {
+                                Some(extension) if [#(#extensions),*].iter().any(|e| extension.eq_ignore_ascii_case(e)) => (),
+                                _ => return Err(#error_path::ExtensionNotAllowed),
+                            }
+                        },
+                        None => quote! {},
+                    };
+
+                    token_stream.extend(quote! {
+                        impl #name {
+                            #[inline]
+                            fn v_parse_os_str(s: &::std::ffi::OsStr) -> Result<(), #error_path> {
+                                if s.is_empty() {
+                                    return Err(#error_path::Empty);
+                                }
+
+                                if validators_prelude::contains_nul(s) {
+                                    return Err(#error_path::ContainsNul);
+                                }
+
+                                let path = ::std::path::Path::new(s);
+
+                                #check_absolute
+
+                                #check_components
+
+                                #check_extension
+
+                                Ok(())
+                            }
+                        }
+                    });
+
+                    let validate_os_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateOsStr>::parse_os_str(s) },
+                        quote! {
+                            Self::v_parse_os_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            <Self as ValidateOsStr>::validate_os_str(s.as_ref())
+                        },
+                    );
+
+                    token_stream.extend(quote! {
+                        impl ValidateOsStr for #name {
+                            type Error = #error_type;
+
+                            #[inline]
+                            fn parse_os_string<S: Into<::std::ffi::OsString>>(s: S) -> Result<Self, Self::Error> {
+                                let s = s.into();
+
+                                Self::v_parse_os_str(&s)?;
+
+                                #ok(#name(::std::path::PathBuf::from(s)))
+                            }
+
+                            #[inline]
+                            fn parse_os_str<S: AsRef<::std::ffi::OsStr>>(s: S) -> Result<Self, Self::Error> {
+                                let s = s.as_ref();
+
+                                Self::v_parse_os_str(s)?;
+
+                                #ok(#name(::std::path::PathBuf::from(s)))
+                            }
+
+                            #[inline]
+                            fn validate_os_str<S: AsRef<::std::ffi::OsStr>>(s: S) -> Result<(), Self::Error> {
+                                #validate_os_str
+                            }
+                        }
+
+                        impl ValidateString for #name {
+                            type Error = #error_type;
+
+                            #[inline]
+                            fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_string
+
+                                <Self as ValidateOsStr>::parse_os_string(s.into())
+                            }
+
+                            #[inline]
+                            fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_str
+
+                                <Self as ValidateOsStr>::parse_os_str(s.as_ref())
+                            }
+
+                            #[inline]
+                            fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                                #validate_str
+                            }
+                        }
+                    });
+
+                    #[cfg(feature = "serde")]
+                    {
+                        if type_attribute.serde_options.serialize {
+                            token_stream.extend(quote! {
+                                impl validators_prelude::serde::Serialize for #name {
+                                    #[inline]
+                                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                                        where
+                                            S: validators_prelude::serde::Serializer, {
+                                        match self.0.to_str() {
+                                            Some(s) => serializer.serialize_str(s),
+                                            None => Err(validators_prelude::serde::ser::Error::custom("path contains invalid UTF-8 characters")),
+                                        }
+                                    }
+                                }
+                            });
+                        }
+
+                        if type_attribute.serde_options.deserialize {
+                            let expect = "a path";
+
+                            token_stream.extend(quote! {
+                                impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
+                                    #[inline]
+                                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                                    where
+                                        D: validators_prelude::serde::Deserializer<'de>, {
+                                        struct MyVisitor;
+
+                                        impl<'de> validators_prelude::serde::de::Visitor<'de> for MyVisitor {
+                                            type Value = #name;
+
+                                            #[inline]
+                                            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                                f.write_str(#expect)
+                                            }
+
+                                            #[inline]
+                                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
+                                            }
+
+                                            #[inline]
+                                            fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
+                                            }
+                                        }
+
+                                        deserializer.deserialize_string(MyVisitor)
+                                    }
+                                }
+                            });
+                        }
+                    }
+
+                    #[cfg(feature = "rocket")]
+                    {
+                        if type_attribute.rocket_options.from_form_field {
+                            crate::common::rocket::impl_from_form_field(&mut token_stream, &name);
+                        }
+
+                        if type_attribute.rocket_options.from_param {
+                            crate::common::rocket::impl_from_param(
+                                &mut token_stream,
+                                &name,
+                                &error_type,
+                            );
+                        }
+                    }
+
+                    return Ok(token_stream);
+                }
+            }
+        }
+
+        Err(panic::validator_for_specific_item(meta.path().get_ident().unwrap(), ITEM))
+    }
+}
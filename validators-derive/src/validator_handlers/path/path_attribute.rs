@@ -0,0 +1,291 @@
+use syn::{punctuated::Punctuated, spanned::Spanned, LitStr, Meta, Token};
+
+use crate::{
+    common::{
+        allow::Allow, custom_option::CustomOption, normalize_option::NormalizeOption,
+        number::meta_2_number, rocket_options::RocketOptions, serde_options::SerdeOptions,
+        tri_allow::TriAllow,
+    },
+    panic,
+};
+
+pub(crate) struct PathAttribute {
+    pub(crate) absolute:             TriAllow,
+    pub(crate) root:                 Allow,
+    pub(crate) parent:               Allow,
+    pub(crate) windows_reserved:     Allow,
+    pub(crate) extensions:           Option<Vec<String>>,
+    pub(crate) component_length_max: Option<usize>,
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(crate) serde_options:        SerdeOptions,
+    #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
+    pub(crate) rocket_options:       RocketOptions,
+    pub(crate) custom:               CustomOption,
+    pub(crate) normalize:            Option<NormalizeOption>,
+}
+
+impl PathAttribute {
+    pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
+        let correct_parameters = [
+            "absolute",
+            "root",
+            "parent",
+            "windows_reserved",
+            "extensions",
+            "component_length",
+            "serde",
+            "rocket",
+            "custom",
+            "normalize",
+        ];
+
+        let mut absolute = TriAllow::Allow;
+        let mut root = Allow::Allow;
+        let mut parent = Allow::Allow;
+        let mut windows_reserved = Allow::Allow;
+        let mut extensions = None;
+        let mut component_length_max = None;
+        let mut serde_options = SerdeOptions::default();
+        let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
+
+        match meta {
+            Meta::Path(_) => (),
+            Meta::NameValue(_) => {
+                return Err(panic::attribute_incorrect_format(meta.path().get_ident().unwrap()));
+            },
+            Meta::List(list) => {
+                let result =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+                let mut absolute_is_set = false;
+                let mut root_is_set = false;
+                let mut parent_is_set = false;
+                let mut windows_reserved_is_set = false;
+                let mut extensions_is_set = false;
+                let mut component_length_is_set = false;
+                let mut serde_options_is_set = false;
+                let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
+
+                let mut handler = |meta: &Meta| -> syn::Result<bool> {
+                    if let Some(ident) = meta.path().get_ident() {
+                        match ident.to_string().as_str() {
+                            "absolute" => {
+                                let v = TriAllow::from_meta(meta)?;
+
+                                if absolute_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                absolute_is_set = true;
+
+                                absolute = v;
+
+                                return Ok(true);
+                            },
+                            "root" => {
+                                let v = Allow::from_meta(meta)?;
+
+                                if root_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                root_is_set = true;
+
+                                root = v;
+
+                                return Ok(true);
+                            },
+                            "parent" => {
+                                let v = Allow::from_meta(meta)?;
+
+                                if parent_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                parent_is_set = true;
+
+                                parent = v;
+
+                                return Ok(true);
+                            },
+                            "windows_reserved" => {
+                                let v = Allow::from_meta(meta)?;
+
+                                if windows_reserved_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                windows_reserved_is_set = true;
+
+                                windows_reserved = v;
+
+                                return Ok(true);
+                            },
+                            "extensions" => {
+                                let v = meta_2_extensions(meta)?;
+
+                                if extensions_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                extensions_is_set = true;
+
+                                extensions = Some(v);
+
+                                return Ok(true);
+                            },
+                            "component_length" => {
+                                let v = meta_2_component_length_max(meta)?;
+
+                                if component_length_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                component_length_is_set = true;
+
+                                component_length_max = Some(v);
+
+                                return Ok(true);
+                            },
+                            "serde" => {
+                                let v = SerdeOptions::from_meta(meta)?;
+
+                                if serde_options_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                serde_options_is_set = true;
+
+                                serde_options = v;
+
+                                return Ok(true);
+                            },
+                            "rocket" => {
+                                let v = RocketOptions::from_meta(meta)?;
+
+                                if rocket_options_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                rocket_options_is_set = true;
+
+                                rocket_options = v;
+
+                                return Ok(true);
+                            },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
+                            _ => (),
+                        }
+                    }
+
+                    Ok(false)
+                };
+
+                for p in result {
+                    if !handler(&p)? {
+                        return Err(panic::parameter_incorrect_format(
+                            p.path(),
+                            &correct_parameters,
+                        ));
+                    }
+                }
+            },
+        }
+
+        Ok(Self {
+            absolute,
+            root,
+            parent,
+            windows_reserved,
+            extensions,
+            component_length_max,
+            serde_options,
+            rocket_options,
+            custom,
+            normalize,
+        })
+    }
+}
+
+fn meta_2_extensions(meta: &Meta) -> syn::Result<Vec<String>> {
+    if let Meta::List(list) = meta {
+        let result =
+            list.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_separated_nonempty)?;
+
+        let mut extensions: Vec<String> = Vec::with_capacity(result.len());
+
+        for lit in result {
+            let extension = lit.value();
+
+            if extension.is_empty() || extension.contains(['.', '/', '\\']) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "an extension must not be empty or contain `.`, `/` and `\\`, such as \
+                     `\"txt\"`",
+                ));
+            }
+
+            if extensions.iter().any(|e| e.eq_ignore_ascii_case(&extension)) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("the extension `{extension}` of the path validator is repeated"),
+                ));
+            }
+
+            extensions.push(extension);
+        }
+
+        return Ok(extensions);
+    }
+
+    let path = meta.path();
+
+    Err(syn::Error::new(path.span(), "expected `extensions(\"txt\", \"md\", ...)`"))
+}
+
+fn meta_2_component_length_max(meta: &Meta) -> syn::Result<usize> {
+    if let Meta::List(list) = meta {
+        let result = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+        if result.len() == 1 {
+            let meta = &result[0];
+
+            if meta.path().is_ident("max") {
+                return meta_2_number(meta);
+            }
+        }
+    }
+
+    let path = meta.path();
+
+    Err(syn::Error::new(path.span(), "expected `component_length(max = 255)`"))
+}
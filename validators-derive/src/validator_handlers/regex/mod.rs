@@ -1,6 +1,6 @@
 mod regex_attribute;
 
-use quote::quote;
+use quote::{quote, ToTokens};
 use regex_attribute::RegexAttribute;
 use syn::{Data, DeriveInput, Fields, Meta, Path};
 
@@ -19,6 +19,8 @@ impl ValidatorHandler for RegexHandler {
     fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = RegexAttribute::build_from_meta(&meta)?;
 
+        let borrowed_type = crate::common::borrowed::borrowed_type(&ast);
+
         if let Data::Struct(data) = ast.data {
             if let Fields::Unnamed(_) = &data.fields {
                 if data.fields.len() == 1 {
@@ -26,13 +28,42 @@ impl ValidatorHandler for RegexHandler {
 
                     let name = ast.ident;
 
+                    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::RegexError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    let v_regex = match &type_attribute.source {
+                        Some(source) => ("regex", source.clone()),
+                        None => (
+                            "regex_path",
+                            type_attribute.regex.to_token_stream().to_string().replace(' ', ""),
+                        ),
+                    };
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "regex",
+                        &[(v_regex.0, crate::common::spec::spec_value("Str", v_regex.1))],
+                    );
+
                     let re = type_attribute.regex;
 
                     token_stream.extend(quote! {
-                        impl #name {
+                        impl #impl_generics #name #ty_generics #where_clause {
                             #[inline]
                             fn v_parse_str(s: &str) -> Result<(), #error_path> {
                                 if !#re.is_match(s) {
@@ -44,33 +75,71 @@ impl ValidatorHandler for RegexHandler {
                         }
                     });
 
+                    let expect = "a string matched by a regular expression";
+
+                    if let Some(borrowed_type) = borrowed_type {
+                        crate::common::borrowed::impl_borrowed(
+                            &mut token_stream,
+                            &name,
+                            borrowed_type,
+                            &error_type,
+                            &type_attribute.custom,
+                            type_attribute.normalize.as_ref(),
+                            &type_attribute.serde_options,
+                            &type_attribute.rocket_options,
+                            expect,
+                        )?;
+
+                        return Ok(token_stream);
+                    }
+
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_string
+
                                 let s = s.into();
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(#name(s))
+                                #ok(#name(s))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #normalize_str
+
                                 let s = s.as_ref();
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(#name(validators_prelude::String::from(s)))
+                                #ok(#name(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -91,8 +160,6 @@ impl ValidatorHandler for RegexHandler {
                         }
 
                         if type_attribute.serde_options.deserialize {
-                            let expect = "a string matched by a regular expression";
-
                             token_stream.extend(quote! {
                                 impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
                                     #[inline]
@@ -113,14 +180,14 @@ impl ValidatorHandler for RegexHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -141,7 +208,7 @@ impl ValidatorHandler for RegexHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
@@ -3,25 +3,34 @@ use regex::Regex;
 use syn::{punctuated::Punctuated, spanned::Spanned, Expr, ExprLit, Lit, Meta, Token};
 
 use crate::{
-    common::{rocket_options::RocketOptions, serde_options::SerdeOptions},
+    common::{
+        custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, serde_options::SerdeOptions,
+    },
     panic,
 };
 
 pub(crate) struct RegexAttribute {
     pub(crate) regex:          Expr,
+    /// The source of the regular expression if it is given as a string literal.
+    pub(crate) source:         Option<String>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
 }
 
 impl RegexAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["regex", "serde", "rocket"];
+        let correct_parameters = ["regex", "serde", "rocket", "custom", "normalize"];
 
         let mut regex = None;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -35,6 +44,8 @@ impl RegexAttribute {
                 let mut regex_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -78,6 +89,32 @@ impl RegexAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -96,19 +133,22 @@ impl RegexAttribute {
             },
         }
 
-        if let Some(regex) = regex {
+        if let Some((regex, source)) = regex {
             Ok(Self {
                 regex,
+                source,
                 serde_options,
                 rocket_options,
+                custom,
+                normalize,
             })
         } else {
-            return Err(syn::Error::new(meta.path().span(), "the `regex` parameter is not set"));
+            Err(syn::Error::new(meta.path().span(), "the `regex` parameter is not set"))
         }
     }
 }
 
-fn expr_lit_2_regex_expr(lit: &ExprLit) -> syn::Result<Expr> {
+fn expr_lit_2_regex_expr(lit: &ExprLit) -> syn::Result<(Expr, Option<String>)> {
     if let Lit::Str(lit) = &lit.lit {
         let s = lit.value();
 
@@ -116,27 +156,32 @@ fn expr_lit_2_regex_expr(lit: &ExprLit) -> syn::Result<Expr> {
             return Err(syn::Error::new(lit.span(), error));
         }
 
-        return Ok(
-            syn::parse2(quote! ( validators_prelude::regex::Regex::new(#s).unwrap() )).unwrap()
-        );
+        return Ok((
+            syn::parse2(quote! ( validators_prelude::regex::Regex::new(#s).unwrap() )).unwrap(),
+            Some(s),
+        ));
     }
 
     Err(syn::Error::new(lit.span(), "expected `\"regex\"`"))
 }
 
-fn meta_2_regex_expr(meta: &Meta) -> syn::Result<Expr> {
+fn meta_2_regex_expr(meta: &Meta) -> syn::Result<(Expr, Option<String>)> {
     match meta {
         Meta::NameValue(name_value) => {
             return if let Expr::Lit(lit) = &name_value.value {
                 expr_lit_2_regex_expr(lit)
             } else {
-                Ok(name_value.value.clone())
+                Ok((name_value.value.clone(), None))
             }
         },
         Meta::List(list) => {
             let expr: Expr = list.parse_args()?;
 
-            return if let Expr::Lit(lit) = &expr { expr_lit_2_regex_expr(lit) } else { Ok(expr) };
+            return if let Expr::Lit(lit) = &expr {
+                expr_lit_2_regex_expr(lit)
+            } else {
+                Ok((expr, None))
+            };
         },
         Meta::Path(_) => (),
     }
@@ -71,6 +71,31 @@ impl ValidatorHandler for SignedIntegerHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::SignedIntegerError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "signed_integer",
+                        &[(
+                            "range",
+                            crate::common::spec::spec_value(
+                                "SignedRange",
+                                type_attribute.range.to_spec_tokens(quote! { i128 }),
+                            ),
+                        )],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_range = &type_attribute.range;
@@ -82,115 +107,15 @@ impl ValidatorHandler for SignedIntegerHandler {
                         });
                     }
 
-                    let handle_range = {
-                        match &type_attribute.range {
-                            RangeTokenStream::Inside {
-                                min,
-                                max,
-                                inclusive,
-                                equal,
-                            } => {
-                                if *equal {
-                                    quote! {
-                                        match ::core::cmp::Ord::cmp(&i, &#min) {
-                                            ::core::cmp::Ordering::Equal => (),
-                                            ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall),
-                                            ::core::cmp::Ordering::Greater => return Err(#error_path::TooLarge),
-                                        }
-                                    }
-                                } else {
-                                    let mut token_stream = proc_macro2::TokenStream::new();
-
-                                    if let Some(min) = min {
-                                        token_stream.extend(quote! {
-                                            if i < #min {
-                                                return Err(#error_path::TooSmall);
-                                            }
-                                        });
-                                    }
-
-                                    if let Some(max) = max {
-                                        token_stream.extend(if *inclusive {
-                                            quote! {
-                                                if i > #max {
-                                                    return Err(#error_path::TooLarge);
-                                                }
-                                            }
-                                        } else {
-                                            quote! {
-                                                if i >= #max {
-                                                    return Err(#error_path::TooLarge);
-                                                }
-                                            }
-                                        });
-                                    }
+                    let handle_range = match &type_attribute.range {
+                        RangeTokenStream::Unlimited => quote! {},
+                        range => {
+                            let range = range.to_spec_tokens(quote! { i128 });
 
-                                    token_stream
-                                }
-                            },
-                            RangeTokenStream::Outside {
-                                min,
-                                max,
-                                inclusive,
-                                equal,
-                            } => {
-                                if *equal {
-                                    quote! {
-                                        if i == #min {
-                                            return Err(#error_path::Forbidden);
-                                        }
-                                    }
-                                } else {
-                                    match min {
-                                        Some(min) => match max {
-                                            Some(max) => {
-                                                if *inclusive {
-                                                    quote! {
-                                                        if (#min..=#max).contains(&i) {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                } else {
-                                                    quote! {
-                                                        if (#min..#max).contains(&i) {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                }
-                                            },
-                                            None => {
-                                                quote! {
-                                                    if i >= #min {
-                                                        return Err(#error_path::Forbidden);
-                                                    }
-                                                }
-                                            },
-                                        },
-                                        None => match max {
-                                            Some(max) => {
-                                                if *inclusive {
-                                                    quote! {
-                                                        if i <= #max {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                } else {
-                                                    quote! {
-                                                        if i < #max {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                }
-                                            },
-                                            None => {
-                                                quote! {}
-                                            },
-                                        },
-                                    }
-                                }
-                            },
-                            RangeTokenStream::Unlimited => quote! {},
-                        }
+                            quote! {
+                                validators_prelude::check_range(&#range, i as i128)?;
+                            }
+                        },
                     };
 
                     token_stream.extend(quote! {
@@ -213,47 +138,119 @@ impl ValidatorHandler for SignedIntegerHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
 
+                    let validate_i128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i128(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i64(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i32 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i32(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i16 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i16(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_i8 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_i8(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_isize = type_attribute.custom.validate(
+                        quote! { <Self as ValidateSignedInteger>::parse_isize(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(match signed_integer_type {
                         SignedIntegerType::I128 => {
                             quote! {
                                 impl ValidateSignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                         Self::v_parse_i(i)?;
 
-                                        Ok(Self(i))
+                                        #ok(Self(i))
                                     }
 
                                     #[inline]
                                     fn validate_i128(i: i128) -> Result<(), Self::Error> {
-                                        Self::v_parse_i(i)?;
-
-                                        Ok(())
+                                        #validate_i128
                                     }
                                 }
                             }
@@ -261,14 +258,14 @@ impl ValidatorHandler for SignedIntegerHandler {
                         SignedIntegerType::I64 => {
                             quote! {
                                 impl ValidateSignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                         if i > i64::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i64::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i64::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i64::MIN as i128, value: i }.into())
                                         } else {
                                             Self::parse_i64(i as i64)
                                         }
@@ -277,9 +274,9 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     #[inline]
                                     fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                         if i > i64::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i64::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i64::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i64::MIN as i128, value: i }.into())
                                         } else {
                                             Self::validate_i64(i as i64)
                                         }
@@ -289,14 +286,12 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     fn parse_i64(i: i64) -> Result<Self, Self::Error> {
                                         Self::v_parse_i(i)?;
 
-                                        Ok(Self(i))
+                                        #ok(Self(i))
                                     }
 
                                     #[inline]
                                     fn validate_i64(i: i64) -> Result<(), Self::Error> {
-                                        Self::v_parse_i(i)?;
-
-                                        Ok(())
+                                        #validate_i64
                                     }
                                 }
                             }
@@ -304,14 +299,14 @@ impl ValidatorHandler for SignedIntegerHandler {
                         SignedIntegerType::I32 => {
                             quote! {
                                 impl ValidateSignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                         if i > i32::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i32::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i32::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i32::MIN as i128, value: i }.into())
                                         } else {
                                             Self::parse_i32(i as i32)
                                         }
@@ -320,9 +315,9 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     #[inline]
                                     fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                         if i > i32::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i32::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i32::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i32::MIN as i128, value: i }.into())
                                         } else {
                                             Self::validate_i32(i as i32)
                                         }
@@ -332,14 +327,12 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     fn parse_i32(i: i32) -> Result<Self, Self::Error> {
                                         Self::v_parse_i(i)?;
 
-                                        Ok(Self(i))
+                                        #ok(Self(i))
                                     }
 
                                     #[inline]
                                     fn validate_i32(i: i32) -> Result<(), Self::Error> {
-                                        Self::v_parse_i(i)?;
-
-                                        Ok(())
+                                        #validate_i32
                                     }
                                 }
                             }
@@ -347,14 +340,14 @@ impl ValidatorHandler for SignedIntegerHandler {
                         SignedIntegerType::I16 => {
                             quote! {
                                 impl ValidateSignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                         if i > i16::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i16::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i16::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i16::MIN as i128, value: i }.into())
                                         } else {
                                             Self::parse_i16(i as i16)
                                         }
@@ -363,9 +356,9 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     #[inline]
                                     fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                         if i > i16::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i16::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i16::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i16::MIN as i128, value: i }.into())
                                         } else {
                                             Self::validate_i16(i as i16)
                                         }
@@ -375,14 +368,12 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     fn parse_i16(i: i16) -> Result<Self, Self::Error> {
                                         Self::v_parse_i(i)?;
 
-                                        Ok(Self(i))
+                                        #ok(Self(i))
                                     }
 
                                     #[inline]
                                     fn validate_i16(i: i16) -> Result<(), Self::Error> {
-                                        Self::v_parse_i(i)?;
-
-                                        Ok(())
+                                        #validate_i16
                                     }
                                 }
                             }
@@ -390,14 +381,14 @@ impl ValidatorHandler for SignedIntegerHandler {
                         SignedIntegerType::I8 => {
                             quote! {
                                 impl ValidateSignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                         if i > i8::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i8::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i8::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i8::MIN as i128, value: i }.into())
                                         } else {
                                             Self::parse_i8(i as i8)
                                         }
@@ -406,9 +397,9 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     #[inline]
                                     fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                         if i > i8::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: i8::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < i8::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: i8::MIN as i128, value: i }.into())
                                         } else {
                                             Self::validate_i8(i as i8)
                                         }
@@ -418,14 +409,12 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     fn parse_i8(i: i8) -> Result<Self, Self::Error> {
                                         Self::v_parse_i(i)?;
 
-                                        Ok(Self(i))
+                                        #ok(Self(i))
                                     }
 
                                     #[inline]
                                     fn validate_i8(i: i8) -> Result<(), Self::Error> {
-                                        Self::v_parse_i(i)?;
-
-                                        Ok(())
+                                        #validate_i8
                                     }
                                 }
                             }
@@ -433,15 +422,15 @@ impl ValidatorHandler for SignedIntegerHandler {
                         SignedIntegerType::Isize => {
                             quote! {
                                 impl ValidateSignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[cfg(not(target_pointer_width = "128"))]
                                     #[inline]
                                     fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                         if i > isize::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: isize::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < isize::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: isize::MIN as i128, value: i }.into())
                                         } else {
                                             Self::parse_isize(i as isize)
                                         }
@@ -451,9 +440,9 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     #[inline]
                                     fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                         if i > isize::MAX as i128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: isize::MAX as i128, inclusive: true, value: i }.into())
                                         } else if i < isize::MIN as i128 {
-                                            Err(#error_path::TooSmall)
+                                            Err(#error_path::TooSmall { min: isize::MIN as i128, value: i }.into())
                                         } else {
                                             Self::validate_isize(i as isize)
                                         }
@@ -463,14 +452,12 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     fn parse_isize(i: isize) -> Result<Self, Self::Error> {
                                         Self::v_parse_i(i)?;
 
-                                        Ok(Self(i))
+                                        #ok(Self(i))
                                     }
 
                                     #[inline]
                                     fn validate_isize(i: isize) -> Result<(), Self::Error> {
-                                        Self::v_parse_i(i)?;
-
-                                        Ok(())
+                                        #validate_isize
                                     }
 
                                     #[cfg(target_pointer_width = "8")]
@@ -624,48 +611,48 @@ impl ValidatorHandler for SignedIntegerHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i8(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i16(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i16(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i32(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i32(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::de::error)
                                             }
 
                                             fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -686,7 +673,7 @@ impl ValidatorHandler for SignedIntegerHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
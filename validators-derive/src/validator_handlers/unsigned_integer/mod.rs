@@ -73,6 +73,31 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::UnsignedIntegerError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "unsigned_integer",
+                        &[(
+                            "range",
+                            crate::common::spec::spec_value(
+                                "UnsignedRange",
+                                type_attribute.range.to_spec_tokens(quote! { u128 }),
+                            ),
+                        )],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_range = &type_attribute.range;
@@ -84,115 +109,15 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         });
                     }
 
-                    let handle_range = {
-                        match &type_attribute.range {
-                            RangeTokenStream::Inside {
-                                min,
-                                max,
-                                inclusive,
-                                equal,
-                            } => {
-                                if *equal {
-                                    quote! {
-                                        match ::core::cmp::Ord::cmp(&u, &#min) {
-                                            ::core::cmp::Ordering::Equal => (),
-                                            ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall),
-                                            ::core::cmp::Ordering::Greater => return Err(#error_path::TooLarge),
-                                        }
-                                    }
-                                } else {
-                                    let mut token_stream = proc_macro2::TokenStream::new();
-
-                                    if let Some(min) = min {
-                                        token_stream.extend(quote! {
-                                            if u < #min {
-                                                return Err(#error_path::TooSmall);
-                                            }
-                                        });
-                                    }
-
-                                    if let Some(max) = max {
-                                        token_stream.extend(if *inclusive {
-                                            quote! {
-                                                if u > #max {
-                                                    return Err(#error_path::TooLarge);
-                                                }
-                                            }
-                                        } else {
-                                            quote! {
-                                                if u >= #max {
-                                                    return Err(#error_path::TooLarge);
-                                                }
-                                            }
-                                        });
-                                    }
+                    let handle_range = match &type_attribute.range {
+                        RangeTokenStream::Unlimited => quote! {},
+                        range => {
+                            let range = range.to_spec_tokens(quote! { u128 });
 
-                                    token_stream
-                                }
-                            },
-                            RangeTokenStream::Outside {
-                                min,
-                                max,
-                                inclusive,
-                                equal,
-                            } => {
-                                if *equal {
-                                    quote! {
-                                        if u == #min {
-                                            return Err(#error_path::Forbidden);
-                                        }
-                                    }
-                                } else {
-                                    match min {
-                                        Some(min) => match max {
-                                            Some(max) => {
-                                                if *inclusive {
-                                                    quote! {
-                                                        if (#min..=#max).contains(&i) {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                } else {
-                                                    quote! {
-                                                        if (#min..#max).contains(&i) {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                }
-                                            },
-                                            None => {
-                                                quote! {
-                                                    if u >= #min {
-                                                        return Err(#error_path::Forbidden);
-                                                    }
-                                                }
-                                            },
-                                        },
-                                        None => match max {
-                                            Some(max) => {
-                                                if *inclusive {
-                                                    quote! {
-                                                        if u <= #max {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                } else {
-                                                    quote! {
-                                                        if u < #max {
-                                                            return Err(#error_path::Forbidden);
-                                                        }
-                                                    }
-                                                }
-                                            },
-                                            None => {
-                                                quote! {}
-                                            },
-                                        },
-                                    }
-                                }
-                            },
-                            RangeTokenStream::Unlimited => quote! {},
-                        }
+                            quote! {
+                                validators_prelude::check_range(&#range, u as u128)?;
+                            }
+                        },
                     };
 
                     token_stream.extend(quote! {
@@ -215,47 +140,119 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         }
                     });
 
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
+
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
+
+                            Self::v_parse_str(s.as_ref())?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
 
+                    let validate_u128 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u128(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u64 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u64(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u32 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u32(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u16 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u16(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_u8 = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_u8(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
+                    let validate_usize = type_attribute.custom.validate(
+                        quote! { <Self as ValidateUnsignedInteger>::parse_usize(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     token_stream.extend(match unsigned_integer_type {
                         UnsignedIntegerType::U128 => {
                             quote! {
                                 impl ValidateUnsignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                         Self::v_parse_u(u)?;
 
-                                        Ok(Self(u))
+                                        #ok(Self(u))
                                     }
 
                                     #[inline]
                                     fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                        Self::v_parse_u(u)?;
-
-                                        Ok(())
+                                        #validate_u128
                                     }
                                 }
                             }
@@ -263,12 +260,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         UnsignedIntegerType::U64 => {
                             quote! {
                                 impl ValidateUnsignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                         if u > u64::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u64::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::parse_u64(u as u64)
                                         }
@@ -277,7 +274,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     #[inline]
                                     fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                         if u > u64::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u64::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::validate_u64(u as u64)
                                         }
@@ -287,14 +284,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     fn parse_u64(u: u64) -> Result<Self, Self::Error> {
                                         Self::v_parse_u(u)?;
 
-                                        Ok(Self(u))
+                                        #ok(Self(u))
                                     }
 
                                     #[inline]
                                     fn validate_u64(u: u64) -> Result<(), Self::Error> {
-                                        Self::v_parse_u(u)?;
-
-                                        Ok(())
+                                        #validate_u64
                                     }
                                 }
                             }
@@ -302,12 +297,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         UnsignedIntegerType::U32 => {
                             quote! {
                                 impl ValidateUnsignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                         if u > u32::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u32::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::parse_u32(u as u32)
                                         }
@@ -316,7 +311,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     #[inline]
                                     fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                         if u > u32::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u32::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::validate_u32(u as u32)
                                         }
@@ -326,14 +321,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     fn parse_u32(u: u32) -> Result<Self, Self::Error> {
                                         Self::v_parse_u(u)?;
 
-                                        Ok(Self(u))
+                                        #ok(Self(u))
                                     }
 
                                     #[inline]
                                     fn validate_u32(u: u32) -> Result<(), Self::Error> {
-                                        Self::v_parse_u(u)?;
-
-                                        Ok(())
+                                        #validate_u32
                                     }
                                 }
                             }
@@ -341,12 +334,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         UnsignedIntegerType::U16 => {
                             quote! {
                                 impl ValidateUnsignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                         if u > u16::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u16::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::parse_u16(u as u16)
                                         }
@@ -355,7 +348,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     #[inline]
                                     fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                         if u > u16::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u16::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::validate_u16(u as u16)
                                         }
@@ -365,14 +358,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     fn parse_u16(u: u16) -> Result<Self, Self::Error> {
                                         Self::v_parse_u(u)?;
 
-                                        Ok(Self(u))
+                                        #ok(Self(u))
                                     }
 
                                     #[inline]
                                     fn validate_u16(u: u16) -> Result<(), Self::Error> {
-                                        Self::v_parse_u(u)?;
-
-                                        Ok(())
+                                        #validate_u16
                                     }
                                 }
                             }
@@ -380,12 +371,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         UnsignedIntegerType::U8 => {
                             quote! {
                                 impl ValidateUnsignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[inline]
                                     fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                         if u > u8::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u8::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::parse_u8(u as u8)
                                         }
@@ -394,7 +385,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     #[inline]
                                     fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                         if u > u8::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: u8::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::validate_u8(u as u8)
                                         }
@@ -404,14 +395,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     fn parse_u8(u: u8) -> Result<Self, Self::Error> {
                                         Self::v_parse_u(u)?;
 
-                                        Ok(Self(u))
+                                        #ok(Self(u))
                                     }
 
                                     #[inline]
                                     fn validate_u8(u: u8) -> Result<(), Self::Error> {
-                                        Self::v_parse_u(u)?;
-
-                                        Ok(())
+                                        #validate_u8
                                     }
                                 }
                             }
@@ -419,13 +408,13 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                         UnsignedIntegerType::Usize => {
                             quote! {
                                 impl ValidateUnsignedInteger for #name {
-                                    type Error = #error_path;
+                                    type Error = #error_type;
 
                                     #[cfg(not(target_pointer_width = "128"))]
                                     #[inline]
                                     fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                         if u > usize::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: usize::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::parse_usize(u as usize)
                                         }
@@ -435,7 +424,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     #[inline]
                                     fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                         if u > usize::MAX as u128 {
-                                            Err(#error_path::TooLarge)
+                                            Err(#error_path::TooLarge { max: usize::MAX as u128, inclusive: true, value: u }.into())
                                         } else {
                                             Self::validate_usize(u as usize)
                                         }
@@ -445,14 +434,12 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     fn parse_usize(u: usize) -> Result<Self, Self::Error> {
                                         Self::v_parse_u(u)?;
 
-                                        Ok(Self(u))
+                                        #ok(Self(u))
                                     }
 
                                     #[inline]
                                     fn validate_usize(u: usize) -> Result<(), Self::Error> {
-                                        Self::v_parse_u(u)?;
-
-                                        Ok(())
+                                        #validate_usize
                                     }
 
                                     #[cfg(target_pointer_width = "8")]
@@ -606,48 +593,48 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u8(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u16(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u16(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u32(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u32(v).map_err(validators_prelude::de::error)
                                             }
 
                                             #[inline]
                                             fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::de::error)
                                             }
 
                                             fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -668,7 +655,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
@@ -22,6 +22,8 @@ impl ValidatorHandler for UuidHandler {
     fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = UuidAttribute::build_from_meta(&meta)?;
 
+        let borrowed_type = crate::common::borrowed::borrowed_type(&ast);
+
         if let Data::Struct(data) = ast.data {
             if let Fields::Unnamed(_) = &data.fields {
                 if data.fields.len() == 1 {
@@ -29,242 +31,161 @@ impl ValidatorHandler for UuidHandler {
 
                     let name = ast.ident;
 
+                    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
                     let error_path: Path =
                         syn::parse2(quote! { validators_prelude::UuidError }).unwrap();
 
+                    let error_type = type_attribute.custom.error_type(&error_path);
+
+                    let ok = type_attribute.custom.ok();
+
+                    type_attribute.custom.impl_custom(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        &error_path,
+                    );
+
+                    crate::common::spec::impl_validator_spec(
+                        &mut token_stream,
+                        &name,
+                        &ast.generics,
+                        "uuid",
+                        &[
+                            ("case", crate::common::spec::spec_value("Case", type_attribute.case)),
+                            (
+                                "separator",
+                                crate::common::spec::spec_value(
+                                    "Separator",
+                                    type_attribute.separator,
+                                ),
+                            ),
+                        ],
+                    );
+
                     #[cfg(feature = "test")]
                     {
                         let v_case = type_attribute.case;
                         let v_separator = type_attribute.separator;
 
                         token_stream.extend(quote! {
-                            impl #name {
+                            impl #impl_generics #name #ty_generics #where_clause {
                                 pub(crate) const V_CASE: validators_prelude::CaseOption = #v_case;
                                 pub(crate) const V_SEPARATOR: validators_prelude::SeparatorOption = #v_separator;
                             }
                         });
                     }
 
-                    let handle_iter = {
-                        match type_attribute.separator {
-                            SeparatorOption::Allow(separator) => {
-                                quote! {
-                                    if !(32..=36).contains(&length) {
-                                        return Err(#error_path::Invalid);
-                                    }
-
-                                    let time_low = &bytes[0..8];
-
-                                    let mut no_hyphen_counter = if bytes[8] != #separator {
-                                        1
-                                    } else {
-                                        0
-                                    };
-
-                                    let time_mid = &bytes[(9 - no_hyphen_counter)..(13 - no_hyphen_counter)];
-
-                                    if bytes[13 - no_hyphen_counter] != #separator {
-                                        no_hyphen_counter += 1;
-                                    }
-
-                                    let time_high_and_version =
-                                        &bytes[(14 - no_hyphen_counter)..(18 - no_hyphen_counter)];
-
-                                    if bytes[18 - no_hyphen_counter] != #separator {
-                                        no_hyphen_counter += 1;
-                                    }
+                    let case = type_attribute.case;
+                    let separator = type_attribute.separator;
 
-                                    let clock_seq = &bytes[(19 - no_hyphen_counter)..(23 - no_hyphen_counter)];
-
-                                    if bytes[23 - no_hyphen_counter] != #separator {
-                                        no_hyphen_counter += 1;
-                                    }
-
-                                    let node = &bytes[(24 - no_hyphen_counter)..];
-
-                                    if node.len() != 12 {
-                                        return Err(#error_path::Invalid);
-                                    }
-
-                                    time_low.iter().chain(time_mid).chain(time_high_and_version).chain(clock_seq).chain(node).copied()
-                                }
-                            },
-                            SeparatorOption::Must(separator) => {
-                                quote! {
-                                    if length != 36 {
-                                        return Err(#error_path::SeparatorMust);
-                                    }
+                    token_stream.extend(quote! {
+                        impl #impl_generics #name #ty_generics #where_clause {
+                            #[inline]
+                            fn v_parse_str(s: &str) -> Result<u128, #error_path> {
+                                validators_prelude::parse_uuid(s, #case, #separator)
+                            }
 
-                                    if bytes[8] != #separator || bytes[13] != #separator || bytes[18] != #separator || bytes[23] != #separator {
-                                        return Err(#error_path::Invalid);
-                                    }
+                            #[inline]
+                            fn v_validate_str(s: &str) -> Result<(), #error_path> {
+                                validators_prelude::parse_uuid(s, #case, #separator)?;
 
-                                    let time_low = &bytes[0..8];
-                                    let time_mid = &bytes[9..13];
-                                    let time_high_and_version = &bytes[14..18];
-                                    let clock_seq = &bytes[19..23];
-                                    let node = &bytes[24..];
+                                Ok(())
+                            }
+                        }
+                    });
 
-                                    time_low.iter().chain(time_mid).chain(time_high_and_version).chain(clock_seq).chain(node).copied()
-                                }
-                            },
-                            SeparatorOption::Disallow => {
-                                quote! {
-                                    if length != 32 {
-                                        return Err(#error_path::SeparatorDisallow);
-                                    }
+                    let expect = {
+                        use std::fmt::Write;
 
-                                    bytes.iter().copied()
-                                }
-                            },
-                        }
-                    };
+                        let mut s = String::from("a valid ");
 
-                    let handle_decode = {
                         match type_attribute.case {
-                            CaseOption::Any => {
-                                quote! {
-                                    for e in iter {
-                                        uuid_decoded <<= 4;
-
-                                        match e {
-                                            b'0'..=b'9' => {
-                                                uuid_decoded |= u128::from(e - b'0');
-                                            }
-                                            b'a'..=b'f' => {
-                                                uuid_decoded |= u128::from(e - (b'a' - 10));
-                                            }
-                                            b'A'..=b'F' => {
-                                                uuid_decoded |= u128::from(e - (b'A' - 10));
-                                            }
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
+                            CaseOption::Any => (),
                             CaseOption::Upper => {
-                                quote! {
-                                    for e in iter {
-                                        uuid_decoded <<= 4;
-
-                                        match e {
-                                            b'0'..=b'9' => {
-                                                uuid_decoded |= u128::from(e - b'0');
-                                            }
-                                            b'A'..=b'F' => {
-                                                uuid_decoded |= u128::from(e - (b'A' - 10));
-                                            }
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
+                                s.push_str("upper-case ");
                             },
                             CaseOption::Lower => {
-                                quote! {
-                                    for e in iter {
-                                        uuid_decoded <<= 4;
-
-                                        match e {
-                                            b'0'..=b'9' => {
-                                                uuid_decoded |= u128::from(e - b'0');
-                                            }
-                                            b'a'..=b'f' => {
-                                                uuid_decoded |= u128::from(e - (b'a' - 10));
-                                            }
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
+                                s.push_str("lower-case ");
                             },
                         }
-                    };
 
-                    let handle_check = {
-                        match type_attribute.case {
-                            CaseOption::Any => {
-                                quote! {
-                                    for e in iter {
-                                        match e {
-                                            b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F' => (),
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
-                            },
-                            CaseOption::Upper => {
-                                quote! {
-                                    for e in iter {
-                                        match e {
-                                            b'0'..=b'9' | b'A'..=b'F' => (),
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
+                        s.push_str("UUID string");
+
+                        match type_attribute.separator {
+                            SeparatorOption::Must(e) => {
+                                s.write_fmt(format_args!(" with separators {:?}", e as char))
+                                    .unwrap();
                             },
-                            CaseOption::Lower => {
-                                quote! {
-                                    for e in iter {
-                                        match e {
-                                            b'0'..=b'9' | b'a'..=b'f' => (),
-                                            _ => return Err(#error_path::Invalid),
-                                        }
-                                    }
-                                }
+                            SeparatorOption::Allow(e) => {
+                                s.write_fmt(format_args!(
+                                    " with optional separators {:?}",
+                                    e as char
+                                ))
+                                .unwrap();
                             },
+                            SeparatorOption::Disallow => s.push_str(" without separators"),
                         }
-                    };
-
-                    token_stream.extend(quote! {
-                        impl #name {
-                            fn v_parse_str(s: &str) -> Result<u128, #error_path> {
-                                let bytes = s.as_bytes();
-                                let length = bytes.len();
-
-                                let iter = {
-                                    #handle_iter
-                                };
-
-                                let mut uuid_decoded = 0u128;
 
-                                #handle_decode
+                        s
+                    };
 
-                                Ok(uuid_decoded)
-                            }
+                    if let Some(borrowed_type) = borrowed_type {
+                        crate::common::borrowed::impl_borrowed(
+                            &mut token_stream,
+                            &name,
+                            borrowed_type,
+                            &error_type,
+                            &type_attribute.custom,
+                            type_attribute.normalize.as_ref(),
+                            &type_attribute.serde_options,
+                            &type_attribute.rocket_options,
+                            &expect,
+                        )?;
+
+                        return Ok(token_stream);
+                    }
 
-                            fn v_validate_str(s: &str) -> Result<(), #error_path> {
-                                let bytes = s.as_bytes();
-                                let length = bytes.len();
+                    let normalize_string = crate::common::normalize_option::normalize_string(
+                        type_attribute.normalize.as_ref(),
+                    );
+                    let normalize_str = crate::common::normalize_option::normalize_str(
+                        type_attribute.normalize.as_ref(),
+                    );
 
-                                let iter = {
-                                    #handle_iter
-                                };
+                    let validate_str = type_attribute.custom.validate(
+                        quote! { <Self as ValidateString>::parse_str(s) },
+                        quote! {
+                            #normalize_str
 
-                                #handle_check
+                            Self::v_validate_str(s.as_ref())?;
 
-                                Ok(())
-                            }
-                        }
-                    });
+                            Ok(())
+                        },
+                    );
 
                     token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #normalize_string
+
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #normalize_str
+
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_validate_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -295,7 +216,7 @@ impl ValidatorHandler for UuidHandler {
                                             bytes[2],
                                             bytes[1],
                                             bytes[0],
-                                            separator = #separator
+                                            separator = #separator as char
                                         )
                                     }
                                 }
@@ -335,7 +256,7 @@ impl ValidatorHandler for UuidHandler {
                                         bytes[2],
                                         bytes[1],
                                         bytes[0],
-                                        separator = #separator
+                                        separator = #separator as char
                                     )
                                 }
                             }
@@ -367,44 +288,6 @@ impl ValidatorHandler for UuidHandler {
                         }
 
                         if type_attribute.serde_options.deserialize {
-                            use std::fmt::Write;
-
-                            let expect = {
-                                let mut s = String::from("a valid ");
-
-                                match type_attribute.case {
-                                    CaseOption::Any => (),
-                                    CaseOption::Upper => {
-                                        s.push_str("upper-case ");
-                                    },
-                                    CaseOption::Lower => {
-                                        s.push_str("lower-case ");
-                                    },
-                                }
-
-                                s.push_str("UUID string");
-
-                                match type_attribute.separator {
-                                    SeparatorOption::Must(e) => {
-                                        s.write_fmt(format_args!(
-                                            " with separators {:?}",
-                                            e as char
-                                        ))
-                                        .unwrap();
-                                    },
-                                    SeparatorOption::Allow(e) => {
-                                        s.write_fmt(format_args!(
-                                            " with optional separators {:?}",
-                                            e as char
-                                        ))
-                                        .unwrap();
-                                    },
-                                    SeparatorOption::Disallow => s.push_str(" without separators"),
-                                }
-
-                                s
-                            };
-
                             token_stream.extend(quote! {
                                 impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
                                     #[inline]
@@ -425,7 +308,7 @@ impl ValidatorHandler for UuidHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de::error)
                                             }
                                         }
 
@@ -446,7 +329,7 @@ impl ValidatorHandler for UuidHandler {
                             crate::common::rocket::impl_from_param(
                                 &mut token_stream,
                                 &name,
-                                &error_path,
+                                &error_type,
                             );
                         }
                     }
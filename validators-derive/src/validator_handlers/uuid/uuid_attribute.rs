@@ -2,7 +2,8 @@ use syn::{punctuated::Punctuated, Meta, Token};
 
 use crate::{
     common::{
-        case_option::CaseOption, rocket_options::RocketOptions, separator_option::SeparatorOption,
+        case_option::CaseOption, custom_option::CustomOption, normalize_option::NormalizeOption,
+        rocket_options::RocketOptions, separator_option::SeparatorOption,
         serde_options::SerdeOptions,
     },
     panic,
@@ -15,16 +16,20 @@ pub(crate) struct UuidAttribute {
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
     pub(crate) rocket_options: RocketOptions,
+    pub(crate) custom:         CustomOption,
+    pub(crate) normalize:      Option<NormalizeOption>,
 }
 
 impl UuidAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["case", "separator", "serde", "rocket"];
+        let correct_parameters = ["case", "separator", "serde", "rocket", "custom", "normalize"];
 
         let mut case = CaseOption::Any;
         let mut separator = SeparatorOption::Allow(b'-');
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
+        let mut custom = CustomOption::default();
+        let mut normalize = None;
 
         match meta {
             Meta::Path(_) => (),
@@ -39,6 +44,8 @@ impl UuidAttribute {
                 let mut separator_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
+                let mut custom_is_set = false;
+                let mut normalize_is_set = false;
 
                 let mut handler = |meta: &Meta| -> syn::Result<bool> {
                     if let Some(ident) = meta.path().get_ident() {
@@ -95,6 +102,32 @@ impl UuidAttribute {
 
                                 return Ok(true);
                             },
+                            "custom" => {
+                                let v = CustomOption::from_meta(meta)?;
+
+                                if custom_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                custom_is_set = true;
+
+                                custom = v;
+
+                                return Ok(true);
+                            },
+                            "normalize" => {
+                                let v = Some(NormalizeOption::from_meta(meta)?);
+
+                                if normalize_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                normalize_is_set = true;
+
+                                normalize = v;
+
+                                return Ok(true);
+                            },
                             _ => (),
                         }
                     }
@@ -118,6 +151,8 @@ impl UuidAttribute {
             separator,
             serde_options,
             rocket_options,
+            custom,
+            normalize,
         })
     }
 }
@@ -0,0 +1,3 @@
+mod validator_parser;
+
+pub use validator_parser::*;
@@ -0,0 +1,127 @@
+use alloc::boxed::Box;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+use std::{error::Error, ffi::OsStr};
+
+use clap::{
+    builder::{PossibleValue, StringValueParser, TypedValueParser},
+    Arg, Command,
+};
+
+use crate::{
+    models::BOOLEAN_STRINGS,
+    traits::{ValidateString, ValidatorSpec},
+};
+
+/**
+A `TypedValueParser` of `clap` which parses arguments with a validator.
+
+An invalid argument is reported by `clap` with the message of the validator error, such as `invalid value 'localhost:99999' for '--host <HOST>': invalid port`. Types derived with the `boolean` validator list `true` and `false` as the possible values, and the other accepted strings as their aliases.
+
+Structs derived with the `Validator` derive macro implement `ValueParserFactory`, whose parser is this, so they can be arguments without setting `value_parser`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "byte", feature = "host"))]
+# {
+use clap::Parser;
+use validators::prelude::*;
+
+#[derive(Validator, Clone)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct Host {
+    pub host: validators::models::Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator, Clone)]
+#[validator(byte(range(max = 1073741824)))]
+pub struct Size(validators::byte_unit::Byte);
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long)]
+    host:       Host,
+    #[arg(long, value_parser = validators::cli::ValidatorParser::<Size>::new())]
+    max_upload: Size,
+}
+
+let args = Args::try_parse_from(["app", "--host", "localhost:8000", "--max-upload", "10MB"]).unwrap();
+
+assert_eq!(Some(8000), args.host.port);
+assert_eq!(10000000, args.max_upload.0.as_u64());
+
+assert!(Args::try_parse_from(["app", "--host", "localhost:8000", "--max-upload", "10GB"]).is_err());
+# }
+```
+ */
+pub struct ValidatorParser<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ValidatorParser<T> {
+    /// Create a new instance.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData
+        }
+    }
+}
+
+impl<T> Default for ValidatorParser<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ValidatorParser<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ValidatorParser<T> {}
+
+impl<T> Debug for ValidatorParser<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("ValidatorParser").finish()
+    }
+}
+
+impl<T> TypedValueParser for ValidatorParser<T>
+where
+    T: ValidateString + ValidatorSpec + Clone + Send + Sync + 'static,
+    T::Error: Error + Send + Sync + 'static,
+{
+    type Value = T;
+
+    #[inline]
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        StringValueParser::new().try_map(T::parse_string).parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        match T::SPEC.validator {
+            "boolean" => Some(Box::new(
+                [
+                    PossibleValue::new("true")
+                        .aliases(BOOLEAN_STRINGS[2..].iter().step_by(2).copied()),
+                    PossibleValue::new("false")
+                        .aliases(BOOLEAN_STRINGS[3..].iter().step_by(2).copied()),
+                ]
+                .into_iter(),
+            )),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,830 @@
+use alloc::{
+    boxed::Box,
+    collections::{btree_map, BTreeMap},
+    format,
+    string::{String, ToString},
+};
+
+use serde_json::{Map, Value};
+
+#[cfg(any(feature = "line", feature = "text"))]
+use crate::errors::LengthUnit;
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+use crate::options::RangeOption;
+#[cfg(any(
+    all(feature = "derive", any(feature = "email", feature = "host", feature = "http_ftp_url")),
+    feature = "number"
+))]
+use crate::options::TriAllow;
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+use crate::options::{CaseOption, SeparatorOption};
+#[allow(unused_imports)]
+use crate::runtime::*;
+use crate::{
+    errors::{ConfigError, OptionsError},
+    registry::Registry,
+    traits::{DynError, DynValidator},
+};
+
+/// The kinds of validators which can be loaded from configuration files.
+pub(crate) const KINDS: &[&str] = &[
+    #[cfg(all(feature = "derive", feature = "email"))]
+    "email",
+    #[cfg(all(feature = "derive", feature = "host"))]
+    "host",
+    #[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+    "http_ftp_url",
+    #[cfg(feature = "line")]
+    "line",
+    #[cfg(feature = "mac_address")]
+    "mac_address",
+    #[cfg(feature = "number")]
+    "number",
+    #[cfg(feature = "signed_integer")]
+    "signed_integer",
+    #[cfg(feature = "text")]
+    "text",
+    #[cfg(feature = "unsigned_integer")]
+    "unsigned_integer",
+    #[cfg(feature = "uuid")]
+    "uuid",
+];
+
+// The parameters are the same as the ones of the attributes, except `conflict`, `serde` and `rocket`, which are meaningless at runtime.
+
+const ROOT_PARAMETERS: &[&str] = &["validators"];
+
+#[cfg(all(feature = "derive", feature = "email"))]
+const EMAIL_PARAMETERS: &[&str] =
+    &["kind", "comment", "ip", "local", "at_least_two_labels", "non_ascii"];
+
+#[cfg(all(feature = "derive", feature = "host"))]
+const HOST_PARAMETERS: &[&str] = &["kind", "local", "port", "at_least_two_labels"];
+
+#[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+const HTTP_FTP_URL_PARAMETERS: &[&str] = &["kind", "local"];
+
+#[cfg(any(feature = "line", feature = "text"))]
+const UTF8_PARAMETERS: &[&str] = &["kind", "char_length", "byte_length"];
+
+#[cfg(any(feature = "line", feature = "text"))]
+const LENGTH_PARAMETERS: &[&str] = &["min", "trimmed_min", "max"];
+
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+const HEX_PARAMETERS: &[&str] = &["kind", "case", "separator"];
+
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+const SEPARATOR_OPTION_PARAMETERS: &[&str] = &["Must", "Allow"];
+
+#[cfg(feature = "number")]
+const NUMBER_PARAMETERS: &[&str] = &["kind", "range", "nan"];
+
+#[cfg(any(feature = "signed_integer", feature = "unsigned_integer"))]
+const RANGE_ATTRIBUTE_PARAMETERS: &[&str] = &["kind", "range"];
+
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+const RANGE_OPTION_PARAMETERS: &[&str] = &["Inside", "Outside", "Unlimited"];
+
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+const RANGE_PARAMETERS: &[&str] = &["min", "max", "inclusive"];
+
+/// A validator loaded from a configuration file.
+#[derive(Debug, Clone)]
+pub enum RuntimeValidator {
+    #[cfg(all(feature = "derive", feature = "email"))]
+    Email(EmailValidator),
+    #[cfg(all(feature = "derive", feature = "host"))]
+    Host(HostValidator),
+    #[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+    HttpFtpUrl(HttpFtpUrlValidator),
+    #[cfg(feature = "line")]
+    Line(LineValidator),
+    #[cfg(feature = "mac_address")]
+    MacAddress(MacAddressValidator),
+    #[cfg(feature = "number")]
+    Number(NumberValidator),
+    #[cfg(feature = "signed_integer")]
+    SignedInteger(SignedIntegerValidator),
+    #[cfg(feature = "text")]
+    Text(TextValidator),
+    #[cfg(feature = "unsigned_integer")]
+    UnsignedInteger(UnsignedIntegerValidator),
+    #[cfg(feature = "uuid")]
+    Uuid(UuidValidator),
+}
+
+impl RuntimeValidator {
+    /// The value of the `kind` parameter of this validator.
+    #[inline]
+    pub const fn kind(&self) -> &'static str {
+        match *self {
+            #[cfg(all(feature = "derive", feature = "email"))]
+            Self::Email(_) => "email",
+            #[cfg(all(feature = "derive", feature = "host"))]
+            Self::Host(_) => "host",
+            #[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+            Self::HttpFtpUrl(_) => "http_ftp_url",
+            #[cfg(feature = "line")]
+            Self::Line(_) => "line",
+            #[cfg(feature = "mac_address")]
+            Self::MacAddress(_) => "mac_address",
+            #[cfg(feature = "number")]
+            Self::Number(_) => "number",
+            #[cfg(feature = "signed_integer")]
+            Self::SignedInteger(_) => "signed_integer",
+            #[cfg(feature = "text")]
+            Self::Text(_) => "text",
+            #[cfg(feature = "unsigned_integer")]
+            Self::UnsignedInteger(_) => "unsigned_integer",
+            #[cfg(feature = "uuid")]
+            Self::Uuid(_) => "uuid",
+        }
+    }
+}
+
+impl DynValidator for RuntimeValidator {
+    #[inline]
+    fn validate_str(&self, s: &str) -> Result<(), Box<dyn DynError>> {
+        match *self {
+            #[cfg(all(feature = "derive", feature = "email"))]
+            Self::Email(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(all(feature = "derive", feature = "host"))]
+            Self::Host(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+            Self::HttpFtpUrl(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "line")]
+            Self::Line(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "mac_address")]
+            Self::MacAddress(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "number")]
+            Self::Number(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "signed_integer")]
+            Self::SignedInteger(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "text")]
+            Self::Text(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "unsigned_integer")]
+            Self::UnsignedInteger(ref validator) => DynValidator::validate_str(validator, s),
+            #[cfg(feature = "uuid")]
+            Self::Uuid(ref validator) => DynValidator::validate_str(validator, s),
+        }
+    }
+}
+
+/// Named validators loaded from a TOML or JSON configuration file.
+///
+/// Every table in the `validators` table is a validator. Its `kind` is the name of the validator, and the other parameters are the same as the ones of the attribute.
+///
+/// ```toml
+/// [validators.username]
+/// kind = "line"
+/// char_length = { min = 3, max = 32 }
+///
+/// [validators.age]
+/// kind = "unsigned_integer"
+/// range = { Inside = { min = 0, max = 150 } }
+///
+/// [validators.contact]
+/// kind = "email"
+/// local = "Disallow"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ValidatorConfig {
+    validators: BTreeMap<String, RuntimeValidator>,
+}
+
+impl ValidatorConfig {
+    #[inline]
+    pub fn from_toml<S: AsRef<str>>(toml: S) -> Result<Self, ConfigError> {
+        let table = toml.as_ref().parse::<toml::Table>()?;
+
+        Self::from_map(&toml_table_to_map(table))
+    }
+
+    #[inline]
+    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self, ConfigError> {
+        let map = serde_json::from_str::<Map<String, Value>>(json.as_ref())?;
+
+        Self::from_map(&map)
+    }
+
+    fn from_map(map: &Map<String, Value>) -> Result<Self, ConfigError> {
+        let root = Parameters::from_map("", map, ROOT_PARAMETERS)?;
+
+        let mut validators = BTreeMap::new();
+
+        if let Some((name, value)) = root.get("validators") {
+            let map = value.as_object().ok_or_else(|| ConfigError::IncorrectValue {
+                name:     name.clone(),
+                expected: "{ .. }",
+            })?;
+
+            for (key, value) in map {
+                let name = format!("{name}.{key}");
+
+                validators.insert(key.clone(), build_validator(name, value)?);
+            }
+        }
+
+        Ok(Self {
+            validators,
+        })
+    }
+
+    #[inline]
+    pub fn get<S: AsRef<str>>(&self, name: S) -> Option<&RuntimeValidator> {
+        self.validators.get(name.as_ref())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> btree_map::Iter<'_, String, RuntimeValidator> {
+        self.validators.iter()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> BTreeMap<String, RuntimeValidator> {
+        self.validators
+    }
+}
+
+impl From<ValidatorConfig> for Registry {
+    #[inline]
+    fn from(config: ValidatorConfig) -> Self {
+        let mut registry = Registry::new();
+
+        for (name, validator) in config.validators {
+            registry.insert(name, validator);
+        }
+
+        registry
+    }
+}
+
+fn toml_table_to_map(table: toml::Table) -> Map<String, Value> {
+    table.into_iter().map(|(key, value)| (key, toml_value_to_json(value))).collect()
+}
+
+fn toml_value_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(array) => {
+            Value::Array(array.into_iter().map(toml_value_to_json).collect())
+        },
+        toml::Value::Table(table) => Value::Object(toml_table_to_map(table)),
+    }
+}
+
+fn build_validator(name: String, value: &Value) -> Result<RuntimeValidator, ConfigError> {
+    let kind = match value.as_object() {
+        Some(map) => match map.get("kind") {
+            Some(Value::String(kind)) => kind.as_str(),
+            Some(_) => {
+                return Err(ConfigError::IncorrectValue {
+                    name:     format!("{name}.kind"),
+                    expected: "\"..\"",
+                })
+            },
+            None => return Err(ConfigError::ParameterMissing(format!("{name}.kind"))),
+        },
+        None => {
+            return Err(ConfigError::IncorrectValue {
+                name,
+                expected: "{ .. }",
+            })
+        },
+    };
+
+    match kind {
+        #[cfg(all(feature = "derive", feature = "email"))]
+        "email" => {
+            let parameters = Parameters::new(name, value, EMAIL_PARAMETERS)?;
+
+            let mut builder = EmailValidator::builder();
+
+            if let Some(comment) = parameters.allow("comment")? {
+                builder = builder.comment(comment);
+            }
+
+            if let Some(ip) = parameters.tri_allow("ip")? {
+                builder = builder.ip(ip);
+            }
+
+            if let Some(local) = parameters.tri_allow("local")? {
+                builder = builder.local(local);
+            }
+
+            if let Some(at_least_two_labels) = parameters.tri_allow("at_least_two_labels")? {
+                builder = builder.at_least_two_labels(at_least_two_labels);
+            }
+
+            if let Some(non_ascii) = parameters.allow("non_ascii")? {
+                builder = builder.non_ascii(non_ascii);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::Email)
+        },
+        #[cfg(all(feature = "derive", feature = "host"))]
+        "host" => {
+            let parameters = Parameters::new(name, value, HOST_PARAMETERS)?;
+
+            let mut builder = HostValidator::builder();
+
+            if let Some(local) = parameters.tri_allow("local")? {
+                builder = builder.local(local);
+            }
+
+            if let Some(port) = parameters.tri_allow("port")? {
+                builder = builder.port(port);
+            }
+
+            if let Some(at_least_two_labels) = parameters.tri_allow("at_least_two_labels")? {
+                builder = builder.at_least_two_labels(at_least_two_labels);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::Host)
+        },
+        #[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+        "http_ftp_url" => {
+            let parameters = Parameters::new(name, value, HTTP_FTP_URL_PARAMETERS)?;
+
+            let mut builder = HttpFtpUrlValidator::builder();
+
+            if let Some(local) = parameters.tri_allow("local")? {
+                builder = builder.local(local);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::HttpFtpUrl)
+        },
+        #[cfg(feature = "line")]
+        "line" => {
+            let parameters = Parameters::new(name, value, UTF8_PARAMETERS)?;
+
+            let mut builder = LineValidator::builder();
+
+            if let Some((unit, length)) = parameters.length()? {
+                builder = builder.unit(unit);
+
+                if let Some(min) = length.usize("min")? {
+                    builder = builder.min(min);
+                }
+
+                if let Some(trimmed_min) = length.usize("trimmed_min")? {
+                    builder = builder.trimmed_min(trimmed_min);
+                }
+
+                if let Some(max) = length.usize("max")? {
+                    builder = builder.max(max);
+                }
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::Line)
+        },
+        #[cfg(feature = "mac_address")]
+        "mac_address" => {
+            let parameters = Parameters::new(name, value, HEX_PARAMETERS)?;
+
+            let mut builder = MacAddressValidator::builder();
+
+            if let Some(case) = parameters.case("case")? {
+                builder = builder.case(case);
+            }
+
+            if let Some(separator) = parameters.separator("separator")? {
+                builder = builder.separator(separator);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::MacAddress)
+        },
+        #[cfg(feature = "number")]
+        "number" => {
+            let parameters = Parameters::new(name, value, NUMBER_PARAMETERS)?;
+
+            let mut builder = NumberValidator::builder();
+
+            if let Some(range) = parameters.range("range")? {
+                builder = builder.range(range);
+            }
+
+            if let Some(nan) = parameters.tri_allow("nan")? {
+                builder = builder.nan(nan);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::Number)
+        },
+        #[cfg(feature = "signed_integer")]
+        "signed_integer" => {
+            let parameters = Parameters::new(name, value, RANGE_ATTRIBUTE_PARAMETERS)?;
+
+            let mut builder = SignedIntegerValidator::builder();
+
+            if let Some(range) = parameters.range("range")? {
+                builder = builder.range(range);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::SignedInteger)
+        },
+        #[cfg(feature = "text")]
+        "text" => {
+            let parameters = Parameters::new(name, value, UTF8_PARAMETERS)?;
+
+            let mut builder = TextValidator::builder();
+
+            if let Some((unit, length)) = parameters.length()? {
+                builder = builder.unit(unit);
+
+                if let Some(min) = length.usize("min")? {
+                    builder = builder.min(min);
+                }
+
+                if let Some(trimmed_min) = length.usize("trimmed_min")? {
+                    builder = builder.trimmed_min(trimmed_min);
+                }
+
+                if let Some(max) = length.usize("max")? {
+                    builder = builder.max(max);
+                }
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::Text)
+        },
+        #[cfg(feature = "unsigned_integer")]
+        "unsigned_integer" => {
+            let parameters = Parameters::new(name, value, RANGE_ATTRIBUTE_PARAMETERS)?;
+
+            let mut builder = UnsignedIntegerValidator::builder();
+
+            if let Some(range) = parameters.range("range")? {
+                builder = builder.range(range);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::UnsignedInteger)
+        },
+        #[cfg(feature = "uuid")]
+        "uuid" => {
+            let parameters = Parameters::new(name, value, HEX_PARAMETERS)?;
+
+            let mut builder = UuidValidator::builder();
+
+            if let Some(case) = parameters.case("case")? {
+                builder = builder.case(case);
+            }
+
+            if let Some(separator) = parameters.separator("separator")? {
+                builder = builder.separator(separator);
+            }
+
+            parameters.build(builder.build()).map(RuntimeValidator::Uuid)
+        },
+        _ => Err(ConfigError::UnsupportedValidator {
+            name,
+            kind: String::from(kind),
+        }),
+    }
+}
+
+/// A table whose keys have been checked to be supported parameters.
+struct Parameters<'a> {
+    name: String,
+    map:  &'a Map<String, Value>,
+}
+
+impl<'a> Parameters<'a> {
+    fn new(
+        name: String,
+        value: &'a Value,
+        available: &'static [&'static str],
+    ) -> Result<Self, ConfigError> {
+        match value.as_object() {
+            Some(map) => Self::from_map(name, map, available),
+            None => Err(ConfigError::IncorrectValue {
+                name,
+                expected: "{ .. }",
+            }),
+        }
+    }
+
+    fn from_map<S: Into<String>>(
+        name: S,
+        map: &'a Map<String, Value>,
+        available: &'static [&'static str],
+    ) -> Result<Self, ConfigError> {
+        let parameters = Self {
+            name: name.into(),
+            map,
+        };
+
+        for key in map.keys() {
+            if !available.contains(&key.as_str()) {
+                return Err(ConfigError::UnsupportedParameter {
+                    name: parameters.path(key),
+                    available,
+                });
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    #[inline]
+    fn path(&self, key: &str) -> String {
+        if self.name.is_empty() {
+            String::from(key)
+        } else {
+            format!("{}.{key}", self.name)
+        }
+    }
+
+    /// Report the options which the validator rejects as `IncorrectOptions` of this table.
+    #[inline]
+    fn build<T>(&self, result: Result<T, OptionsError>) -> Result<T, ConfigError> {
+        result.map_err(|error| ConfigError::IncorrectOptions {
+            name: self.name.clone(),
+            error,
+        })
+    }
+
+    #[inline]
+    fn get(&self, key: &str) -> Option<(String, &'a Value)> {
+        self.map.get(key).map(|value| (self.path(key), value))
+    }
+
+    #[cfg(any(
+        all(
+            feature = "derive",
+            any(feature = "email", feature = "host", feature = "http_ftp_url")
+        ),
+        feature = "number"
+    ))]
+    fn tri_allow(&self, key: &str) -> Result<Option<TriAllow>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => match value.as_str() {
+                Some("Must") => Ok(Some(TriAllow::Must)),
+                Some("Allow") => Ok(Some(TriAllow::Allow)),
+                Some("Disallow") => Ok(Some(TriAllow::Disallow)),
+                _ => Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: "\"Must\"/\"Allow\"/\"Disallow\"",
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// A parameter which can only be `Allow` or `Disallow`, such as `comment` of the `email` validator.
+    #[cfg(all(feature = "derive", feature = "email"))]
+    fn allow(&self, key: &str) -> Result<Option<TriAllow>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => match value.as_str() {
+                Some("Allow") => Ok(Some(TriAllow::Allow)),
+                Some("Disallow") => Ok(Some(TriAllow::Disallow)),
+                _ => Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: "\"Allow\"/\"Disallow\"",
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(any(feature = "mac_address", feature = "uuid"))]
+    fn case(&self, key: &str) -> Result<Option<CaseOption>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => match value.as_str() {
+                Some("Any") => Ok(Some(CaseOption::Any)),
+                Some("Upper") => Ok(Some(CaseOption::Upper)),
+                Some("Lower") => Ok(Some(CaseOption::Lower)),
+                _ => Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: "\"Any\"/\"Upper\"/\"Lower\"",
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// The `separator` parameter, written as `"Disallow"`, `{ Must = "-" }` or `{ Allow = "-" }`.
+    #[cfg(any(feature = "mac_address", feature = "uuid"))]
+    fn separator(&self, key: &str) -> Result<Option<SeparatorOption>, ConfigError> {
+        let (name, value) = match self.get(key) {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let expected = "\"Disallow\"/{ Must = \"..\" }/{ Allow = \"..\" }";
+
+        if value.as_str() == Some("Disallow") {
+            return Ok(Some(SeparatorOption::Disallow));
+        }
+
+        let option = match value.as_object() {
+            Some(_) => Parameters::new(name.clone(), value, SEPARATOR_OPTION_PARAMETERS)?,
+            None => {
+                return Err(ConfigError::IncorrectValue {
+                    name,
+                    expected,
+                })
+            },
+        };
+
+        let mut variants = option.map.iter();
+
+        match (variants.next(), variants.next()) {
+            (Some((variant, value)), None) => {
+                let separator = match value.as_str().map(str::as_bytes) {
+                    Some(&[separator]) => separator,
+                    _ => {
+                        return Err(ConfigError::IncorrectValue {
+                            name:     option.path(variant),
+                            expected: "a single ASCII character",
+                        })
+                    },
+                };
+
+                if variant == "Must" {
+                    Ok(Some(SeparatorOption::Must(separator)))
+                } else {
+                    Ok(Some(SeparatorOption::Allow(separator)))
+                }
+            },
+            _ => Err(ConfigError::IncorrectValue {
+                name,
+                expected,
+            }),
+        }
+    }
+
+    #[cfg(any(
+        any(feature = "line", feature = "text"),
+        feature = "number",
+        feature = "signed_integer",
+        feature = "unsigned_integer"
+    ))]
+    fn table(
+        &self,
+        key: &str,
+        available: &'static [&'static str],
+    ) -> Result<Option<Parameters<'a>>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => Parameters::new(name, value, available).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The `char_length` or `byte_length` parameter.
+    #[cfg(any(feature = "line", feature = "text"))]
+    fn length(&self) -> Result<Option<(LengthUnit, Parameters<'a>)>, ConfigError> {
+        let char_length = self.table("char_length", LENGTH_PARAMETERS)?;
+        let byte_length = self.table("byte_length", LENGTH_PARAMETERS)?;
+
+        match (char_length, byte_length) {
+            (Some(_), Some(_)) => Err(ConfigError::ParameterReset(self.path("byte_length"))),
+            (Some(length), None) => Ok(Some((LengthUnit::Char, length))),
+            (None, Some(length)) => Ok(Some((LengthUnit::Byte, length))),
+            (None, None) => Ok(None),
+        }
+    }
+
+    #[cfg(any(feature = "line", feature = "text"))]
+    fn usize(&self, key: &str) -> Result<Option<usize>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => match value.as_u64().and_then(|n| usize::try_from(n).ok()) {
+                Some(n) => Ok(Some(n)),
+                None => Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: "a non-negative integer",
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+    fn range<T: ConfigNumber>(&self, key: &str) -> Result<Option<RangeOption<T>>, ConfigError> {
+        let (name, value) = match self.get(key) {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let option = Parameters::new(name.clone(), value, RANGE_OPTION_PARAMETERS)?;
+
+        let mut variants = option.map.keys();
+
+        let range = match (variants.next(), variants.next()) {
+            (Some(variant), None) => match variant.as_str() {
+                "Unlimited" => {
+                    option.table(variant, &[])?;
+
+                    RangeOption::Unlimited
+                },
+                _ => {
+                    let range = option.table(variant, RANGE_PARAMETERS)?.unwrap();
+
+                    let min = range.number("min")?;
+                    let max = range.number("max")?;
+                    let inclusive = range.bool("inclusive")?.unwrap_or(true);
+
+                    if variant == "Inside" {
+                        RangeOption::Inside {
+                            min,
+                            max,
+                            inclusive,
+                        }
+                    } else {
+                        RangeOption::Outside {
+                            min,
+                            max,
+                            inclusive,
+                        }
+                    }
+                },
+            },
+            _ => {
+                return Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: "{ Inside = { .. } }/{ Outside = { .. } }/{ Unlimited = {} }",
+                })
+            },
+        };
+
+        Ok(Some(range))
+    }
+
+    #[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+    fn number<T: ConfigNumber>(&self, key: &str) -> Result<Option<T>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => match T::from_value(value) {
+                Some(n) => Ok(Some(n)),
+                None => Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: T::EXPECTED,
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+    fn bool(&self, key: &str) -> Result<Option<bool>, ConfigError> {
+        match self.get(key) {
+            Some((name, value)) => match value.as_bool() {
+                Some(b) => Ok(Some(b)),
+                None => Err(ConfigError::IncorrectValue {
+                    name,
+                    expected: "true/false",
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+/// The types of the bounds of `RangeOption`s.
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+trait ConfigNumber: Sized {
+    const EXPECTED: &'static str;
+
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+#[cfg(feature = "number")]
+impl ConfigNumber for f64 {
+    const EXPECTED: &'static str = "a number";
+
+    #[inline]
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+#[cfg(feature = "signed_integer")]
+impl ConfigNumber for i128 {
+    const EXPECTED: &'static str = "an integer";
+
+    #[inline]
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_i64().map(i128::from).or_else(|| value.as_u64().map(i128::from))
+    }
+}
+
+#[cfg(feature = "unsigned_integer")]
+impl ConfigNumber for u128 {
+    const EXPECTED: &'static str = "a non-negative integer";
+
+    #[inline]
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_u64().map(u128::from)
+    }
+}
@@ -0,0 +1,12 @@
+use core::fmt::Display;
+
+use crate::traits::ErrorCode;
+
+/// Convert the error of a validator to the error of a deserializer. With the `axum` feature, the error is also kept for the `Query` extractor, so its rejection has the code of the error.
+#[inline]
+pub fn error<E: serde::de::Error, T: ErrorCode + Display>(error: T) -> E {
+    #[cfg(feature = "axum")]
+    crate::web::set_deserialize_error(crate::web::ErrorBody::from_error(&error));
+
+    E::custom(error)
+}
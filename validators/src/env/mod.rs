@@ -0,0 +1,163 @@
+use alloc::{borrow::Cow, string::String};
+use std::{collections::HashMap, env::VarError};
+
+use crate::{
+    errors::EnvError,
+    traits::{FieldInput, FieldSource, ValidateString},
+};
+
+/**
+Read the environment variable `name` and parse it with a validator.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer"))]
+# {
+use validators::{errors::EnvError, prelude::*};
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Port(u16);
+
+std::env::set_var("DOC_VAR_PORT", "8080");
+std::env::set_var("DOC_VAR_ZERO", "0");
+
+assert_eq!(8080, validators::env::var::<Port>("DOC_VAR_PORT").unwrap().0);
+
+let error = validators::env::var::<Port>("DOC_VAR_ZERO").unwrap_err();
+
+assert_eq!("DOC_VAR_ZERO", error.name());
+assert_eq!("unsigned_integer.too_small", error.code());
+
+assert!(matches!(
+    validators::env::var::<Port>("DOC_VAR_MISSING"),
+    Err(EnvError::NotPresent { .. })
+));
+# }
+```
+ */
+#[inline]
+pub fn var<T: ValidateString>(name: &str) -> Result<T, EnvError<T::Error>> {
+    var_opt(name)?.ok_or_else(|| EnvError::NotPresent {
+        name: String::from(name)
+    })
+}
+
+/// Read the environment variable `name` and parse it with a validator. The result is `None` if the variable is not set.
+#[inline]
+pub fn var_opt<T: ValidateString>(name: &str) -> Result<Option<T>, EnvError<T::Error>> {
+    match std::env::var(name) {
+        Ok(v) => T::parse_string(v).map(Some).map_err(|error| EnvError::Invalid {
+            name: String::from(name),
+            error,
+        }),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(VarError::NotUnicode(_)) => Err(EnvError::NotUnicode {
+            name: String::from(name)
+        }),
+    }
+}
+
+/**
+A snapshot of the environment variables, which can be the source of structs derived with the `Validate` derive macro in order to report all missing and invalid variables at once.
+
+A field is looked up by its name with the prefix, and then by the upper-cased one, so `database_host` matches `DATABASE_HOST`. Use `#[validate(rename = "...")]` for other names. Errors are reported under the name of the variable, which is the upper-cased one if the variable is missing. A variable whose value is not valid Unicode is reported with `NotUnicodeFieldError`, and variables whose names are not valid Unicode are left out.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "host", feature = "unsigned_integer"))]
+# {
+use validators::{models::Host, prelude::*};
+
+#[derive(Debug, Validator)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct DatabaseHost {
+    pub host: Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Workers(u8);
+
+#[derive(Debug, Validate)]
+pub struct Config {
+    database_host: DatabaseHost,
+    workers:       Option<Workers>,
+    #[validate(rename = "CACHE")]
+    cache_host:    DatabaseHost,
+}
+
+std::env::set_var("DOC_VARS_DATABASE_HOST", "localhost:5432");
+std::env::set_var("DOC_VARS_WORKERS", "0");
+
+let errors = Config::parse_fields(&validators::env::Vars::with_prefix("DOC_VARS_")).unwrap_err();
+
+assert_eq!(2, errors.len());
+assert_eq!(
+    "DOC_VARS_WORKERS: integer is too small, must be at least 1 (got 0)\nDOC_VARS_CACHE: \
+     missing field",
+    errors.to_string()
+);
+# }
+```
+ */
+#[derive(Debug, Clone, Default)]
+pub struct Vars {
+    prefix: String,
+    /// The values which are not valid Unicode are `None`.
+    vars:   HashMap<String, Option<String>>,
+}
+
+impl Vars {
+    /// Take a snapshot of the environment variables of the current process.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_prefix("")
+    }
+
+    /// Take a snapshot of the environment variables of the current process. Fields are looked up with `prefix` prepended to their names.
+    #[inline]
+    pub fn with_prefix<S: Into<String>>(prefix: S) -> Self {
+        let vars = std::env::vars_os()
+            .filter_map(|(k, v)| Some((k.into_string().ok()?, v.into_string().ok())))
+            .collect();
+
+        Self {
+            prefix: prefix.into(),
+            vars,
+        }
+    }
+}
+
+impl FieldSource for Vars {
+    /// Find the variable of the field `name`, by the name with the prefix and then by the upper-cased one. The name of a missing variable is the upper-cased one.
+    fn lookup_field<'a>(&'a self, name: &'a str) -> FieldInput<'a> {
+        let mut name = if self.prefix.is_empty() {
+            Cow::Borrowed(name)
+        } else {
+            Cow::Owned(alloc::format!("{}{name}", self.prefix))
+        };
+
+        let value = match self.vars.get(name.as_ref()) {
+            Some(value) => Some(value),
+            None if name.bytes().any(|e| e.is_ascii_lowercase()) => {
+                name.to_mut().make_ascii_uppercase();
+
+                self.vars.get(name.as_ref())
+            },
+            None => None,
+        };
+
+        match value {
+            Some(Some(value)) => FieldInput::Present {
+                name,
+                value,
+            },
+            Some(None) => FieldInput::NotUnicode {
+                name,
+            },
+            None => FieldInput::Missing {
+                name,
+            },
+        }
+    }
+}
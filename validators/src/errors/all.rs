@@ -0,0 +1,58 @@
+use alloc::boxed::Box;
+#[cfg(feature = "i18n")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::{DynError, ErrorCode};
+
+/// Error from the `all` validator. `index` is the index of the first validator which fails, and `error` is its error.
+///
+/// The message and the code are the ones of `error`.
+#[derive(Debug)]
+pub struct AllError {
+    pub index: usize,
+    pub error: Box<dyn DynError>,
+}
+
+impl AllError {
+    #[inline]
+    pub fn new<E: DynError + 'static>(index: usize, error: E) -> Self {
+        Self {
+            index,
+            error: Box::new(error),
+        }
+    }
+}
+
+impl Display for AllError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AllError {}
+
+impl ErrorCode for AllError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        self.error.code()
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        self.error.message_key()
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        self.error.message_args()
+    }
+}
@@ -0,0 +1,46 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::traits::{DynError, ErrorCode};
+
+/// Error from the `any` validator. `errors` are the errors of every validator, in order.
+#[derive(Debug, Default)]
+pub struct AnyError {
+    pub errors: Vec<Box<dyn DynError>>,
+}
+
+impl AnyError {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn push<E: DynError + 'static>(&mut self, error: E) {
+        self.errors.push(Box::new(error));
+    }
+}
+
+impl Display for AnyError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("not valid for any of the validators")?;
+
+        for (i, error) in self.errors.iter().enumerate() {
+            f.write_fmt(format_args!("{} {error}", if i == 0 { ":" } else { ";" }))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AnyError {}
+
+impl ErrorCode for AnyError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "any.invalid"
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `base32` validator.
 #[derive(Debug, Clone)]
 pub enum Base32Error {
@@ -26,3 +28,14 @@ impl Display for Base32Error {
 
 #[cfg(feature = "std")]
 impl Error for Base32Error {}
+
+impl ErrorCode for Base32Error {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base32.invalid",
+            Self::PaddingMust => "base32.padding_must",
+            Self::PaddingDisallow => "base32.padding_disallow",
+        }
+    }
+}
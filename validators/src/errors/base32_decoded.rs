@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `base32_decoded` validator.
 #[derive(Debug, Clone)]
 pub enum Base32DecodedError {
@@ -29,3 +31,15 @@ impl Display for Base32DecodedError {
 
 #[cfg(feature = "std")]
 impl Error for Base32DecodedError {}
+
+impl ErrorCode for Base32DecodedError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base32_decoded.invalid",
+            Self::PaddingMust => "base32_decoded.padding_must",
+            Self::PaddingDisallow => "base32_decoded.padding_disallow",
+            Self::Decode => "base32_decoded.decode",
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `base64` validator.
 #[derive(Debug, Clone)]
 pub enum Base64Error {
@@ -26,3 +28,14 @@ impl Display for Base64Error {
 
 #[cfg(feature = "std")]
 impl Error for Base64Error {}
+
+impl ErrorCode for Base64Error {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base64.invalid",
+            Self::PaddingMust => "base64.padding_must",
+            Self::PaddingDisallow => "base64.padding_disallow",
+        }
+    }
+}
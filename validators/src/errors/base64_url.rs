@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `base64_url` validator.
 #[derive(Debug, Clone)]
 pub enum Base64UrlError {
@@ -26,3 +28,14 @@ impl Display for Base64UrlError {
 
 #[cfg(feature = "std")]
 impl Error for Base64UrlError {}
+
+impl ErrorCode for Base64UrlError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base64_url.invalid",
+            Self::PaddingMust => "base64_url.padding_must",
+            Self::PaddingDisallow => "base64_url.padding_disallow",
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `base64_url_decoded` validator.
 #[derive(Debug, Clone)]
 pub enum Base64UrlDecodedError {
@@ -29,3 +31,15 @@ impl Display for Base64UrlDecodedError {
 
 #[cfg(feature = "std")]
 impl Error for Base64UrlDecodedError {}
+
+impl ErrorCode for Base64UrlDecodedError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base64_url_decoded.invalid",
+            Self::PaddingMust => "base64_url_decoded.padding_must",
+            Self::PaddingDisallow => "base64_url_decoded.padding_disallow",
+            Self::Decode => "base64_url_decoded.decode",
+        }
+    }
+}
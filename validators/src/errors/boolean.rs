@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `boolean` validator.
 #[derive(Debug, Clone)]
 pub struct BooleanError;
@@ -15,3 +17,10 @@ impl Display for BooleanError {
 
 #[cfg(feature = "std")]
 impl Error for BooleanError {}
+
+impl ErrorCode for BooleanError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "boolean.invalid"
+    }
+}
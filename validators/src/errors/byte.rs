@@ -1,15 +1,30 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
 use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
 use byte_unit::ParseError;
 
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::ErrorCode;
+
 /// Error from the `bit` validator.
 #[derive(Debug, Clone)]
 pub enum ByteError {
     ParseError(ParseError),
-    TooLarge,
-    TooSmall,
+    /// The size is larger than `max`, or equal to `max` if the range is not `inclusive`.
+    TooLarge {
+        max:       u128,
+        inclusive: bool,
+        value:     u128,
+    },
+    /// The size is smaller than `min`.
+    TooSmall {
+        min:   u128,
+        value: u128,
+    },
 }
 
 impl From<ParseError> for ByteError {
@@ -24,11 +39,72 @@ impl Display for ByteError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
             Self::ParseError(error) => Display::fmt(error, f),
-            Self::TooLarge => f.write_str("byte is too large"),
-            Self::TooSmall => f.write_str("byte is too small"),
+            Self::TooLarge {
+                max,
+                inclusive,
+                value,
+            } => {
+                if *inclusive {
+                    f.write_fmt(format_args!(
+                        "byte is too large, must be at most {max} bytes (got {value})"
+                    ))
+                } else {
+                    f.write_fmt(format_args!(
+                        "byte is too large, must be less than {max} bytes (got {value})"
+                    ))
+                }
+            },
+            Self::TooSmall {
+                min,
+                value,
+            } => f.write_fmt(format_args!(
+                "byte is too small, must be at least {min} bytes (got {value})"
+            )),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for ByteError {}
+
+impl ErrorCode for ByteError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "byte.invalid",
+            Self::TooLarge {
+                ..
+            } => "byte.too_large",
+            Self::TooSmall {
+                ..
+            } => "byte.too_small",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::TooLarge {
+                inclusive: false, ..
+            } => "byte.too_large_exclusive",
+            _ => self.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::ParseError(_) => Vec::new(),
+            Self::TooLarge {
+                max,
+                value,
+                ..
+            } => alloc::vec![("max", max.to_string().into()), ("value", value.to_string().into())],
+            Self::TooSmall {
+                min,
+                value,
+            } => alloc::vec![("min", min.to_string().into()), ("value", value.to_string().into())],
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+/// Error from parsing a message catalog.
+#[derive(Debug)]
+pub enum CatalogError {
+    TomlError(toml::de::Error),
+    /// The value of the key is neither a message nor a table of messages.
+    NotString(String),
+}
+
+impl From<toml::de::Error> for CatalogError {
+    #[inline]
+    fn from(error: toml::de::Error) -> Self {
+        Self::TomlError(error)
+    }
+}
+
+impl Display for CatalogError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::TomlError(error) => Display::fmt(error, f),
+            Self::NotString(key) => {
+                f.write_fmt(format_args!("the value of `{key}` is not a string"))
+            },
+        }
+    }
+}
+
+impl Error for CatalogError {}
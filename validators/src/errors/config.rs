@@ -0,0 +1,103 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+use super::OptionsError;
+
+/// Error from loading validators from a configuration file.
+#[derive(Debug)]
+pub enum ConfigError {
+    TomlError(toml::de::Error),
+    JsonError(serde_json::Error),
+    /// The `kind` of a validator is not supported.
+    UnsupportedValidator {
+        name: String,
+        kind: String,
+    },
+    /// The parameter is not supported. `available` lists the supported ones.
+    UnsupportedParameter {
+        name:      String,
+        available: &'static [&'static str],
+    },
+    /// The parameter is set more than once, such as both `char_length` and `byte_length`.
+    ParameterReset(String),
+    /// The parameter is required but not set.
+    ParameterMissing(String),
+    /// The value of the parameter is not `expected`.
+    IncorrectValue {
+        name:     String,
+        expected: &'static str,
+    },
+    /// The parameters of the validator cannot be used together, the same as the attribute rejects them at compile time.
+    IncorrectOptions {
+        name:  String,
+        error: OptionsError,
+    },
+}
+
+impl From<toml::de::Error> for ConfigError {
+    #[inline]
+    fn from(error: toml::de::Error) -> Self {
+        Self::TomlError(error)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    #[inline]
+    fn from(error: serde_json::Error) -> Self {
+        Self::JsonError(error)
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::TomlError(error) => Display::fmt(error, f),
+            Self::JsonError(error) => Display::fmt(error, f),
+            Self::UnsupportedValidator {
+                name,
+                kind,
+            } => {
+                f.write_fmt(format_args!(
+                    "unsupported validator `{kind}` of `{name}`, available validators:"
+                ))?;
+
+                for kind in crate::config::KINDS {
+                    f.write_str("\n    ")?;
+                    f.write_str(kind)?;
+                }
+
+                Ok(())
+            },
+            Self::UnsupportedParameter {
+                name,
+                available,
+            } => {
+                f.write_fmt(format_args!("unsupported parameter `{name}`, available parameters:"))?;
+
+                for parameter in available.iter() {
+                    f.write_str("\n    ")?;
+                    f.write_str(parameter)?;
+                }
+
+                Ok(())
+            },
+            Self::ParameterReset(name) => {
+                f.write_fmt(format_args!("you are trying to reset the `{name}` parameter"))
+            },
+            Self::ParameterMissing(name) => {
+                f.write_fmt(format_args!("the `{name}` parameter is missing"))
+            },
+            Self::IncorrectValue {
+                name,
+                expected,
+            } => f.write_fmt(format_args!("expected `{name} = {expected}`")),
+            Self::IncorrectOptions {
+                name,
+                error,
+            } => f.write_fmt(format_args!("incorrect options of `{name}`: {error}")),
+        }
+    }
+}
+
+impl Error for ConfigError {}
@@ -0,0 +1,74 @@
+use alloc::boxed::Box;
+#[cfg(feature = "i18n")]
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::{DynError, ErrorCode};
+
+/// Error from a validator which has the `custom` parameter. `Validator` is the error of the built-in validator, and `Custom` is the error returned by the custom function.
+///
+/// The message and the code are the ones of the wrapped error.
+#[derive(Debug)]
+pub enum CustomError<E> {
+    Validator(E),
+    Custom(Box<dyn DynError>),
+}
+
+impl<E> CustomError<E> {
+    #[inline]
+    pub fn custom<C: DynError + 'static>(error: C) -> Self {
+        Self::Custom(Box::new(error))
+    }
+}
+
+impl<E> From<E> for CustomError<E> {
+    #[inline]
+    fn from(error: E) -> Self {
+        Self::Validator(error)
+    }
+}
+
+impl<E: Display> Display for CustomError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Validator(error) => Display::fmt(error, f),
+            Self::Custom(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Display + Debug> Error for CustomError<E> {}
+
+impl<E: ErrorCode> ErrorCode for CustomError<E> {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Validator(error) => error.code(),
+            Self::Custom(error) => error.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::Validator(error) => error.message_key(),
+            Self::Custom(error) => error.message_key(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::Validator(error) => error.message_args(),
+            Self::Custom(error) => error.message_args(),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `domain` validator.
 #[derive(Debug, Clone)]
 pub enum DomainError {
@@ -44,3 +46,20 @@ impl Display for DomainError {
 
 #[cfg(feature = "std")]
 impl Error for DomainError {}
+
+impl ErrorCode for DomainError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "domain.invalid",
+            Self::IPv4Must => "domain.ipv4_must",
+            Self::IPv4Disallow => "domain.ipv4_disallow",
+            Self::LocalMust => "domain.local_must",
+            Self::LocalDisallow => "domain.local_disallow",
+            Self::AtLeastTwoLabelsMust => "domain.at_least_two_labels_must",
+            Self::AtLeastTwoLabelsDisallow => "domain.at_least_two_labels_disallow",
+            Self::PortMust => "domain.port_must",
+            Self::PortDisallow => "domain.port_disallow",
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::string::String;
+#[cfg(feature = "i18n")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::ErrorCode;
+
+/// Error from a `DynValidator` or a `Registry`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DynValidatorError {
+    /// No validator is registered with the name.
+    UnknownValidator(String),
+    /// The `Value` is not a string, a number or a boolean.
+    UnsupportedValue,
+}
+
+impl Display for DynValidatorError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::UnknownValidator(name) => f.write_fmt(format_args!("unknown validator `{name}`")),
+            Self::UnsupportedValue => {
+                f.write_str("unsupported value, must be a string, a number or a boolean")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DynValidatorError {}
+
+impl ErrorCode for DynValidatorError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::UnknownValidator(_) => "dyn_validator.unknown_validator",
+            Self::UnsupportedValue => "dyn_validator.unsupported_value",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::UnknownValidator(name) => alloc::vec![("name", name.clone().into())],
+            Self::UnsupportedValue => Vec::new(),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `email` validator.
 #[derive(Debug, Clone)]
 pub enum EmailError {
@@ -41,3 +43,19 @@ impl Display for EmailError {
 
 #[cfg(feature = "std")]
 impl Error for EmailError {}
+
+impl ErrorCode for EmailError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "email.invalid",
+            Self::IPMust => "email.ip_must",
+            Self::IPDisallow => "email.ip_disallow",
+            Self::LocalMust => "email.local_must",
+            Self::LocalDisallow => "email.local_disallow",
+            Self::AtLeastTwoLabelsMust => "email.at_least_two_labels_must",
+            Self::AtLeastTwoLabelsDisallow => "email.at_least_two_labels_disallow",
+            Self::CommentDisallow => "email.comment_disallow",
+        }
+    }
+}
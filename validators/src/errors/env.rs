@@ -0,0 +1,114 @@
+use alloc::string::String;
+#[cfg(feature = "i18n")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::ErrorCode;
+
+/// Error from reading an environment variable with a validator. Every variant keeps the name of the variable.
+#[derive(Debug, Clone)]
+pub enum EnvError<E> {
+    /// The variable is not set.
+    NotPresent { name: String },
+    /// The value of the variable is not valid Unicode.
+    NotUnicode { name: String },
+    /// The value of the variable is rejected by the validator.
+    Invalid { name: String, error: E },
+}
+
+impl<E> EnvError<E> {
+    /// Get the name of the variable.
+    #[inline]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::NotPresent {
+                name,
+            }
+            | Self::NotUnicode {
+                name,
+            }
+            | Self::Invalid {
+                name, ..
+            } => name,
+        }
+    }
+
+    /// Get the error from the validator, if the value is rejected by it.
+    #[inline]
+    pub fn validator_error(&self) -> Option<&E> {
+        match self {
+            Self::Invalid {
+                error, ..
+            } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Display> Display for EnvError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::NotPresent {
+                name,
+            } => f.write_fmt(format_args!("environment variable `{name}` is not set")),
+            Self::NotUnicode {
+                name,
+            } => f.write_fmt(format_args!("environment variable `{name}` is not valid unicode")),
+            Self::Invalid {
+                name,
+                error,
+            } => f.write_fmt(format_args!("environment variable `{name}` is invalid: {error}")),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for EnvError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.validator_error().map(|error| error as &(dyn Error + 'static))
+    }
+}
+
+/// The code of `Invalid` is the code of the validator error.
+impl<E: ErrorCode> ErrorCode for EnvError<E> {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NotPresent {
+                ..
+            } => "env.not_present",
+            Self::NotUnicode {
+                ..
+            } => "env.not_unicode",
+            Self::Invalid {
+                error, ..
+            } => error.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::Invalid {
+                error, ..
+            } => error.message_key(),
+            _ => self.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::Invalid {
+                error, ..
+            } => error.message_args(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `host` validator.
 #[derive(Debug, Clone)]
 pub enum HostError {
@@ -38,3 +40,18 @@ impl Display for HostError {
 
 #[cfg(feature = "std")]
 impl Error for HostError {}
+
+impl ErrorCode for HostError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "host.invalid",
+            Self::LocalMust => "host.local_must",
+            Self::LocalDisallow => "host.local_disallow",
+            Self::AtLeastTwoLabelsMust => "host.at_least_two_labels_must",
+            Self::AtLeastTwoLabelsDisallow => "host.at_least_two_labels_disallow",
+            Self::PortMust => "host.port_must",
+            Self::PortDisallow => "host.port_disallow",
+        }
+    }
+}
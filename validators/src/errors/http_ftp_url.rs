@@ -2,7 +2,7 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
-use crate::url;
+use crate::{traits::ErrorCode, url};
 
 /// Error from the `http_ftp_url` validator.
 #[derive(Debug, Clone)]
@@ -37,3 +37,15 @@ impl Display for HttpFtpURLError {
 
 #[cfg(feature = "std")]
 impl Error for HttpFtpURLError {}
+
+impl ErrorCode for HttpFtpURLError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "http_ftp_url.invalid",
+            Self::ProtocolError => "http_ftp_url.protocol_disallow",
+            Self::LocalMust => "http_ftp_url.local_must",
+            Self::LocalDisallow => "http_ftp_url.local_disallow",
+        }
+    }
+}
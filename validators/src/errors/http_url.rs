@@ -2,7 +2,7 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
-use crate::url;
+use crate::{traits::ErrorCode, url};
 
 /// Error from the `http_url` validator.
 #[derive(Debug, Clone)]
@@ -35,3 +35,15 @@ impl Display for HttpURLError {
 
 #[cfg(feature = "std")]
 impl Error for HttpURLError {}
+
+impl ErrorCode for HttpURLError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "http_url.invalid",
+            Self::ProtocolError => "http_url.protocol_disallow",
+            Self::LocalMust => "http_url.local_must",
+            Self::LocalDisallow => "http_url.local_disallow",
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `ip` validator.
 #[derive(Debug, Clone)]
 pub enum IpError {
@@ -32,3 +34,16 @@ impl Display for IpError {
 
 #[cfg(feature = "std")]
 impl Error for IpError {}
+
+impl ErrorCode for IpError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "ip.invalid",
+            Self::LocalMust => "ip.local_must",
+            Self::LocalDisallow => "ip.local_disallow",
+            Self::PortMust => "ip.port_must",
+            Self::PortDisallow => "ip.port_disallow",
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `ipv4` validator.
 #[derive(Debug, Clone)]
 pub enum Ipv4Error {
@@ -32,3 +34,16 @@ impl Display for Ipv4Error {
 
 #[cfg(feature = "std")]
 impl Error for Ipv4Error {}
+
+impl ErrorCode for Ipv4Error {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "ipv4.invalid",
+            Self::LocalMust => "ipv4.local_must",
+            Self::LocalDisallow => "ipv4.local_disallow",
+            Self::PortMust => "ipv4.port_must",
+            Self::PortDisallow => "ipv4.port_disallow",
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `ipv6` validator.
 #[derive(Debug, Clone)]
 pub enum Ipv6Error {
@@ -32,3 +34,16 @@ impl Display for Ipv6Error {
 
 #[cfg(feature = "std")]
 impl Error for Ipv6Error {}
+
+impl ErrorCode for Ipv6Error {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "ipv6.invalid",
+            Self::LocalMust => "ipv6.local_must",
+            Self::LocalDisallow => "ipv6.local_disallow",
+            Self::PortMust => "ipv6.port_must",
+            Self::PortDisallow => "ipv6.port_disallow",
+        }
+    }
+}
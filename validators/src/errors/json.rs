@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `json` validator.
 #[derive(Debug)]
 pub enum JsonError {
@@ -29,3 +31,13 @@ impl Display for JsonError {
 
 #[cfg(feature = "std")]
 impl Error for JsonError {}
+
+impl ErrorCode for JsonError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::SerdeJsonError(_) => "json.invalid",
+            Self::InvalidJsonValueError => "json.invalid_value",
+        }
+    }
+}
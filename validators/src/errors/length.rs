@@ -1,23 +1,73 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
 use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::ErrorCode;
+
 /// Error from the `length` validator.
 #[derive(Debug, Clone)]
 pub enum LengthError {
-    TooLarge,
-    TooSmall,
+    /// The collection has more than `max` elements.
+    TooLarge { max: usize, length: usize },
+    /// The collection has fewer than `min` elements.
+    TooSmall { min: usize, length: usize },
 }
 
 impl Display for LengthError {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
-            Self::TooLarge => f.write_str("collection is too large"),
-            Self::TooSmall => f.write_str("collection is too small"),
+            Self::TooLarge {
+                max,
+                length,
+            } => f.write_fmt(format_args!(
+                "collection is too large, must have at most {max} elements (got {length})"
+            )),
+            Self::TooSmall {
+                min,
+                length,
+            } => f.write_fmt(format_args!(
+                "collection is too small, must have at least {min} elements (got {length})"
+            )),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for LengthError {}
+
+impl ErrorCode for LengthError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::TooLarge {
+                ..
+            } => "length.too_large",
+            Self::TooSmall {
+                ..
+            } => "length.too_small",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::TooLarge {
+                max,
+                length,
+            } => {
+                alloc::vec![("max", max.to_string().into()), ("length", length.to_string().into())]
+            },
+            Self::TooSmall {
+                min,
+                length,
+            } => {
+                alloc::vec![("min", min.to_string().into()), ("length", length.to_string().into())]
+            },
+        }
+    }
+}
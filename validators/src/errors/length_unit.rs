@@ -0,0 +1,32 @@
+use core::fmt::{self, Display, Formatter};
+
+/// The unit used to measure the length of a string.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LengthUnit {
+    /// UTF-8 bytes.
+    Byte,
+    /// Unicode scalar values.
+    Char,
+}
+
+impl Display for LengthUnit {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Byte => f.write_str("bytes"),
+            Self::Char => f.write_str("characters"),
+        }
+    }
+}
+
+#[cfg(feature = "i18n")]
+impl LengthUnit {
+    /// The key of the name of this unit in message catalogs.
+    #[inline]
+    pub(crate) const fn message_key(self) -> &'static str {
+        match self {
+            Self::Byte => "length_unit.byte",
+            Self::Char => "length_unit.char",
+        }
+    }
+}
@@ -1,15 +1,62 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
 use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use super::LengthUnit;
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::{functions::LengthViolation, traits::ErrorCode};
+
 /// Error from the `line` validator.
 #[derive(Debug, Clone)]
 pub enum LineError {
     Invalid,
     /// May not be valid, but it is guaranteed that this line is too long.
-    TooLong,
-    /// May not be valid, but it is guaranteed that this line is too short.
-    TooShort,
+    TooLong {
+        max:    usize,
+        length: usize,
+        unit:   LengthUnit,
+    },
+    /// May not be valid, but it is guaranteed that this line is too short. `trimmed_length` is the length without leading and trailing whitespaces.
+    TooShort {
+        min:            Option<usize>,
+        trimmed_min:    Option<usize>,
+        length:         usize,
+        trimmed_length: usize,
+        unit:           LengthUnit,
+    },
+}
+
+impl From<LengthViolation> for LineError {
+    #[inline]
+    fn from(violation: LengthViolation) -> Self {
+        match violation {
+            LengthViolation::TooLong {
+                max,
+                length,
+                unit,
+            } => Self::TooLong {
+                max,
+                length,
+                unit,
+            },
+            LengthViolation::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            } => Self::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            },
+        }
+    }
 }
 
 impl Display for LineError {
@@ -17,11 +64,96 @@ impl Display for LineError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
             Self::Invalid => f.write_str("invalid line"),
-            Self::TooLong => f.write_str("line is too long"),
-            Self::TooShort => f.write_str("line is too short"),
+            Self::TooLong {
+                max,
+                length,
+                unit,
+            } => f.write_fmt(format_args!(
+                "line is too long, must be at most {max} {unit} (got {length})"
+            )),
+            Self::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            } => match min {
+                Some(min) if length < min => f.write_fmt(format_args!(
+                    "line is too short, must be at least {min} {unit} (got {length})"
+                )),
+                _ => match trimmed_min {
+                    Some(trimmed_min) => f.write_fmt(format_args!(
+                        "line is too short, must be at least {trimmed_min} {unit} after trimming \
+                         (got {trimmed_length})"
+                    )),
+                    None => f.write_str("line is too short"),
+                },
+            },
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for LineError {}
+
+impl ErrorCode for LineError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "line.invalid",
+            Self::TooLong {
+                ..
+            } => "line.too_long",
+            Self::TooShort {
+                ..
+            } => "line.too_short",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::TooShort {
+                min,
+                length,
+                ..
+            } if !matches!(min, Some(min) if length < min) => "line.too_short_trimmed",
+            _ => self.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::Invalid => Vec::new(),
+            Self::TooLong {
+                max,
+                length,
+                unit,
+            } => alloc::vec![
+                ("max", max.to_string().into()),
+                ("length", length.to_string().into()),
+                ("unit", MessageArg::Message(unit.message_key())),
+            ],
+            Self::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            } => {
+                let (min, length) = match min {
+                    Some(min) if length < min => (*min, *length),
+                    _ => (trimmed_min.unwrap_or_default(), *trimmed_length),
+                };
+
+                alloc::vec![
+                    ("min", min.to_string().into()),
+                    ("length", length.to_string().into()),
+                    ("unit", MessageArg::Message(unit.message_key())),
+                ]
+            },
+        }
+    }
+}
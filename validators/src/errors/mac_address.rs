@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `mac_address` validator.
 #[derive(Debug, Clone)]
 pub enum MacAddressError {
@@ -26,3 +28,14 @@ impl Display for MacAddressError {
 
 #[cfg(feature = "std")]
 impl Error for MacAddressError {}
+
+impl ErrorCode for MacAddressError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "mac_address.invalid",
+            Self::SeparatorMust => "mac_address.separator_must",
+            Self::SeparatorDisallow => "mac_address.separator_disallow",
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::traits::ErrorCode;
+
+/// Error for a field which has no input.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MissingFieldError;
+
+impl Display for MissingFieldError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("missing field")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for MissingFieldError {}
+
+impl ErrorCode for MissingFieldError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "field.missing"
+    }
+}
+
+/// Error for a field whose input is not valid Unicode, such as an environment variable.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NotUnicodeFieldError;
+
+impl Display for NotUnicodeFieldError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("not valid unicode")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotUnicodeFieldError {}
+
+impl ErrorCode for NotUnicodeFieldError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "field.not_unicode"
+    }
+}
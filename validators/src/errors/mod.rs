@@ -1,3 +1,13 @@
+#[cfg(feature = "all")]
+mod all;
+#[cfg(feature = "all")]
+pub use self::all::*;
+
+#[cfg(feature = "any")]
+mod any;
+#[cfg(feature = "any")]
+pub use self::any::*;
+
 #[cfg(feature = "base32")]
 mod base32;
 #[cfg(feature = "base32")]
@@ -43,16 +53,37 @@ mod byte;
 #[cfg(feature = "byte")]
 pub use self::byte::*;
 
+#[cfg(feature = "i18n")]
+mod catalog;
+#[cfg(feature = "i18n")]
+pub use self::catalog::*;
+
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "config")]
+pub use self::config::*;
+
+mod custom;
+pub use self::custom::*;
+
 #[cfg(feature = "domain")]
 mod domain;
 #[cfg(feature = "domain")]
 pub use self::domain::*;
 
+mod dyn_validator;
+pub use self::dyn_validator::*;
+
 #[cfg(feature = "email")]
 mod email;
 #[cfg(feature = "email")]
 pub use self::email::*;
 
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "std")]
+pub use self::env::*;
+
 #[cfg(feature = "host")]
 mod host;
 #[cfg(feature = "host")]
@@ -98,21 +129,45 @@ mod line;
 #[cfg(feature = "line")]
 pub use self::line::*;
 
+#[cfg(any(feature = "line", feature = "text"))]
+mod length_unit;
+#[cfg(any(feature = "line", feature = "text"))]
+pub use self::length_unit::*;
+
 #[cfg(feature = "mac_address")]
 mod mac_address;
 #[cfg(feature = "mac_address")]
 pub use self::mac_address::*;
 
+mod missing_field;
+pub use self::missing_field::*;
+
+#[cfg(feature = "not")]
+mod not;
+#[cfg(feature = "not")]
+pub use self::not::*;
+
 #[cfg(feature = "number")]
 mod number;
 #[cfg(feature = "number")]
 pub use self::number::*;
 
+mod options;
+pub use self::options::*;
+
+#[cfg(feature = "path")]
+mod path;
+#[cfg(feature = "path")]
+pub use self::path::*;
+
 #[cfg(feature = "phone")]
 mod phone;
 #[cfg(feature = "phone")]
 pub use self::phone::*;
 
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+mod range;
+
 #[cfg(feature = "regex")]
 mod regex;
 #[cfg(feature = "regex")]
@@ -147,3 +202,8 @@ pub use self::url::*;
 mod uuid;
 #[cfg(feature = "uuid")]
 pub use self::uuid::*;
+
+#[cfg(feature = "std")]
+mod validation_errors;
+#[cfg(feature = "std")]
+pub use self::validation_errors::*;
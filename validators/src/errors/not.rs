@@ -0,0 +1,26 @@
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::traits::ErrorCode;
+
+/// Error from the `not` validator.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NotError;
+
+impl Display for NotError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("must not be valid for the validator")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotError {}
+
+impl ErrorCode for NotError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "not.invalid"
+    }
+}
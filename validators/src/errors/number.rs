@@ -1,3 +1,5 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
 use core::{
     fmt::{self, Display, Formatter},
     num::ParseFloatError,
@@ -5,13 +7,33 @@ use core::{
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use super::range::DisplayRange;
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::{functions::RangeViolation, traits::ErrorCode};
+
 /// Error from the `number` validator.
 #[derive(Debug, Clone)]
 pub enum NumberError {
     ParseFloatError(ParseFloatError),
-    TooLarge,
-    TooSmall,
-    Forbidden,
+    /// The number is larger than `max`, or equal to `max` if the range is not `inclusive`.
+    TooLarge {
+        max:       f64,
+        inclusive: bool,
+        value:     f64,
+    },
+    /// The number is smaller than `min`.
+    TooSmall {
+        min:   f64,
+        value: f64,
+    },
+    /// The number is in a forbidden range.
+    Forbidden {
+        min:       Option<f64>,
+        max:       Option<f64>,
+        inclusive: bool,
+        value:     f64,
+    },
     NaNMust,
     NaNDisallow,
 }
@@ -23,14 +45,78 @@ impl From<ParseFloatError> for NumberError {
     }
 }
 
+impl From<RangeViolation<f64>> for NumberError {
+    #[inline]
+    fn from(violation: RangeViolation<f64>) -> Self {
+        match violation {
+            RangeViolation::TooSmall {
+                min,
+                value,
+            } => Self::TooSmall {
+                min,
+                value,
+            },
+            RangeViolation::TooLarge {
+                max,
+                inclusive,
+                value,
+            } => Self::TooLarge {
+                max,
+                inclusive,
+                value,
+            },
+            RangeViolation::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            } => Self::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            },
+        }
+    }
+}
+
 impl Display for NumberError {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
             Self::ParseFloatError(error) => Display::fmt(error, f),
-            Self::TooLarge => f.write_str("number is too large"),
-            Self::TooSmall => f.write_str("number is too small"),
-            Self::Forbidden => f.write_str("number is forbidden"),
+            Self::TooLarge {
+                max,
+                inclusive,
+                value,
+            } => {
+                if *inclusive {
+                    f.write_fmt(format_args!(
+                        "number is too large, must be at most {max} (got {value})"
+                    ))
+                } else {
+                    f.write_fmt(format_args!(
+                        "number is too large, must be less than {max} (got {value})"
+                    ))
+                }
+            },
+            Self::TooSmall {
+                min,
+                value,
+            } => f.write_fmt(format_args!(
+                "number is too small, must be at least {min} (got {value})"
+            )),
+            Self::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            } => f.write_fmt(format_args!(
+                "number is forbidden, must not be in {range} (got {value})",
+                range = DisplayRange {
+                    min: *min, max: *max, inclusive: *inclusive
+                }
+            )),
             Self::NaNMust => f.write_str("must be NaN"),
             Self::NaNDisallow => f.write_str("must not be NaN"),
         }
@@ -39,3 +125,66 @@ impl Display for NumberError {
 
 #[cfg(feature = "std")]
 impl Error for NumberError {}
+
+impl ErrorCode for NumberError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseFloatError(_) => "number.invalid",
+            Self::TooLarge {
+                ..
+            } => "number.too_large",
+            Self::TooSmall {
+                ..
+            } => "number.too_small",
+            Self::Forbidden {
+                ..
+            } => "number.forbidden",
+            Self::NaNMust => "number.nan_must",
+            Self::NaNDisallow => "number.nan_disallow",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::TooLarge {
+                inclusive: false, ..
+            } => "number.too_large_exclusive",
+            _ => self.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::TooLarge {
+                max,
+                value,
+                ..
+            } => alloc::vec![("max", max.to_string().into()), ("value", value.to_string().into())],
+            Self::TooSmall {
+                min,
+                value,
+            } => alloc::vec![("min", min.to_string().into()), ("value", value.to_string().into())],
+            Self::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            } => alloc::vec![
+                (
+                    "range",
+                    DisplayRange {
+                        min: *min, max: *max, inclusive: *inclusive
+                    }
+                    .to_string()
+                    .into()
+                ),
+                ("value", value.to_string().into()),
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
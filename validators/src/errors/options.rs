@@ -0,0 +1,63 @@
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Error from building a runtime validator with options which the attribute of the same validator rejects at compile time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OptionsError {
+    /// `ip(Must)` and `at_least_two_labels(Disallow)` are used together.
+    IPMustAtLeastTwoLabelsDisallow,
+    /// An option which can only be `Allow` or `Disallow`, such as `comment` of the `email` validator, is `Must`.
+    MustUnsupported { option: &'static str },
+    /// `nan(Must)` and `range(Inside)` are used together.
+    NaNMustRangeInside,
+    /// The `min` of a range is greater than its `max`, or not less than it if the range is not inclusive.
+    RangeMinMax { inclusive: bool },
+    /// The `trimmed_min` of a length is greater than its `min`.
+    TrimmedMinGreaterThanMin { trimmed_min: usize, min: usize },
+    /// The `min` of a length is greater than its `max`.
+    MinGreaterThanMax { min: usize, max: usize },
+    /// The `trimmed_min` of a length is greater than its `max`.
+    TrimmedMinGreaterThanMax { trimmed_min: usize, max: usize },
+}
+
+impl Display for OptionsError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::IPMustAtLeastTwoLabelsDisallow => f.write_str(
+                "`ip(Must)` and `at_least_two_labels(Disallow)` cannot be used together",
+            ),
+            Self::MustUnsupported {
+                option,
+            } => f.write_fmt(format_args!("`{option}` can only be `Allow` or `Disallow`")),
+            Self::NaNMustRangeInside => {
+                f.write_str("`nan(Must)` and `range(Inside)` cannot be used together")
+            },
+            Self::RangeMinMax {
+                inclusive,
+            } => {
+                if *inclusive {
+                    f.write_str("min > max")
+                } else {
+                    f.write_str("min >= max")
+                }
+            },
+            Self::TrimmedMinGreaterThanMin {
+                trimmed_min,
+                min,
+            } => f.write_fmt(format_args!("{trimmed_min} > {min} (trimmed_min > min)")),
+            Self::MinGreaterThanMax {
+                min,
+                max,
+            } => f.write_fmt(format_args!("{min} > {max} (min > max)")),
+            Self::TrimmedMinGreaterThanMax {
+                trimmed_min,
+                max,
+            } => f.write_fmt(format_args!("{trimmed_min} > {max} (trimmed_min > max)")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for OptionsError {}
@@ -0,0 +1,89 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::traits::ErrorCode;
+
+/// Error from the `path` validator.
+#[derive(Debug, Clone)]
+pub enum PathError {
+    /// The path is empty.
+    Empty,
+    /// The path contains a NUL character.
+    ContainsNul,
+    /// May not be valid, but it is guaranteed that the path is relative.
+    AbsoluteMust,
+    /// May not be valid, but it is guaranteed that the path is absolute.
+    AbsoluteDisallow,
+    /// May not be valid, but it is guaranteed that the path has a root or a prefix, such as `/` or `C:`.
+    RootDisallow,
+    /// May not be valid, but it is guaranteed that the path has a `..` component.
+    ParentDisallow,
+    /// May not be valid, but it is guaranteed that the path has a component reserved by Windows, such as `CON` or `NUL.txt`.
+    WindowsReservedDisallow,
+    /// May not be valid, but it is guaranteed that the extension of the path is not one of the allowed extensions.
+    ExtensionNotAllowed,
+    /// May not be valid, but it is guaranteed that a component of the path is too long. The length is in bytes.
+    ComponentTooLong { max: usize, length: usize },
+}
+
+impl Display for PathError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Empty => f.write_str("path is empty"),
+            Self::ContainsNul => f.write_str("path must not contain NUL characters"),
+            Self::AbsoluteMust => f.write_str("path must be absolute"),
+            Self::AbsoluteDisallow => f.write_str("path must be relative"),
+            Self::RootDisallow => f.write_str("path must not have a root or a prefix"),
+            Self::ParentDisallow => f.write_str("path must not contain `..`"),
+            Self::WindowsReservedDisallow => {
+                f.write_str("path must not contain names reserved by Windows")
+            },
+            Self::ExtensionNotAllowed => f.write_str("extension not allowed"),
+            Self::ComponentTooLong {
+                max,
+                length,
+            } => f.write_fmt(format_args!(
+                "path component is too long, must be at most {max} bytes (got {length})"
+            )),
+        }
+    }
+}
+
+impl Error for PathError {}
+
+impl ErrorCode for PathError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Empty => "path.empty",
+            Self::ContainsNul => "path.contains_nul",
+            Self::AbsoluteMust => "path.absolute_must",
+            Self::AbsoluteDisallow => "path.absolute_disallow",
+            Self::RootDisallow => "path.root_disallow",
+            Self::ParentDisallow => "path.parent_disallow",
+            Self::WindowsReservedDisallow => "path.windows_reserved_disallow",
+            Self::ExtensionNotAllowed => "path.extension_not_allowed",
+            Self::ComponentTooLong {
+                ..
+            } => "path.component_too_long",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::ComponentTooLong {
+                max,
+                length,
+            } => {
+                alloc::vec![("max", max.to_string().into()), ("length", length.to_string().into())]
+            },
+            _ => Vec::new(),
+        }
+    }
+}
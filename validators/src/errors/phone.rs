@@ -4,6 +4,8 @@ use std::error::Error;
 
 use phonenumber::ParseError;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `phone` validator.
 #[derive(Debug)]
 pub enum PhoneError {
@@ -32,3 +34,12 @@ impl Display for PhoneError {
 
 #[cfg(feature = "std")]
 impl Error for PhoneError {}
+
+impl ErrorCode for PhoneError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Failure(_) | Self::Invalid => "phone.invalid",
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use core::fmt::{self, Display, Formatter};
+
+/// Display a range in the form of `min..max`, `min..=max`, `min..` or `..max`.
+pub(crate) struct DisplayRange<T> {
+    pub(crate) min:       Option<T>,
+    pub(crate) max:       Option<T>,
+    pub(crate) inclusive: bool,
+}
+
+impl<T: Display> Display for DisplayRange<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        if let Some(min) = &self.min {
+            Display::fmt(min, f)?;
+        }
+
+        match &self.max {
+            Some(max) => {
+                f.write_str(if self.inclusive { "..=" } else { ".." })?;
+
+                Display::fmt(max, f)
+            },
+            None => f.write_str(".."),
+        }
+    }
+}
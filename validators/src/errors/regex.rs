@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `regex` validator.
 #[derive(Debug, Clone)]
 pub struct RegexError;
@@ -15,3 +17,10 @@ impl Display for RegexError {
 
 #[cfg(feature = "std")]
 impl Error for RegexError {}
+
+impl ErrorCode for RegexError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "regex.invalid"
+    }
+}
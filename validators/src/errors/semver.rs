@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `semver` and `semver_req` validator.
 #[derive(Debug)]
 pub struct SemverError(pub semver::Error);
@@ -22,3 +24,10 @@ impl Display for SemverError {
 
 #[cfg(feature = "std")]
 impl Error for SemverError {}
+
+impl ErrorCode for SemverError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "semver.invalid"
+    }
+}
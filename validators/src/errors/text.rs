@@ -1,15 +1,62 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
 use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use super::LengthUnit;
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::{functions::LengthViolation, traits::ErrorCode};
+
 /// Error from the `text` validator.
 #[derive(Debug, Clone)]
 pub enum TextError {
     Invalid,
     /// May not be valid, but it is guaranteed that this text is too long.
-    TooLong,
-    /// May not be valid, but it is guaranteed that this text is too short.
-    TooShort,
+    TooLong {
+        max:    usize,
+        length: usize,
+        unit:   LengthUnit,
+    },
+    /// May not be valid, but it is guaranteed that this text is too short. `trimmed_length` is the length without leading and trailing whitespaces.
+    TooShort {
+        min:            Option<usize>,
+        trimmed_min:    Option<usize>,
+        length:         usize,
+        trimmed_length: usize,
+        unit:           LengthUnit,
+    },
+}
+
+impl From<LengthViolation> for TextError {
+    #[inline]
+    fn from(violation: LengthViolation) -> Self {
+        match violation {
+            LengthViolation::TooLong {
+                max,
+                length,
+                unit,
+            } => Self::TooLong {
+                max,
+                length,
+                unit,
+            },
+            LengthViolation::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            } => Self::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            },
+        }
+    }
 }
 
 impl Display for TextError {
@@ -17,11 +64,96 @@ impl Display for TextError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
             Self::Invalid => f.write_str("invalid text"),
-            Self::TooLong => f.write_str("text is too long"),
-            Self::TooShort => f.write_str("text is too short"),
+            Self::TooLong {
+                max,
+                length,
+                unit,
+            } => f.write_fmt(format_args!(
+                "text is too long, must be at most {max} {unit} (got {length})"
+            )),
+            Self::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            } => match min {
+                Some(min) if length < min => f.write_fmt(format_args!(
+                    "text is too short, must be at least {min} {unit} (got {length})"
+                )),
+                _ => match trimmed_min {
+                    Some(trimmed_min) => f.write_fmt(format_args!(
+                        "text is too short, must be at least {trimmed_min} {unit} after trimming \
+                         (got {trimmed_length})"
+                    )),
+                    None => f.write_str("text is too short"),
+                },
+            },
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for TextError {}
+
+impl ErrorCode for TextError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "text.invalid",
+            Self::TooLong {
+                ..
+            } => "text.too_long",
+            Self::TooShort {
+                ..
+            } => "text.too_short",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::TooShort {
+                min,
+                length,
+                ..
+            } if !matches!(min, Some(min) if length < min) => "text.too_short_trimmed",
+            _ => self.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::Invalid => Vec::new(),
+            Self::TooLong {
+                max,
+                length,
+                unit,
+            } => alloc::vec![
+                ("max", max.to_string().into()),
+                ("length", length.to_string().into()),
+                ("unit", MessageArg::Message(unit.message_key())),
+            ],
+            Self::TooShort {
+                min,
+                trimmed_min,
+                length,
+                trimmed_length,
+                unit,
+            } => {
+                let (min, length) = match min {
+                    Some(min) if length < min => (*min, *length),
+                    _ => (trimmed_min.unwrap_or_default(), *trimmed_length),
+                };
+
+                alloc::vec![
+                    ("min", min.to_string().into()),
+                    ("length", length.to_string().into()),
+                    ("unit", MessageArg::Message(unit.message_key())),
+                ]
+            },
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "i18n")]
+use alloc::{string::ToString, vec::Vec};
 use core::{
     fmt::{self, Display, Formatter},
     num::ParseIntError,
@@ -5,13 +7,33 @@ use core::{
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use super::range::DisplayRange;
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+use crate::{functions::RangeViolation, traits::ErrorCode};
+
 /// Error from the `unsigned_integer` validator.
 #[derive(Debug, Clone)]
 pub enum UnsignedIntegerError {
     ParseIntError(ParseIntError),
-    TooLarge,
-    TooSmall,
-    Forbidden,
+    /// The integer is larger than `max`, or equal to `max` if the range is not `inclusive`.
+    TooLarge {
+        max:       u128,
+        inclusive: bool,
+        value:     u128,
+    },
+    /// The integer is smaller than `min`.
+    TooSmall {
+        min:   u128,
+        value: u128,
+    },
+    /// The integer is in a forbidden range.
+    Forbidden {
+        min:       Option<u128>,
+        max:       Option<u128>,
+        inclusive: bool,
+        value:     u128,
+    },
 }
 
 impl From<ParseIntError> for UnsignedIntegerError {
@@ -21,17 +43,142 @@ impl From<ParseIntError> for UnsignedIntegerError {
     }
 }
 
+impl From<RangeViolation<u128>> for UnsignedIntegerError {
+    #[inline]
+    fn from(violation: RangeViolation<u128>) -> Self {
+        match violation {
+            RangeViolation::TooSmall {
+                min,
+                value,
+            } => Self::TooSmall {
+                min,
+                value,
+            },
+            RangeViolation::TooLarge {
+                max,
+                inclusive,
+                value,
+            } => Self::TooLarge {
+                max,
+                inclusive,
+                value,
+            },
+            RangeViolation::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            } => Self::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            },
+        }
+    }
+}
+
 impl Display for UnsignedIntegerError {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
             Self::ParseIntError(error) => Display::fmt(error, f),
-            Self::TooLarge => f.write_str("integer is too large"),
-            Self::TooSmall => f.write_str("integer is too small"),
-            Self::Forbidden => f.write_str("integer is forbidden"),
+            Self::TooLarge {
+                max,
+                inclusive,
+                value,
+            } => {
+                if *inclusive {
+                    f.write_fmt(format_args!(
+                        "integer is too large, must be at most {max} (got {value})"
+                    ))
+                } else {
+                    f.write_fmt(format_args!(
+                        "integer is too large, must be less than {max} (got {value})"
+                    ))
+                }
+            },
+            Self::TooSmall {
+                min,
+                value,
+            } => f.write_fmt(format_args!(
+                "integer is too small, must be at least {min} (got {value})"
+            )),
+            Self::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            } => f.write_fmt(format_args!(
+                "integer is forbidden, must not be in {range} (got {value})",
+                range = DisplayRange {
+                    min: *min, max: *max, inclusive: *inclusive
+                }
+            )),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for UnsignedIntegerError {}
+
+impl ErrorCode for UnsignedIntegerError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseIntError(_) => "unsigned_integer.invalid",
+            Self::TooLarge {
+                ..
+            } => "unsigned_integer.too_large",
+            Self::TooSmall {
+                ..
+            } => "unsigned_integer.too_small",
+            Self::Forbidden {
+                ..
+            } => "unsigned_integer.forbidden",
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::TooLarge {
+                inclusive: false, ..
+            } => "unsigned_integer.too_large_exclusive",
+            _ => self.code(),
+        }
+    }
+
+    #[cfg(feature = "i18n")]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        match self {
+            Self::TooLarge {
+                max,
+                value,
+                ..
+            } => alloc::vec![("max", max.to_string().into()), ("value", value.to_string().into())],
+            Self::TooSmall {
+                min,
+                value,
+            } => alloc::vec![("min", min.to_string().into()), ("value", value.to_string().into())],
+            Self::Forbidden {
+                min,
+                max,
+                inclusive,
+                value,
+            } => alloc::vec![
+                (
+                    "range",
+                    DisplayRange {
+                        min: *min, max: *max, inclusive: *inclusive
+                    }
+                    .to_string()
+                    .into()
+                ),
+                ("value", value.to_string().into()),
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
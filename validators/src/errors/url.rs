@@ -2,7 +2,7 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
-use crate::url;
+use crate::{traits::ErrorCode, url};
 
 /// Error from the `url` validator.
 #[derive(Debug, Clone)]
@@ -24,3 +24,10 @@ impl Display for UrlError {
 
 #[cfg(feature = "std")]
 impl Error for UrlError {}
+
+impl ErrorCode for UrlError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "url.invalid"
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+use crate::traits::ErrorCode;
+
 /// Error from the `uuid` validator.
 #[derive(Debug, Clone)]
 pub enum UuidError {
@@ -26,3 +28,14 @@ impl Display for UuidError {
 
 #[cfg(feature = "std")]
 impl Error for UuidError {}
+
+impl ErrorCode for UuidError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "uuid.invalid",
+            Self::SeparatorMust => "uuid.separator_must",
+            Self::SeparatorDisallow => "uuid.separator_disallow",
+        }
+    }
+}
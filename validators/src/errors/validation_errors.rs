@@ -0,0 +1,318 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    slice,
+};
+use std::error::Error;
+
+use crate::traits::ErrorCode;
+
+/// A segment of a `FieldPath`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum FieldPathSegment {
+    /// The name of a field, such as `email`.
+    Field(String),
+    /// The index of an element in a collection, such as `[3]`.
+    Index(usize),
+}
+
+/// The location of a field, such as `users[3].email`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct FieldPath {
+    segments: Vec<FieldPathSegment>,
+}
+
+impl FieldPath {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            segments: Vec::new()
+        }
+    }
+
+    #[inline]
+    pub fn segments(&self) -> &[FieldPathSegment] {
+        self.segments.as_slice()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    #[inline]
+    pub fn push_field<S: Into<String>>(&mut self, field: S) {
+        self.segments.push(FieldPathSegment::Field(field.into()));
+    }
+
+    #[inline]
+    pub fn push_index(&mut self, index: usize) {
+        self.segments.push(FieldPathSegment::Index(index));
+    }
+
+    /// Create a new path by appending `path` to this path.
+    #[inline]
+    pub fn join<P: Into<FieldPath>>(&self, path: P) -> FieldPath {
+        let mut segments = self.segments.clone();
+
+        segments.extend(path.into().segments);
+
+        FieldPath {
+            segments,
+        }
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                FieldPathSegment::Field(field) => {
+                    if i > 0 {
+                        f.write_char('.')?;
+                    }
+
+                    f.write_str(field)?;
+                },
+                FieldPathSegment::Index(index) => f.write_fmt(format_args!("[{index}]"))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for FieldPath {
+    #[inline]
+    fn from(field: &str) -> Self {
+        Self::from(String::from(field))
+    }
+}
+
+impl From<String> for FieldPath {
+    #[inline]
+    fn from(field: String) -> Self {
+        Self {
+            segments: alloc::vec![FieldPathSegment::Field(field)]
+        }
+    }
+}
+
+impl From<usize> for FieldPath {
+    #[inline]
+    fn from(index: usize) -> Self {
+        Self {
+            segments: alloc::vec![FieldPathSegment::Index(index)]
+        }
+    }
+}
+
+impl From<FieldPathSegment> for FieldPath {
+    #[inline]
+    fn from(segment: FieldPathSegment) -> Self {
+        Self {
+            segments: alloc::vec![segment]
+        }
+    }
+}
+
+impl From<Vec<FieldPathSegment>> for FieldPath {
+    #[inline]
+    fn from(segments: Vec<FieldPathSegment>) -> Self {
+        Self {
+            segments,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldPath {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Errors from validating multiple fields. Every entry is the path of a field and the error of that field.
+///
+/// With the `serde` feature, it is serialized as a sequence of `{ "path": ..., "code": ..., "message": ... }` objects. The `code` is `null` if the error was added by `push_boxed`.
+#[derive(Debug, Default)]
+pub struct ValidationErrors {
+    errors: Vec<(FieldPath, Box<dyn Error + Send + Sync>)>,
+    codes:  Vec<Option<&'static str>>,
+}
+
+impl ValidationErrors {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            errors: Vec::new(), codes: Vec::new()
+        }
+    }
+
+    /// Add the error of a field. Its code is kept for serialization.
+    #[inline]
+    pub fn push<P: Into<FieldPath>, E: Error + ErrorCode + Send + Sync + 'static>(
+        &mut self,
+        path: P,
+        error: E,
+    ) {
+        self.codes.push(Some(error.code()));
+        self.errors.push((path.into(), Box::new(error)));
+    }
+
+    /// Add the error of a field which has already been boxed. The error has no code.
+    #[inline]
+    pub fn push_boxed<P: Into<FieldPath>>(&mut self, path: P, error: Box<dyn Error + Send + Sync>) {
+        self.codes.push(None);
+        self.errors.push((path.into(), error));
+    }
+
+    /// Move all errors from `other` into this collection, nesting their paths under `prefix`.
+    ///
+    /// ```rust
+    /// use validators::errors::{MissingFieldError, ValidationErrors};
+    ///
+    /// let mut user_errors = ValidationErrors::new();
+    /// user_errors.push("email", MissingFieldError);
+    ///
+    /// let mut errors = ValidationErrors::new();
+    /// errors.merge(
+    ///     validators::errors::FieldPath::from("users").join(3usize),
+    ///     user_errors,
+    /// );
+    ///
+    /// assert_eq!("users[3].email: missing field", errors.to_string());
+    /// ```
+    pub fn merge<P: Into<FieldPath>>(&mut self, prefix: P, other: ValidationErrors) {
+        let prefix = prefix.into();
+
+        self.errors
+            .extend(other.errors.into_iter().map(|(path, error)| (prefix.join(path), error)));
+        self.codes.extend(other.codes);
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    #[inline]
+    pub fn errors(&self) -> &[(FieldPath, Box<dyn Error + Send + Sync>)] {
+        self.errors.as_slice()
+    }
+
+    /// Get the code of the error at `index`, which is `None` if the error was added by `push_boxed`.
+    #[inline]
+    pub fn code(&self, index: usize) -> Option<&'static str> {
+        self.codes.get(index).copied().flatten()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> ValidationErrorsIter<'_> {
+        ValidationErrorsIter {
+            inner: self.errors.iter()
+        }
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        for (i, (path, error)) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+
+            f.write_fmt(format_args!("{path}: {error}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ValidationErrors {}
+
+/// An iterator over the entries of `ValidationErrors`.
+#[derive(Debug, Clone)]
+pub struct ValidationErrorsIter<'a> {
+    inner: slice::Iter<'a, (FieldPath, Box<dyn Error + Send + Sync>)>,
+}
+
+impl<'a> Iterator for ValidationErrorsIter<'a> {
+    type Item = (&'a FieldPath, &'a (dyn Error + Send + Sync + 'static));
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(path, error)| (path, error.as_ref()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for ValidationErrorsIter<'a> {}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type IntoIter = ValidationErrorsIter<'a>;
+    type Item = (&'a FieldPath, &'a (dyn Error + Send + Sync + 'static));
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for ValidationErrors {
+    type IntoIter = alloc::vec::IntoIter<(FieldPath, Box<dyn Error + Send + Sync>)>;
+    type Item = (FieldPath, Box<dyn Error + Send + Sync>);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ValidationErrors {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{SerializeSeq, SerializeStruct};
+
+        struct Entry<'a> {
+            path:  &'a FieldPath,
+            code:  Option<&'static str>,
+            error: &'a (dyn Error + Send + Sync + 'static),
+        }
+
+        impl<'a> serde::Serialize for Entry<'a> {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut s = serializer.serialize_struct("ValidationError", 3)?;
+
+                s.serialize_field("path", self.path)?;
+                s.serialize_field("code", &self.code)?;
+                s.serialize_field("message", &alloc::string::ToString::to_string(self.error))?;
+
+                s.end()
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.errors.len()))?;
+
+        for ((path, error), code) in self.errors.iter().zip(self.codes.iter()) {
+            seq.serialize_element(&Entry {
+                path,
+                code: *code,
+                error: error.as_ref(),
+            })?;
+        }
+
+        seq.end()
+    }
+}
@@ -24,3 +24,19 @@ pub fn is_at_least_two_labels_domain<S: AsRef<str>>(s: S) -> bool {
         .take(s.len() - 1) // to avoid "."-ended domain
         .any(|e| e == b'.')
 }
+
+/// Convert a domain to ASCII in the same way as the `email` and `host` validators. `None` means the domain is invalid.
+#[cfg(any(feature = "email", feature = "host"))]
+#[allow(deprecated)] // the `domain` validator uses the same `Config`
+pub(crate) fn to_ascii_domain(s: &str) -> Option<alloc::string::String> {
+    match idna::Config::default()
+        .use_std3_ascii_rules(true)
+        .verify_dns_length(true)
+        .check_hyphens(true)
+        .to_ascii(s)
+    {
+        // IDNA may map the last label to nothing, e.g. a soft hyphen, or to a dot, e.g. an ideographic full stop
+        Ok(ascii_domain) if !ascii_domain.ends_with('.') => Some(ascii_domain),
+        _ => None,
+    }
+}
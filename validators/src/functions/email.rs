@@ -0,0 +1,529 @@
+use alloc::{format, string::String};
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use super::{
+    is_at_least_two_labels_domain, is_local_domain, is_local_ipv4, is_local_ipv6, to_ascii_domain,
+};
+use crate::{errors::EmailError, models::Host, options::TriAllow};
+
+/// The parts of an email address parsed by `parse_email`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailParts {
+    pub local_part:                 String,
+    pub need_quoted:                bool,
+    pub domain_part:                Host,
+    pub comment_before_local_part:  Option<String>,
+    pub comment_after_local_part:   Option<String>,
+    pub comment_before_domain_part: Option<String>,
+    pub comment_after_domain_part:  Option<String>,
+    pub is_local:                   bool,
+}
+
+/// Parse an email address in the same way as the `email` validator. `comment` and `non_ascii` treat `Must` as `Allow`.
+///
+/// The code generated for the `email` attribute and `EmailValidator` both call this function, so they report the same errors in the same order.
+pub fn parse_email(
+    s: &str,
+    comment: TriAllow,
+    ip: TriAllow,
+    local: TriAllow,
+    at_least_two_labels: TriAllow,
+    non_ascii: TriAllow,
+) -> Result<EmailParts, EmailError> {
+    let bytes = s.as_bytes();
+    let length = bytes.len();
+
+    if length == 0 || length > 320 {
+        return Err(EmailError::Invalid);
+    }
+
+    // comment 1
+    let (mut local_part_length, comment_before_local_part) = if bytes[0] == b'(' {
+        if comment.disallow() {
+            return Err(EmailError::CommentDisallow);
+        }
+
+        if length == 1 {
+            return Err(EmailError::Invalid);
+        }
+
+        // only take 63 - 1, 1 is reserved for the meaningful local part.
+        match bytes[1..].iter().take(62).copied().position(|e| e == b')') {
+            Some(mut index) => {
+                index += 1;
+
+                let local_part_length = index + 1;
+
+                if local_part_length == length {
+                    // end
+                    return Err(EmailError::Invalid);
+                }
+
+                (local_part_length, Some(&s[1..index]))
+            },
+            None => return Err(EmailError::Invalid),
+        }
+    } else {
+        (0, None)
+    };
+
+    let (local_part, need_quoted, quoted) = if bytes[local_part_length] == b'"' {
+        // quoted
+        let mut p = local_part_length + 1;
+
+        if p == 63 || p == length {
+            // too long
+            return Err(EmailError::Invalid);
+        }
+
+        let e = bytes[p];
+
+        let (mut escaping, mut need_quoted, mut last_dot) = match e {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'!'
+            | b'#'..=b'\''
+            | b'*'
+            | b'+'
+            | b'-'
+            | b'/'
+            | b'='
+            | b'?'
+            | b'^'..=b'`'
+            | b'{'..=b'~' => (false, false, false),
+            b'.' => (false, true, true),
+            b'\\' => (true, true, false),
+            b' ' | b'\t' => (false, true, false),
+            _ => {
+                if non_ascii.disallow() || e < 128 {
+                    return Err(EmailError::Invalid);
+                }
+
+                (false, false, false)
+            },
+        };
+
+        p += 1;
+
+        loop {
+            if p == length {
+                // not found '"', so returns Err.
+                return Err(EmailError::Invalid);
+            }
+
+            let e = bytes[p];
+
+            match e {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'!'
+                | b'#'..=b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'/'
+                | b'='
+                | b'?'
+                | b'^'..=b'`'
+                | b'{'..=b'~' => {
+                    if p == 63 {
+                        // too long
+                        return Err(EmailError::Invalid);
+                    }
+
+                    last_dot = false;
+                    escaping = false;
+                },
+                b'.' => {
+                    if p == 63 {
+                        // too long
+                        return Err(EmailError::Invalid);
+                    }
+
+                    if last_dot {
+                        need_quoted = true;
+                    }
+
+                    last_dot = true;
+                    escaping = false;
+                },
+                b'\\' => {
+                    if p == 63 {
+                        // too long
+                        return Err(EmailError::Invalid);
+                    }
+
+                    need_quoted = true;
+                    escaping = !escaping;
+                },
+                b' ' | b'\t' => {
+                    if p == 63 {
+                        // too long
+                        return Err(EmailError::Invalid);
+                    }
+
+                    need_quoted = true;
+                },
+                b'(' | b')' | b',' | b':'..=b'<' | b'>' | b'@' | b'[' | b']' => {
+                    if p == 63 || !escaping {
+                        // too long or not in escaping
+                        return Err(EmailError::Invalid);
+                    }
+
+                    escaping = false;
+                },
+                b'"' => {
+                    if escaping {
+                        if p == 63 {
+                            // too long
+                            return Err(EmailError::Invalid);
+                        }
+
+                        escaping = false;
+                    } else {
+                        let local_part = &s[(local_part_length + 1)..p];
+
+                        local_part_length = p + 1;
+
+                        if local_part_length == length {
+                            // end
+                            return Err(EmailError::Invalid);
+                        }
+
+                        break (local_part, need_quoted, true);
+                    }
+                },
+                _ => {
+                    // non-ASCII bytes also count toward the length
+                    if non_ascii.disallow() || e < 128 || p == 63 {
+                        return Err(EmailError::Invalid);
+                    }
+
+                    last_dot = false;
+                    escaping = false;
+                },
+            }
+
+            p += 1;
+        }
+    } else {
+        let e = bytes[local_part_length];
+
+        // unquoted
+        match e {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'!'
+            | b'#'..=b'\''
+            | b'*'
+            | b'+'
+            | b'-'
+            | b'/'
+            | b'='
+            | b'?'
+            | b'^'..=b'`'
+            | b'{'..=b'~' => {
+                // '.' is not allowed as the first character
+            },
+            _ => {
+                if non_ascii.disallow() || e < 128 {
+                    return Err(EmailError::Invalid);
+                }
+            },
+        }
+
+        let mut p = local_part_length + 1;
+        let mut last_dot = false;
+
+        loop {
+            if p == length {
+                // not found '@' or '(', so returns Err.
+                return Err(EmailError::Invalid);
+            }
+
+            let e = bytes[p];
+
+            match e {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'!'
+                | b'#'..=b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'/'
+                | b'='
+                | b'?'
+                | b'^'..=b'`'
+                | b'{'..=b'~' => {
+                    if p == 64 {
+                        // too long
+                        return Err(EmailError::Invalid);
+                    }
+
+                    last_dot = false;
+                },
+                b'.' => {
+                    if p == 64 || last_dot {
+                        // too long or '.' appears consecutively
+                        return Err(EmailError::Invalid);
+                    }
+
+                    last_dot = true;
+                },
+                b'@' | b'(' => {
+                    let local_part = &s[local_part_length..p];
+
+                    local_part_length = p;
+
+                    break (local_part, false, false);
+                },
+                _ => {
+                    // non-ASCII bytes also count toward the length
+                    if non_ascii.disallow() || e < 128 || p == 64 {
+                        return Err(EmailError::Invalid);
+                    }
+
+                    last_dot = false;
+                },
+            }
+
+            p += 1;
+        }
+    };
+
+    // comment 2
+    let comment_after_local_part = if bytes[local_part_length] == b'(' {
+        if comment.disallow() {
+            return Err(EmailError::CommentDisallow);
+        }
+
+        local_part_length += 1;
+
+        if length == local_part_length {
+            return Err(EmailError::Invalid);
+        }
+
+        match bytes[local_part_length..]
+            .iter()
+            .take(64usize.saturating_sub(local_part_length))
+            .copied()
+            .position(|e| e == b')')
+        {
+            Some(mut index) => {
+                index += local_part_length;
+
+                let c = &s[local_part_length..index];
+
+                local_part_length = index + 1;
+
+                if local_part_length == length {
+                    // end
+                    return Err(EmailError::Invalid);
+                }
+
+                Some(c)
+            },
+            None => return Err(EmailError::Invalid),
+        }
+    } else {
+        None
+    };
+
+    if bytes[local_part_length] != b'@' || local_part_length + 1 == length {
+        return Err(EmailError::Invalid);
+    }
+
+    let s = &s[(local_part_length + 1)..];
+    let bytes = s.as_bytes();
+    let length = bytes.len();
+
+    if length > 255 {
+        return Err(EmailError::Invalid);
+    }
+
+    // comment 3
+    let (mut domain_part_length, comment_before_domain_part) = if bytes[0] == b'(' {
+        if comment.disallow() {
+            return Err(EmailError::CommentDisallow);
+        }
+
+        if length == 1 {
+            return Err(EmailError::Invalid);
+        }
+
+        // only take 254 - 1, 1 is reserved for the meaningful domain part.
+        match bytes[1..].iter().take(253).copied().position(|e| e == b')') {
+            Some(mut index) => {
+                index += 1;
+
+                let domain_part_length = index + 1;
+
+                if domain_part_length == length {
+                    // end
+                    return Err(EmailError::Invalid);
+                }
+
+                (domain_part_length, Some(&s[1..index]))
+            },
+            None => return Err(EmailError::Invalid),
+        }
+    } else {
+        (0, None)
+    };
+
+    let mut domain_part_length_offset: isize = if quoted && need_quoted { -2 } else { 0 };
+
+    let (host, is_local) = match bytes[domain_part_length] {
+        b'(' => return Err(EmailError::Invalid),
+        b'[' => {
+            if ip.disallow() {
+                return Err(EmailError::IPDisallow);
+            }
+
+            if at_least_two_labels.disallow() {
+                return Err(EmailError::AtLeastTwoLabelsDisallow);
+            }
+
+            domain_part_length += 1;
+
+            if length == domain_part_length {
+                return Err(EmailError::Invalid);
+            }
+
+            let is_ipv6 = bytes[domain_part_length..].starts_with(b"IPv6:");
+
+            if is_ipv6 {
+                domain_part_length += 5;
+
+                if length == domain_part_length {
+                    return Err(EmailError::Invalid);
+                }
+            }
+
+            let closing_bracket_index =
+                match bytes[domain_part_length..].iter().copied().position(|e| e == b']') {
+                    Some(index) => index + domain_part_length,
+                    None => return Err(EmailError::Invalid),
+                };
+
+            let ip_str = &s[domain_part_length..closing_bracket_index];
+
+            domain_part_length = closing_bracket_index + 1;
+
+            if is_ipv6 {
+                let addr = Ipv6Addr::from_str(ip_str).map_err(|_| EmailError::Invalid)?;
+
+                // adjust length
+                domain_part_length_offset +=
+                    format!("{addr}").len() as isize - ip_str.len() as isize;
+
+                (Host::IPv6(addr), is_local_ipv6(addr))
+            } else {
+                let addr = Ipv4Addr::from_str(ip_str).map_err(|_| EmailError::Invalid)?;
+
+                (Host::IPv4(addr), is_local_ipv4(addr))
+            }
+        },
+        _ => {
+            if ip.must() {
+                return Err(EmailError::IPMust);
+            }
+
+            let domain_str =
+                match bytes[domain_part_length..].iter().copied().position(|e| e == b'(') {
+                    Some(index) => &s[domain_part_length..(domain_part_length + index)],
+                    None => &s[domain_part_length..],
+                };
+
+            if domain_str.ends_with('.') {
+                return Err(EmailError::Invalid);
+            }
+
+            let ascii_domain = to_ascii_domain(domain_str).ok_or(EmailError::Invalid)?;
+
+            domain_part_length += domain_str.len();
+
+            let is_local = is_local_domain(&ascii_domain);
+
+            if !is_local {
+                match at_least_two_labels {
+                    TriAllow::Allow => (),
+                    TriAllow::Must => {
+                        if !is_at_least_two_labels_domain(&ascii_domain) {
+                            return Err(EmailError::AtLeastTwoLabelsMust);
+                        }
+                    },
+                    TriAllow::Disallow => {
+                        if is_at_least_two_labels_domain(&ascii_domain) {
+                            return Err(EmailError::AtLeastTwoLabelsDisallow);
+                        }
+                    },
+                }
+            }
+
+            // adjust length
+            domain_part_length_offset += ascii_domain.len() as isize - domain_str.len() as isize;
+
+            (Host::Domain(ascii_domain), is_local)
+        },
+    };
+
+    match local {
+        TriAllow::Allow => (),
+        TriAllow::Must => {
+            if !is_local {
+                return Err(EmailError::LocalMust);
+            }
+        },
+        TriAllow::Disallow => {
+            if is_local {
+                return Err(EmailError::LocalDisallow);
+            }
+        },
+    }
+
+    if domain_part_length_offset > 0 && length + domain_part_length_offset as usize > 255 {
+        return Err(EmailError::Invalid);
+    }
+
+    // comment 4
+    let comment_after_domain_part = if length > domain_part_length {
+        if comment.disallow() {
+            return Err(EmailError::CommentDisallow);
+        }
+
+        if bytes[domain_part_length] != b'(' {
+            return Err(EmailError::Invalid);
+        }
+
+        domain_part_length += 1;
+
+        if length == domain_part_length {
+            return Err(EmailError::Invalid);
+        }
+
+        match bytes[domain_part_length..].iter().copied().position(|e| e == b')') {
+            Some(index) => Some(&s[domain_part_length..(domain_part_length + index)]),
+            None => return Err(EmailError::Invalid),
+        }
+    } else {
+        None
+    };
+
+    Ok(EmailParts {
+        local_part: String::from(local_part),
+        need_quoted,
+        domain_part: host,
+        comment_before_local_part: comment_before_local_part.map(String::from),
+        comment_after_local_part: comment_after_local_part.map(String::from),
+        comment_before_domain_part: comment_before_domain_part.map(String::from),
+        comment_after_domain_part: comment_after_domain_part.map(String::from),
+        is_local,
+    })
+}
@@ -0,0 +1,12 @@
+use crate::options::CaseOption;
+
+/// Decode a hexadecimal digit whose letters follow the `case` option.
+#[inline]
+pub(crate) const fn decode_hex_digit(e: u8, case: CaseOption) -> Option<u8> {
+    match e {
+        b'0'..=b'9' => Some(e - b'0'),
+        b'a'..=b'f' if case.lower() => Some(e - (b'a' - 10)),
+        b'A'..=b'F' if case.upper() => Some(e - (b'A' - 10)),
+        _ => None,
+    }
+}
@@ -0,0 +1,145 @@
+use core::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use super::{
+    is_at_least_two_labels_domain, is_local_domain, is_local_ipv4, is_local_ipv6, to_ascii_domain,
+};
+use crate::{errors::HostError, models::Host, options::TriAllow};
+
+/// Parse a host with an optional port in the same way as the `host` validator, returning the host, the port and whether the host is local.
+///
+/// The code generated for the `host` attribute and `HostValidator` both call this function, so they report the same errors in the same order.
+pub fn parse_host(
+    s: &str,
+    local: TriAllow,
+    port: TriAllow,
+    at_least_two_labels: TriAllow,
+) -> Result<(Host, Option<u16>, bool), HostError> {
+    let bytes = s.as_bytes();
+
+    if bytes.is_empty() {
+        return Err(HostError::Invalid);
+    }
+
+    let (host, port, is_local) = if bytes[0] == b'[' {
+        if at_least_two_labels.disallow() {
+            return Err(HostError::AtLeastTwoLabelsDisallow);
+        }
+
+        let last_index = bytes.len() - 1;
+
+        if bytes[last_index] == b']' {
+            if port.must() {
+                return Err(HostError::PortMust);
+            }
+
+            let ip = Ipv6Addr::from_str(&s[1..last_index]).map_err(|_| HostError::Invalid)?;
+
+            (Host::IPv6(ip), None, is_local_ipv6(ip))
+        } else {
+            if port.disallow() {
+                return Err(HostError::PortDisallow);
+            }
+
+            match bytes.iter().copied().rposition(|e| e == b':') {
+                Some(colon_index) if colon_index > 2 && bytes[colon_index - 1] == b']' => {
+                    let ip = Ipv6Addr::from_str(&s[1..(colon_index - 1)])
+                        .map_err(|_| HostError::Invalid)?;
+
+                    let port =
+                        s[(colon_index + 1)..].parse::<u16>().map_err(|_| HostError::Invalid)?;
+
+                    (Host::IPv6(ip), Some(port), is_local_ipv6(ip))
+                },
+                _ => return Err(HostError::Invalid),
+            }
+        }
+    } else if let Ok(ip) = Ipv6Addr::from_str(s) {
+        if at_least_two_labels.disallow() {
+            return Err(HostError::AtLeastTwoLabelsDisallow);
+        }
+
+        if port.must() {
+            return Err(HostError::PortMust);
+        }
+
+        (Host::IPv6(ip), None, is_local_ipv6(ip))
+    } else {
+        let (domain_str, port_str) = match bytes.iter().copied().rposition(|e| e == b':') {
+            Some(colon_index) => {
+                if port.disallow() {
+                    return Err(HostError::PortDisallow);
+                }
+
+                (&s[..colon_index], Some(&s[(colon_index + 1)..]))
+            },
+            None => {
+                if port.must() {
+                    return Err(HostError::PortMust);
+                }
+
+                (s, None)
+            },
+        };
+
+        if domain_str.ends_with('.') {
+            return Err(HostError::Invalid);
+        }
+
+        let parse_port = || match port_str {
+            Some(port_str) => port_str.parse::<u16>().map(Some).map_err(|_| HostError::Invalid),
+            None => Ok(None),
+        };
+
+        match Ipv4Addr::from_str(domain_str) {
+            Ok(ip) => {
+                if at_least_two_labels.disallow() {
+                    return Err(HostError::AtLeastTwoLabelsDisallow);
+                }
+
+                (Host::IPv4(ip), parse_port()?, is_local_ipv4(ip))
+            },
+            Err(_) => {
+                let ascii_domain = to_ascii_domain(domain_str).ok_or(HostError::Invalid)?;
+
+                let port = parse_port()?;
+
+                let is_local = is_local_domain(&ascii_domain);
+
+                if !is_local {
+                    match at_least_two_labels {
+                        TriAllow::Allow => (),
+                        TriAllow::Must => {
+                            if !is_at_least_two_labels_domain(&ascii_domain) {
+                                return Err(HostError::AtLeastTwoLabelsMust);
+                            }
+                        },
+                        TriAllow::Disallow => {
+                            if is_at_least_two_labels_domain(&ascii_domain) {
+                                return Err(HostError::AtLeastTwoLabelsDisallow);
+                            }
+                        },
+                    }
+                }
+
+                (Host::Domain(ascii_domain), port, is_local)
+            },
+        }
+    };
+
+    match local {
+        TriAllow::Allow => (),
+        TriAllow::Must => {
+            if !is_local {
+                return Err(HostError::LocalMust);
+            }
+        },
+        TriAllow::Disallow => {
+            if is_local {
+                return Err(HostError::LocalDisallow);
+            }
+        },
+    }
+
+    Ok((host, port, is_local))
+}
@@ -0,0 +1,64 @@
+use crate::errors::LengthUnit;
+
+/// The bound of the length of a string which it violates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthViolation {
+    /// The string is longer than `max`.
+    TooLong { max: usize, length: usize, unit: LengthUnit },
+    /// The string is shorter than `min`, or shorter than `trimmed_min` after trimming whitespaces.
+    TooShort {
+        min:            Option<usize>,
+        trimmed_min:    Option<usize>,
+        length:         usize,
+        trimmed_length: usize,
+        unit:           LengthUnit,
+    },
+}
+
+/// Check the length of a string in the same way as the `char_length` and `byte_length` parameters of the `line` and `text` validators. `TooLong` is reported before `TooShort`.
+///
+/// The code generated for these parameters and the runtime validators both call this function, so they report the same violations.
+pub fn check_length(
+    s: &str,
+    unit: LengthUnit,
+    min: Option<usize>,
+    trimmed_min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), LengthViolation> {
+    if min.is_none() && trimmed_min.is_none() && max.is_none() {
+        return Ok(());
+    }
+
+    let measure = |s: &str| match unit {
+        LengthUnit::Byte => s.len(),
+        LengthUnit::Char => s.chars().count(),
+    };
+
+    let length = measure(s);
+
+    if let Some(max) = max {
+        if length > max {
+            return Err(LengthViolation::TooLong {
+                max,
+                length,
+                unit,
+            });
+        }
+    }
+
+    let trimmed_length = measure(s.trim());
+
+    if min.map_or(false, |min| length < min)
+        || trimmed_min.map_or(false, |trimmed_min| trimmed_length < trimmed_min)
+    {
+        return Err(LengthViolation::TooShort {
+            min,
+            trimmed_min,
+            length,
+            trimmed_length,
+            unit,
+        });
+    }
+
+    Ok(())
+}
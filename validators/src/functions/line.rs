@@ -0,0 +1,21 @@
+use super::check_length;
+use crate::errors::{LengthUnit, LineError};
+
+/// Validate a line in the same way as the `line` validator. A line must not contain control characters other than tabs, and its length is checked by `check_length`.
+///
+/// The code generated for the `line` attribute and `LineValidator` both call this function.
+pub fn validate_line(
+    s: &str,
+    unit: LengthUnit,
+    min: Option<usize>,
+    trimmed_min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), LineError> {
+    if s.bytes().any(|e| matches!(e, b'\x00'..=b'\x08' | b'\x0A'..=b'\x1F' | b'\x7F')) {
+        return Err(LineError::Invalid);
+    }
+
+    check_length(s, unit, min, trimmed_min, max)?;
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use super::decode_hex_digit;
+use crate::{
+    errors::MacAddressError,
+    options::{CaseOption, SeparatorOption},
+};
+
+/// Parse a MAC address string in the same way as the `mac_address` validator and return its value.
+///
+/// The code generated for the `mac_address` attribute and `MacAddressValidator` both call this function.
+pub fn parse_mac_address(
+    s: &str,
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> Result<u64, MacAddressError> {
+    let bytes = s.as_bytes();
+    let length = bytes.len();
+
+    let groups: [&[u8]; 6] = match separator {
+        SeparatorOption::Allow(separator) => {
+            if !(12..=17).contains(&length) {
+                return Err(MacAddressError::Invalid);
+            }
+
+            let first = &bytes[0..2];
+
+            let mut no_colon_counter = if bytes[2] != separator { 1 } else { 0 };
+
+            let second = &bytes[(3 - no_colon_counter)..(5 - no_colon_counter)];
+
+            if bytes[5 - no_colon_counter] != separator {
+                no_colon_counter += 1;
+            }
+
+            let third = &bytes[(6 - no_colon_counter)..(8 - no_colon_counter)];
+
+            if bytes[8 - no_colon_counter] != separator {
+                no_colon_counter += 1;
+            }
+
+            let forth = &bytes[(9 - no_colon_counter)..(11 - no_colon_counter)];
+
+            if bytes[11 - no_colon_counter] != separator {
+                no_colon_counter += 1;
+            }
+
+            if length < 16 - no_colon_counter {
+                return Err(MacAddressError::Invalid);
+            }
+
+            let fifth = &bytes[(12 - no_colon_counter)..(14 - no_colon_counter)];
+
+            if bytes[14 - no_colon_counter] != separator {
+                no_colon_counter += 1;
+            }
+
+            let sixth = &bytes[(15 - no_colon_counter)..];
+
+            if sixth.len() != 2 {
+                return Err(MacAddressError::Invalid);
+            }
+
+            [first, second, third, forth, fifth, sixth]
+        },
+        SeparatorOption::Must(separator) => {
+            if length != 17 {
+                return Err(MacAddressError::SeparatorMust);
+            }
+
+            if bytes[2] != separator
+                || bytes[5] != separator
+                || bytes[8] != separator
+                || bytes[11] != separator
+                || bytes[14] != separator
+            {
+                return Err(MacAddressError::Invalid);
+            }
+
+            [&bytes[0..2], &bytes[3..5], &bytes[6..8], &bytes[9..11], &bytes[12..14], &bytes[15..]]
+        },
+        SeparatorOption::Disallow => {
+            if length != 12 {
+                return Err(MacAddressError::SeparatorDisallow);
+            }
+
+            [bytes, &[], &[], &[], &[], &[]]
+        },
+    };
+
+    let mut mac_address_decoded = 0u64;
+
+    for e in groups.iter().flat_map(|group| group.iter().copied()) {
+        let digit = decode_hex_digit(e, case).ok_or(MacAddressError::Invalid)?;
+
+        mac_address_decoded = (mac_address_decoded << 4) | u64::from(digit);
+    }
+
+    Ok(mac_address_decoded)
+}
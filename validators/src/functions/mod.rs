@@ -61,3 +61,58 @@ mod domain;
     feature = "http_ftp_url",
 ))]
 pub use domain::*;
+
+#[cfg(feature = "email")]
+mod email;
+#[cfg(feature = "email")]
+pub use email::*;
+
+#[cfg(feature = "host")]
+mod host;
+#[cfg(feature = "host")]
+pub use host::*;
+
+#[cfg(feature = "normalize")]
+mod normalize;
+#[cfg(feature = "normalize")]
+pub use normalize::*;
+
+#[cfg(feature = "path")]
+mod path;
+#[cfg(feature = "path")]
+pub use path::*;
+
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+mod range;
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+pub use range::*;
+
+#[cfg(any(feature = "line", feature = "text"))]
+mod length;
+#[cfg(any(feature = "line", feature = "text"))]
+pub use length::*;
+
+#[cfg(feature = "line")]
+mod line;
+#[cfg(feature = "line")]
+pub use line::*;
+
+#[cfg(feature = "text")]
+mod text;
+#[cfg(feature = "text")]
+pub use text::*;
+
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+mod hex;
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+use hex::*;
+
+#[cfg(feature = "mac_address")]
+mod mac_address;
+#[cfg(feature = "mac_address")]
+pub use self::mac_address::*;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "uuid")]
+pub use self::uuid::*;
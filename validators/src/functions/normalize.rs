@@ -0,0 +1,101 @@
+use alloc::{borrow::Cow, string::String};
+
+use unicode_normalization::{is_nfc, is_nfkc, UnicodeNormalization};
+
+use crate::options::{NormalizationForm, NormalizeOption};
+
+/// Determine whether the input character is a zero-width character, such as a zero-width space or a byte order mark.
+#[inline]
+pub const fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// Normalize a string by a `NormalizeOption`. The string is only reallocated when it is changed.
+pub fn normalize_str(s: Cow<str>, option: NormalizeOption) -> Cow<str> {
+    let mut s = s;
+
+    if option.strip_zero_width && s.chars().any(is_zero_width) {
+        s = Cow::Owned(s.chars().filter(|&c| !is_zero_width(c)).collect());
+    }
+
+    match option.form {
+        Some(NormalizationForm::NFC) if !is_nfc(&s) => {
+            s = Cow::Owned(s.nfc().collect());
+        },
+        Some(NormalizationForm::NFKC) if !is_nfkc(&s) => {
+            s = Cow::Owned(s.nfkc().collect());
+        },
+        _ => (),
+    }
+
+    if option.trim {
+        s = match s {
+            Cow::Borrowed(s) => Cow::Borrowed(s.trim()),
+            Cow::Owned(s) => {
+                let trimmed = s.trim();
+
+                if trimmed.len() == s.len() {
+                    Cow::Owned(s)
+                } else {
+                    Cow::Owned(String::from(trimmed))
+                }
+            },
+        };
+    }
+
+    if option.collapse_whitespace {
+        let mut last_is_whitespace = false;
+
+        let need_collapse = s.chars().any(|c| {
+            let is_whitespace = c.is_whitespace();
+            let need_collapse = is_whitespace && (c != ' ' || last_is_whitespace);
+
+            last_is_whitespace = is_whitespace;
+
+            need_collapse
+        });
+
+        if need_collapse {
+            let mut collapsed = String::with_capacity(s.len());
+
+            last_is_whitespace = false;
+
+            for c in s.chars() {
+                if c.is_whitespace() {
+                    if !last_is_whitespace {
+                        collapsed.push(' ');
+                    }
+
+                    last_is_whitespace = true;
+                } else {
+                    collapsed.push(c);
+
+                    last_is_whitespace = false;
+                }
+            }
+
+            s = Cow::Owned(collapsed);
+        }
+    }
+
+    if option.lowercase && s.chars().any(char::is_uppercase) {
+        s = Cow::Owned(s.to_lowercase());
+    }
+
+    s
+}
+
+/// Normalize the input of `ValidateString::parse_string`. The result can be used in the place of the input, so the code generated for the `normalize` parameter only has to shadow the input.
+#[inline]
+pub fn normalize_into_string<S: Into<String>>(s: S, option: NormalizeOption) -> impl Into<String> {
+    normalize_str(Cow::Owned(s.into()), option).into_owned()
+}
+
+/// Normalize the input of `ValidateString::parse_str` and `ValidateString::validate_str`, like `normalize_into_string`.
+#[inline]
+pub fn normalize_as_ref_str<S: AsRef<str> + ?Sized>(
+    s: &S,
+    option: NormalizeOption,
+) -> impl AsRef<str> + '_ {
+    normalize_str(Cow::Borrowed(s.as_ref()), option)
+}
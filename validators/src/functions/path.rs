@@ -0,0 +1,56 @@
+use std::ffi::OsStr;
+
+const WINDOWS_RESERVED_NAMES: [&str; 4] = ["CON", "PRN", "AUX", "NUL"];
+
+const WINDOWS_RESERVED_NUMBERED_NAMES: [&str; 2] = ["COM", "LPT"];
+
+/// Determine whether the input contains a NUL character. The encoded units are checked directly, so no string is allocated for input which is not valid UTF-8.
+#[inline]
+pub fn contains_nul<S: AsRef<OsStr>>(s: S) -> bool {
+    let s = s.as_ref();
+
+    #[cfg(unix)]
+    {
+        std::os::unix::ffi::OsStrExt::as_bytes(s).contains(&0)
+    }
+
+    #[cfg(windows)]
+    {
+        std::os::windows::ffi::OsStrExt::encode_wide(s).any(|c| c == 0)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        match s.to_str() {
+            Some(s) => s.contains('\0'),
+            None => s.to_string_lossy().contains('\0'),
+        }
+    }
+}
+
+/// Determine whether the input file name is reserved by Windows, such as `CON`, `nul.txt` or `COM1`. Names are compared case-insensitively, ignoring the extensions and trailing spaces.
+#[inline]
+pub fn is_windows_reserved_name<S: AsRef<OsStr>>(s: S) -> bool {
+    let s = s.as_ref().to_string_lossy();
+
+    let stem = match s.find('.') {
+        Some(index) => &s[..index],
+        None => &s,
+    };
+
+    let stem = stem.trim_end_matches(' ');
+
+    if WINDOWS_RESERVED_NAMES.iter().any(|name| stem.eq_ignore_ascii_case(name)) {
+        return true;
+    }
+
+    WINDOWS_RESERVED_NUMBERED_NAMES.iter().any(|name| match stem.get(..name.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(name) => {
+            matches!(
+                &stem[name.len()..],
+                "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" | "¹" | "²" | "³"
+            )
+        },
+        _ => false,
+    })
+}
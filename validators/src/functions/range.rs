@@ -0,0 +1,79 @@
+use crate::options::RangeOption;
+
+/// The bound of a `RangeOption` which a value violates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RangeViolation<T> {
+    /// The value is smaller than `min`.
+    TooSmall { min: T, value: T },
+    /// The value is larger than `max`, or equal to `max` if the range is not `inclusive`.
+    TooLarge { max: T, inclusive: bool, value: T },
+    /// The value is in a forbidden range.
+    Forbidden { min: Option<T>, max: Option<T>, inclusive: bool, value: T },
+}
+
+/// Check a value in the same way as the `range` parameter of the `number`, `signed_integer` and `unsigned_integer` validators.
+///
+/// The code generated for the `range` parameter and the runtime validators both call this function, so they report the same violations.
+pub fn check_range<T: PartialOrd + Copy>(
+    range: &RangeOption<T>,
+    value: T,
+) -> Result<(), RangeViolation<T>> {
+    match *range {
+        RangeOption::Inside {
+            min,
+            max,
+            inclusive,
+        } => {
+            if let Some(min) = min {
+                if value < min {
+                    return Err(RangeViolation::TooSmall {
+                        min,
+                        value,
+                    });
+                }
+            }
+
+            if let Some(max) = max {
+                if if inclusive { value > max } else { value >= max } {
+                    return Err(RangeViolation::TooLarge {
+                        max,
+                        inclusive,
+                        value,
+                    });
+                }
+            }
+        },
+        RangeOption::Outside {
+            min,
+            max,
+            inclusive,
+        } => {
+            let forbidden = match (min, max) {
+                (Some(min), Some(max)) => {
+                    value >= min && if inclusive { value <= max } else { value < max }
+                },
+                (Some(min), None) => value >= min,
+                (None, Some(max)) => {
+                    if inclusive {
+                        value <= max
+                    } else {
+                        value < max
+                    }
+                },
+                (None, None) => false,
+            };
+
+            if forbidden {
+                return Err(RangeViolation::Forbidden {
+                    min,
+                    max,
+                    inclusive,
+                    value,
+                });
+            }
+        },
+        RangeOption::Unlimited => (),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use super::check_length;
+use crate::errors::{LengthUnit, TextError};
+
+/// Validate a text in the same way as the `text` validator. A text must not contain control characters other than tabs, line feeds, vertical tabs and carriage returns, and its length is checked by `check_length`.
+///
+/// The code generated for the `text` attribute and `TextValidator` both call this function.
+pub fn validate_text(
+    s: &str,
+    unit: LengthUnit,
+    min: Option<usize>,
+    trimmed_min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), TextError> {
+    if s.bytes().any(|e| matches!(e, b'\x00'..=b'\x08' | b'\x0C' | b'\x0E'..=b'\x1F' | b'\x7F')) {
+        return Err(TextError::Invalid);
+    }
+
+    check_length(s, unit, min, trimmed_min, max)?;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use super::decode_hex_digit;
+use crate::{
+    errors::UuidError,
+    options::{CaseOption, SeparatorOption},
+};
+
+/// Parse a UUID string in the same way as the `uuid` validator and return its value.
+///
+/// The code generated for the `uuid` attribute and `UuidValidator` both call this function.
+pub fn parse_uuid(
+    s: &str,
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> Result<u128, UuidError> {
+    let bytes = s.as_bytes();
+    let length = bytes.len();
+
+    let groups: [&[u8]; 5] = match separator {
+        SeparatorOption::Allow(separator) => {
+            if !(32..=36).contains(&length) {
+                return Err(UuidError::Invalid);
+            }
+
+            let time_low = &bytes[0..8];
+
+            let mut no_hyphen_counter = if bytes[8] != separator { 1 } else { 0 };
+
+            let time_mid = &bytes[(9 - no_hyphen_counter)..(13 - no_hyphen_counter)];
+
+            if bytes[13 - no_hyphen_counter] != separator {
+                no_hyphen_counter += 1;
+            }
+
+            let time_high_and_version = &bytes[(14 - no_hyphen_counter)..(18 - no_hyphen_counter)];
+
+            if bytes[18 - no_hyphen_counter] != separator {
+                no_hyphen_counter += 1;
+            }
+
+            let clock_seq = &bytes[(19 - no_hyphen_counter)..(23 - no_hyphen_counter)];
+
+            if bytes[23 - no_hyphen_counter] != separator {
+                no_hyphen_counter += 1;
+            }
+
+            let node = &bytes[(24 - no_hyphen_counter)..];
+
+            if node.len() != 12 {
+                return Err(UuidError::Invalid);
+            }
+
+            [time_low, time_mid, time_high_and_version, clock_seq, node]
+        },
+        SeparatorOption::Must(separator) => {
+            if length != 36 {
+                return Err(UuidError::SeparatorMust);
+            }
+
+            if bytes[8] != separator
+                || bytes[13] != separator
+                || bytes[18] != separator
+                || bytes[23] != separator
+            {
+                return Err(UuidError::Invalid);
+            }
+
+            [&bytes[0..8], &bytes[9..13], &bytes[14..18], &bytes[19..23], &bytes[24..]]
+        },
+        SeparatorOption::Disallow => {
+            if length != 32 {
+                return Err(UuidError::SeparatorDisallow);
+            }
+
+            [bytes, &[], &[], &[], &[]]
+        },
+    };
+
+    let mut uuid_decoded = 0u128;
+
+    for e in groups.iter().flat_map(|group| group.iter().copied()) {
+        let digit = decode_hex_digit(e, case).ok_or(UuidError::Invalid)?;
+
+        uuid_decoded = (uuid_decoded << 4) | u128::from(digit);
+    }
+
+    Ok(uuid_decoded)
+}
@@ -0,0 +1,259 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::sync::{Once, RwLock};
+
+use crate::errors::CatalogError;
+
+/// Messages of a locale, keyed by error codes such as `email.comment_disallow`.
+///
+/// A message can contain placeholders such as `{max}`, which are replaced by the arguments provided by the error. Placeholders without arguments are kept as they are.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: BTreeMap<String, String>,
+}
+
+impl Catalog {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            messages: BTreeMap::new()
+        }
+    }
+
+    /// Parse a catalog from a TOML document. Tables are flattened into dotted keys, so the following document has the key `email.comment_disallow`.
+    ///
+    /// ```toml
+    /// [email]
+    /// comment_disallow = "must not contain comments"
+    /// ```
+    pub fn from_toml(toml: &str) -> Result<Self, CatalogError> {
+        fn flatten(
+            messages: &mut BTreeMap<String, String>,
+            prefix: &str,
+            table: toml::Table,
+        ) -> Result<(), CatalogError> {
+            for (key, value) in table {
+                let key = if prefix.is_empty() { key } else { alloc::format!("{prefix}.{key}") };
+
+                match value {
+                    toml::Value::String(message) => {
+                        messages.insert(key, message);
+                    },
+                    toml::Value::Table(table) => flatten(messages, &key, table)?,
+                    _ => return Err(CatalogError::NotString(key)),
+                }
+            }
+
+            Ok(())
+        }
+
+        let table = toml.parse::<toml::Table>()?;
+
+        let mut catalog = Self::new();
+
+        flatten(&mut catalog.messages, "", table)?;
+
+        Ok(catalog)
+    }
+
+    /// Add or replace a message. Return the replaced message.
+    #[inline]
+    pub fn insert<K: Into<String>, M: Into<String>>(
+        &mut self,
+        key: K,
+        message: M,
+    ) -> Option<String> {
+        self.messages.insert(key.into(), message.into())
+    }
+
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(|message| message.as_str())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Add all messages of `other` into this catalog, replacing the messages with the same keys.
+    #[inline]
+    pub fn merge(&mut self, other: Catalog) {
+        self.messages.extend(other.messages);
+    }
+}
+
+/// An argument of a message.
+#[derive(Debug, Clone)]
+pub enum MessageArg {
+    /// A value which is inserted as it is, such as a number.
+    Value(String),
+    /// The key of another message which is localized in the same locale, such as `length_unit.char`.
+    Message(&'static str),
+}
+
+impl From<String> for MessageArg {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::Value(value)
+    }
+}
+
+const DEFAULT_LOCALE: &str = "en";
+
+static BUNDLED: Once = Once::new();
+static CATALOGS: RwLock<BTreeMap<String, Catalog>> = RwLock::new(BTreeMap::new());
+
+#[inline]
+fn normalize_locale(locale: &str) -> String {
+    locale.replace('_', "-").to_ascii_lowercase()
+}
+
+fn catalogs() -> &'static RwLock<BTreeMap<String, Catalog>> {
+    BUNDLED.call_once(|| {
+        let mut catalogs = CATALOGS.write().unwrap_or_else(|error| error.into_inner());
+
+        for (locale, toml) in [
+            ("en", include_str!("locales/en.toml")),
+            ("ja", include_str!("locales/ja.toml")),
+            ("zh-TW", include_str!("locales/zh-TW.toml")),
+        ] {
+            let catalog = Catalog::from_toml(toml).expect("bundled catalogs must be valid");
+
+            catalogs.entry(normalize_locale(locale)).or_default().merge(catalog);
+        }
+    });
+
+    &CATALOGS
+}
+
+/// Register the messages of a locale, such as `ko` or `zh-TW`. If the locale already has a catalog (including the bundled `en`, `ja` and `zh-TW` catalogs), the messages are merged into it and replace the existing ones with the same keys.
+pub fn register_catalog<L: AsRef<str>>(locale: L, catalog: Catalog) {
+    let mut catalogs = catalogs().write().unwrap_or_else(|error| error.into_inner());
+
+    catalogs.entry(normalize_locale(locale.as_ref())).or_default().merge(catalog);
+}
+
+/// The locales which languages with script subtags fall back to, such as Traditional Chinese to the bundled `zh-TW` catalog.
+const SCRIPT_LOCALES: &[(&str, &str)] = &[("zh-hant", "zh-tw")];
+
+/// The locales to look up in order. For example, `zh-Hant-TW` is looked up as `zh-hant-tw`, `zh-hant`, `zh-tw`, `zh` and then `en`, so a script subtag does not hide the catalog of the region.
+fn fallback_locales(locale: &str) -> Vec<String> {
+    let locale = normalize_locale(locale);
+    let subtags = locale.split('-').filter(|subtag| !subtag.is_empty()).collect::<Vec<_>>();
+
+    let mut locales = Vec::new();
+
+    for n in (2..=subtags.len()).rev() {
+        locales.push(subtags[..n].join("-"));
+    }
+
+    if let Some(&language) = subtags.first() {
+        let region = subtags[1..].iter().find(|subtag| {
+            (subtag.len() == 2 && subtag.bytes().all(|e| e.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.bytes().all(|e| e.is_ascii_digit()))
+        });
+
+        if let Some(&region) = region {
+            locales.push([language, region].join("-"));
+        }
+
+        if let Some(&script) = subtags.get(1) {
+            let language_script = [language, script].join("-");
+
+            if let Some((_, script_locale)) =
+                SCRIPT_LOCALES.iter().find(|(locale, _)| *locale == language_script)
+            {
+                locales.push((*script_locale).to_owned());
+            }
+        }
+
+        locales.push(language.to_owned());
+    }
+
+    if !locales.iter().any(|locale| locale == DEFAULT_LOCALE) {
+        locales.push(DEFAULT_LOCALE.to_owned());
+    }
+
+    locales.dedup();
+
+    locales
+}
+
+/// Get the message of `key` in `locale` and fill in its placeholders with `args`. If the locale does not have the message, it is looked up in the more general locales and then English. Return `None` if no catalog has the message.
+///
+/// ```rust
+/// use validators::i18n::{self, MessageArg};
+///
+/// assert_eq!(
+///     Some("文字過長，最多 3 個字元（實際為 5）"),
+///     i18n::message("zh-TW", "text.too_long", &[
+///         ("max", MessageArg::Value("3".into())),
+///         ("length", MessageArg::Value("5".into())),
+///         ("unit", MessageArg::Message("length_unit.char")),
+///     ])
+///     .as_deref()
+/// );
+/// ```
+pub fn message(locale: &str, key: &str, args: &[(&str, MessageArg)]) -> Option<String> {
+    let catalogs = catalogs().read().unwrap_or_else(|error| error.into_inner());
+    let locales = fallback_locales(locale);
+
+    let lookup = |key: &str| {
+        locales.iter().find_map(|locale| catalogs.get(locale).and_then(|catalog| catalog.get(key)))
+    };
+
+    let template = lookup(key)?;
+
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        message.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let arg = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+
+            args.iter().find(|(n, _)| *n == name).map(|(_, arg)| (end, arg))
+        });
+
+        match arg {
+            Some((end, arg)) => {
+                match arg {
+                    MessageArg::Value(value) => message.push_str(value),
+                    MessageArg::Message(key) => match lookup(key) {
+                        Some(value) => message.push_str(value),
+                        None => message.push_str(key),
+                    },
+                }
+
+                rest = &rest[end + 1..];
+            },
+            None => {
+                message.push('{');
+                rest = &rest[1..];
+            },
+        }
+    }
+
+    message.push_str(rest);
+
+    Some(message)
+}
+
+/// Get the locales which have catalogs, in lowercase, such as `["en", "ja", "zh-tw"]`.
+pub fn locales() -> Vec<String> {
+    let catalogs = catalogs().read().unwrap_or_else(|error| error.into_inner());
+
+    catalogs.keys().map(|locale| locale.to_string()).collect()
+}
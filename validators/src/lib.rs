@@ -18,7 +18,7 @@ default-features = false
 
 Certain validators do not require the use of the `std` library. However, if needed, you can explicitly enable the `std` feature.
 
-This library can support the Serde framework and the Rocket framework by enabling the `serde` and `rocket` features, respectively.
+This library can support the Serde framework, the Rocket framework, the Axum framework and the Actix Web framework by enabling the `serde`, `rocket`, `axum` and `actix` features, respectively. Command-line arguments can be validated by `clap` by enabling the `clap` feature. Validated values can be stored in databases by enabling the `sqlx` or `diesel` feature. JSON schemas and OpenAPI schemas can be generated by enabling the `schemars` and `utoipa` features. Property-based testing and fuzzing are supported by enabling the `proptest` and `arbitrary` features.
 
 ## Validators
 
@@ -38,6 +38,64 @@ The struct used as a validator should possess specific components corresponding
 
 The `#[validator(validator_name)]` attribute cannot be applied to fields within any structs or enums. The decision to use a procedural macro for defining a validator (i.e., a struct) instead of offering built-in structs for each configuration is motivated by the aim to eliminate runtime overhead for configurable validations and to enhance compilation speed.
 
+#### all
+
+```rust
+# #[cfg(all(feature = "derive", feature = "all", feature = "domain", feature = "regex"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Allow), at_least_two_labels(Allow), port(Disallow)))]
+pub struct Domain(pub String);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[a-z.]+$"))]
+pub struct Lowercase(pub String);
+
+#[derive(Validator)]
+#[validator(all(Domain, Lowercase))]
+pub struct LowercaseDomain(pub Domain);
+
+assert!(LowercaseDomain::parse_string("magiclen.org").is_ok());
+assert!(LowercaseDomain::parse_string("MagicLen.org").is_err());
+assert_eq!(1, LowercaseDomain::parse_string("MagicLen.org").err().unwrap().index);
+# }
+```
+
+* Traits: `ValidateString`
+* Each parameter is the path of a validator which implements `ValidateString`. The field holds the parsed value of the first one and the others are only used for validation.
+
+#### any
+
+```rust
+# #[cfg(all(feature = "derive", feature = "any", feature = "ipv4", feature = "ipv6"))]
+# {
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(pub Ipv4Addr);
+
+#[derive(Validator)]
+#[validator(ipv6(local(Allow), port(Disallow)))]
+pub struct Ipv6(pub Ipv6Addr);
+
+#[derive(Validator)]
+#[validator(any(Ipv4, Ipv6))]
+pub struct IpString(pub String);
+
+assert!(IpString::parse_string("127.0.0.1").is_ok());
+assert!(IpString::parse_string("::1").is_ok());
+assert_eq!(2, IpString::parse_string("localhost").err().unwrap().errors.len());
+# }
+```
+
+* Traits: `ValidateString`
+* Each parameter is the path of a validator which implements `ValidateString`. The field is the original string.
+
 #### base32
 
 ```rust
@@ -527,6 +585,34 @@ assert!(MacAddress::parse_string("08:00:27:B2:46:C3").is_ok());
 * Traits: `ValidateString`, `ToMacAddressString`
 * By default, `case = Any, separator(Allow(b':')`
 
+#### not
+
+```rust
+# #[cfg(all(feature = "derive", feature = "not", feature = "ipv4"))]
+# {
+use std::net::Ipv4Addr;
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Allow)))]
+pub struct Ipv4 {
+    pub ipv4: Ipv4Addr,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(not(Ipv4))]
+pub struct NotIpv4(pub String);
+
+assert!(NotIpv4::parse_string("magiclen.org").is_ok());
+assert!(NotIpv4::parse_string("127.0.0.1:8080").is_err());
+# }
+```
+
+* Traits: `ValidateString`
+* The parameter is the path of a validator which implements `ValidateString`. The field is the original string.
+
 #### number
 
 ```rust
@@ -556,6 +642,39 @@ assert!(SinglePercentage::parse_string("NaN").is_ok());
 * Traits: `ValidateString`, `ValidateNumber`
 * By default, `nan = Allow, range(Unlimited)`
 
+#### path
+
+```rust
+# #[cfg(all(feature = "derive", feature = "path"))]
+# {
+use std::{ffi::OsStr, path::{Path, PathBuf}};
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(path(absolute(Disallow), root(Disallow), parent(Disallow), windows_reserved(Disallow), extensions("png", "jpg"), component_length(max = 255)))]
+pub struct UploadPath(pub PathBuf);
+
+assert!(UploadPath::parse_str("avatars/len.png").is_ok());
+assert!(UploadPath::parse_os_str(OsStr::new("avatars/len.JPG")).is_ok());
+assert!(UploadPath::parse_str("../len.png").is_err());
+assert!(UploadPath::parse_str("/etc/len.png").is_err());
+assert!(UploadPath::parse_str("avatars/len.exe").is_err());
+assert!(UploadPath::parse_str("nul.png").is_err());
+
+let upload_path = UploadPath::parse_str("avatars/len.png").unwrap();
+
+assert_eq!(Path::new("/srv/uploads/avatars/len.png"), Path::new("/srv/uploads").join(upload_path.0));
+# }
+```
+
+* Traits: `ValidateString`, `ValidateOsStr`
+* By default, `absolute = Allow, root = Allow, parent = Allow, windows_reserved = Allow`, and the extensions and the component length are unlimited
+* Components are split by the rules of the current platform. A path with `root(Disallow)` and `parent(Disallow)` has no root, prefix (such as `C:`) or `..` component, so it stays in the base directory when being joined.
+* `extensions` are compared case-insensitively, and the length of `component_length` is in bytes.
+* `windows_reserved(Disallow)` rejects the names reserved by Windows, such as `CON`, `NUL.txt` and `COM1`, on every platform.
+* With the `serde` feature, paths are serialized as strings, so serializing a path which is not valid UTF-8, such as one parsed by `parse_os_str`, fails with an error.
+
 #### phone
 
 ```rust
@@ -789,6 +908,662 @@ assert!(UUID::parse_string("A866664A-F9D3-4DDE-89CB-182015FA4F41").is_ok());
 * Traits: `ValidateString`, `ToUuidString`
 * By default, `case = Any, separator(Allow(b'-')`
 
+## `#[derive(Validate)]`
+
+A struct with named fields whose types are validators can derive `Validate` to implement the `ValidateFields` trait. Each field is parsed from a raw string input by its own validator, and the errors of all fields are collected into a `validators::errors::ValidationErrors` instead of returning only the first one.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "std", feature = "text", feature = "unsigned_integer"))]
+# {
+use std::collections::HashMap;
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(text(char_length(trimmed_min = 1, min = 1, max = 32)))]
+pub struct Name(String);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 100))))]
+pub struct Count(u8);
+
+#[derive(Validate)]
+pub struct Order {
+    name:  Name,
+    #[validate(rename = "amount")]
+    count: Count,
+    note:  Option<Name>,
+}
+
+let mut fields = HashMap::new();
+
+fields.insert("name", "apple");
+fields.insert("amount", "3");
+
+assert!(Order::parse_fields(&fields).is_ok());
+
+fields.insert("name", " ");
+fields.insert("amount", "0");
+
+assert_eq!(2, Order::parse_fields(&fields).err().unwrap().len());
+# }
+```
+
+* Traits: `ValidateFields`
+* Every field type must implement `ValidateString` with an `Error` which implements `ErrorCode`. The inputs are strings, so fields of number, integer and boolean validators are parsed by their `ValidateString` implementations as well, while primitive types, strings, collections and `length` validators are rejected at compile time. A field of type `Option<T>` may be missing from the inputs, while other missing fields produce `MissingFieldError`.
+* The inputs can be any `FieldSource`, such as a `HashMap`, a `BTreeMap` or a slice of key-value pairs.
+* `ValidationErrors` pairs every error with the path of its field (such as `users[3].email`) and keeps its code, and it can be nested by `ValidationErrors::merge`. It can be iterated, and it implements `Serialize` when the `serde` feature is enabled.
+
+## Error Codes
+
+Every error in the `validators::errors` module implements the `ErrorCode` trait, which provides a stable, machine-readable code made of the name of the validator and the kind of the error. Codes are suitable as keys of translations and analytics, unlike the messages from `Display`, which may change.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Allow), local(Disallow), at_least_two_labels(Allow), non_ascii(Allow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: validators::models::Host,
+}
+
+assert_eq!("email.local_disallow", Email::parse_str("len@localhost").err().unwrap().code());
+# }
+```
+
+## Localization
+
+With the `i18n` feature, every error which implements `ErrorCode` and `Display`, including your own, implements the `Localize` trait, which renders its message in a locale by looking up the message catalogs in the `validators::i18n` module with the error code. Catalogs for English (the default), Traditional Chinese (`zh-TW`) and Japanese (`ja`) are bundled. A locale falls back to its language with its region (so both `zh-Hant-TW` and `zh-Hant` use `zh-TW`), its language and then English, and an error falls back to `Display` if no catalog has its message.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "i18n", feature = "email"))]
+# {
+use validators::{errors::EmailError, i18n::Catalog, prelude::*};
+
+assert_eq!("不可包含註解", EmailError::CommentDisallow.localize("zh-TW"));
+
+// additional locales can be registered at runtime
+let catalog = Catalog::from_toml(r#"
+[email]
+comment_disallow = "주석을 포함할 수 없습니다"
+"#).unwrap();
+
+validators::i18n::register_catalog("ko", catalog);
+
+assert_eq!("주석을 포함할 수 없습니다", EmailError::CommentDisallow.localize("ko"));
+# }
+```
+
+* Catalogs are TOML tables keyed by error codes, such as `email.comment_disallow`. Messages can contain placeholders for the values carried by errors, such as `{max}`, `{min}`, `{length}`, `{value}`, `{range}` and `{unit}`.
+* Registering a catalog for a locale which already has one merges the messages, so it can also be used to override the bundled messages.
+
+## Runtime Validators
+
+The options of the `#[validator(...)]` attribute are fixed at compile time. When they need to be decided at runtime, for example loaded from a database, the validators in the `validators::runtime` module can be used instead. They are configured by builders with the types in the `validators::options` module, share the parsing logic and the error types with the attribute, and return the models from `validators::models`. Options which the attribute rejects at compile time, such as `min > max`, make `build()` return an `OptionsError`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email", feature = "unsigned_integer"))]
+# {
+use validators::{
+    errors::EmailError,
+    options::{RangeOption, TriAllow},
+    runtime::{EmailValidator, UnsignedIntegerValidator},
+};
+
+let allow_local = false; // loaded at runtime
+
+let validator = EmailValidator::builder()
+    .local(if allow_local { TriAllow::Allow } else { TriAllow::Disallow })
+    .build()
+    .unwrap();
+
+assert!(matches!(validator.parse_str("len@localhost"), Err(EmailError::LocalDisallow)));
+
+let validator = UnsignedIntegerValidator::builder()
+    .range(RangeOption::Inside { min: Some(1), max: Some(100), inclusive: true })
+    .build()
+    .unwrap();
+
+assert_eq!(42, validator.parse_str("42").unwrap());
+# }
+```
+
+* Validators: `EmailValidator`, `HostValidator`, `HttpFtpUrlValidator` (which require the `derive` feature), `LineValidator`, `TextValidator`, `MacAddressValidator`, `UuidValidator`, `NumberValidator`, `SignedIntegerValidator`, `UnsignedIntegerValidator`
+* The other validators, such as `domain`, `ip`, `regex` and `path`, have no runtime versions yet, so their options can only be set by the attribute.
+* The default options of the builders are the same as the defaults of the attribute.
+
+## Configuration Files
+
+With the `config` feature, named runtime validators can be loaded from a TOML or JSON configuration file by `validators::config::ValidatorConfig`, so their options can be changed without rebuilding. Every table in the `validators` table is a validator. Its `kind` is the name of the validator, and the other parameters are the same as the parameters of the attribute.
+
+```rust
+# #[cfg(all(feature = "config", feature = "derive", feature = "line"))]
+# {
+use validators::{
+    config::{RuntimeValidator, ValidatorConfig},
+    errors::LineError,
+};
+
+let config = ValidatorConfig::from_toml(r#"
+[validators.username]
+kind = "line"
+char_length = { min = 3, max = 32 }
+"#).unwrap();
+
+if let Some(RuntimeValidator::Line(validator)) = config.get("username") {
+    assert!(matches!(validator.parse_str("ml"), Err(LineError::TooShort { .. })));
+}
+
+let error = ValidatorConfig::from_toml(r#"
+[validators.username]
+kind = "line"
+char_lenght = { min = 3 }
+"#).unwrap_err();
+
+assert_eq!(
+    "unsupported parameter `validators.username.char_lenght`, available parameters:\n    kind\n    char_length\n    byte_length",
+    error.to_string()
+);
+# }
+```
+
+* Kinds: `email`, `host`, `http_ftp_url` (which require the `derive` feature), `line`, `text`, `mac_address`, `uuid`, `number`, `signed_integer`, `unsigned_integer`
+* `Must`/`Allow`/`Disallow` are written as strings, such as `local = "Disallow"`, and ranges are written as tables, such as `range = { Inside = { min = 0, max = 150 } }`.
+* Cases are written as strings, such as `case = "Lower"`, and separators are written as `"Disallow"` or tables, such as `separator = { Must = "-" }`.
+
+## Environment Variables
+
+`validators::env::var` reads an environment variable and parses it with a validator. Its error, `EnvError`, keeps the name of the variable and the error from the validator. `validators::env::var_opt` returns `None` for an unset variable instead.
+
+To check all variables at startup instead of failing at the first one, derive `Validate` for a config struct and parse it from `validators::env::Vars`, a snapshot of the environment variables. A field is looked up by its name and then by the upper-cased one, with an optional prefix, and every missing, invalid or non-Unicode variable is reported in the same `ValidationErrors` under the name of the variable.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "host", feature = "unsigned_integer"))]
+# {
+use validators::{env::Vars, prelude::*};
+
+#[derive(Validator)]
+#[validator(host(local(Allow), port(Must), at_least_two_labels(Allow)))]
+pub struct HostMustPort {
+    pub host: validators::models::Host,
+    pub port: u16,
+}
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Workers(u8);
+
+#[derive(Validate)]
+pub struct Config {
+    database_host: HostMustPort,
+    workers:       Option<Workers>,
+}
+
+std::env::set_var("APP_DATABASE_HOST", "localhost:5432");
+
+let database_host: HostMustPort = validators::env::var("APP_DATABASE_HOST").unwrap();
+
+match Config::parse_fields(&Vars::with_prefix("APP_")) {
+    Ok(_config) => {
+        // start the application
+    },
+    Err(errors) => eprintln!("invalid environment variables:\n{errors}"),
+}
+# }
+```
+
+## Dynamic Validators
+
+When the validator for an input is only known at runtime, such as by the type of a column, validators can be looked up by their names in a `validators::registry::Registry`. Every type implementing `ValidateString` can be registered, as well as the validators in the `validators::runtime` module. Their type is erased through the object-safe `validators::traits::DynValidator` trait, and their errors are boxed as `dyn DynError`, which still provides the error code.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "ipv4", feature = "uuid", feature = "serde_json"))]
+# {
+use std::net::Ipv4Addr;
+
+use validators::{prelude::*, registry::Registry, serde_json::json};
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid(pub u128);
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(pub Ipv4Addr);
+
+let mut registry = Registry::new();
+
+registry.register::<Uuid, _>("uuid");
+registry.register::<Ipv4, _>("ipv4");
+
+assert!(registry.validate_str("uuid", "a866664a-f9d3-4dde-89cb-182015fa4f41").is_ok());
+assert_eq!("ipv4.port_disallow", registry.validate_json_value("ipv4", &json!("127.0.0.1:80")).unwrap_err().code());
+# }
+```
+
+* Strings are validated as they are. Numbers and booleans in `serde_json::Value`s are validated by their JSON representations, and other values are rejected.
+* With the `config` feature, a `Registry` can be created from a `ValidatorConfig`.
+
+## Custom Validation
+
+Every validator accepts a `custom = path::to::function` parameter to add your own rules after the built-in parsing succeeds. The function receives the parsed struct and returns `Result<(), E>`, where `E` is any error which implements `ErrorCode`, `Display` and `Debug`. The `Error` types of the `Validate*` traits become `validators::errors::CustomError`, which is either the error of the built-in validator or the boxed error of the function.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "domain"))]
+# {
+use std::fmt::{self, Display, Formatter};
+
+use validators::{errors::CustomError, prelude::*};
+
+#[derive(Debug)]
+pub struct BlockedDomainError;
+
+impl Display for BlockedDomainError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("the domain is blocked")
+    }
+}
+
+impl ErrorCode for BlockedDomainError {
+    fn code(&self) -> &'static str {
+        "blocked_domain"
+    }
+}
+
+fn not_blocked(domain: &Domain) -> Result<(), BlockedDomainError> {
+    if domain.0.ends_with("example.com") {
+        Err(BlockedDomainError)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), at_least_two_labels(Must), port(Disallow), custom = not_blocked))]
+pub struct Domain(pub String);
+
+assert!(Domain::parse_string("magiclen.org").is_ok());
+assert!(matches!(Domain::parse_string("localhost"), Err(CustomError::Validator(_))));
+assert_eq!("blocked_domain", Domain::parse_string("www.example.com").err().unwrap().code());
+# }
+```
+
+## Normalization
+
+With the `normalize` feature, validators which implement `ValidateString` accept a `normalize(...)` parameter to normalize input strings before they are validated. The normalized string is the one which is parsed and stored in the struct.
+
+* `strip_zero_width`: remove zero-width characters, such as `U+200B` and `U+FEFF`
+* `nfc` or `nfkc`: apply the Unicode normalization form NFC or NFKC
+* `trim`: remove the leading and trailing whitespaces
+* `collapse_whitespace`: replace every run of whitespaces with a single space
+* `lowercase`: convert the string to lowercase
+
+The steps always run in the above order.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email", feature = "normalize"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Allow), local(Allow), at_least_two_labels(Allow), non_ascii(Allow), normalize(trim, lowercase)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: validators::models::Host,
+}
+
+let email = Email::parse_string(" Len@MagicLen.ORG\n").unwrap();
+
+assert_eq!("len", email.local_part);
+assert_eq!("len@magiclen.org", email.to_email_string());
+# }
+```
+
+## Borrowed Strings
+
+The `base32`, `base64`, `base64_url`, `domain`, `line`, `regex`, `text` and `uuid` validators do not change their input, so they can also be used for structs which borrow the string, such as `struct Slug<'a>(&'a str)` and `struct Slug<'a>(Cow<'a, str>)`. These structs implement the `ValidateBorrowedStr<'a>` trait, whose `parse_borrowed` method validates a `&'a str` without copying it. The `domain` validator stores the input as it is, so a borrowed domain must already be in the form which the IDNA conversion produces. Non-ASCII characters and upper-case letters are rejected.
+
+A struct with a `Cow<'a, str>` field also implements `ValidateString`, which owns the string. When deserialized with serde, `&'a str` fields can only borrow from the input, and `Cow<'a, str>` fields borrow whenever possible.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "line", feature = "text"))]
+# {
+use std::borrow::Cow;
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 32)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(text(char_length(max = 256)))]
+pub struct Comment<'a>(Cow<'a, str>);
+
+let input = String::from("hello-world");
+
+let slug = Slug::parse_borrowed(&input).unwrap();
+
+assert_eq!(input.as_ptr(), slug.0.as_ptr());
+
+assert!(matches!(Comment::parse_borrowed("Hi!").unwrap().0, Cow::Borrowed(_)));
+assert!(matches!(Comment::parse_str("Hi!").unwrap().0, Cow::Owned(_)));
+# }
+```
+
+## Standard Traits
+
+Every validator accepts an `impls(...)` parameter to implement the following standard traits for the struct.
+
+* `Display`: format the struct with the `ToEmailString`, `ToUuidString`, `ToMacAddressString` or `ToUriAuthorityString` trait if the validator implements it, or with its only field
+* `FromStr`: call `ValidateString::parse_str`
+* `TryFrom`: implement `TryFrom<String>` and `TryFrom<&str>` by `ValidateString`, or `TryFrom<&'a str>` by `ValidateBorrowedStr<'a>` for borrowed strings
+* `AsRef`, `Deref`, `Borrow`: borrow the only field, as `str` if it is a string
+
+```rust
+# #[cfg(all(feature = "derive", feature = "line", feature = "uuid"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(uuid(case(Upper), separator(Allow(b'-')), impls(Display, FromStr, Deref)))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 32), impls(Display, TryFrom, Deref)))]
+pub struct Name(String);
+
+let uuid: Uuid = "A866664AF9E94B5C8A59E6B5A7A5D4C8".parse().unwrap();
+
+assert_eq!("A866664A-F9E9-4B5C-8A59-E6B5A7A5D4C8", uuid.to_string());
+assert_eq!(0xA866664AF9E94B5C8A59E6B5A7A5D4C8, *uuid);
+
+let name = Name::try_from("Magic Len").unwrap();
+
+assert_eq!("Magic Len", name.to_string());
+assert!(name.starts_with("Magic"));
+# }
+```
+
+## Validator Specs
+
+Every type derived by `Validator` implements the `ValidatorSpec` trait, whose `SPEC` constant describes the validator and all of its options, including the default ones. It can be used to show the constraints of a field, such as "max 1000 chars, no local domains", without repeating the values of the attribute.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "domain", feature = "line"))]
+# {
+use validators::{models::SpecValue, options::TriAllow, prelude::*};
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 1000)))]
+pub struct Comment(String);
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), at_least_two_labels(Allow), port(Disallow)))]
+pub struct Domain(String);
+
+assert_eq!("line", Comment::SPEC.validator);
+assert_eq!(Some(&SpecValue::Length(Some(1000))), Comment::SPEC.get("char_length_max"));
+assert_eq!(Some(&SpecValue::TriAllow(TriAllow::Disallow)), Domain::SPEC.get("local"));
+# }
+```
+
+## JSON Schema
+
+By enabling the `schemars` feature, every type derived by `Validator` implements the `JsonSchema` trait of the `schemars` crate, whose schema is created from the `ValidatorSpec` of the type. For example, `char_length` becomes `minLength`/`maxLength`, `range` becomes `minimum`/`maximum`/`exclusiveMaximum`, the `regex` validator gives a `pattern`, `length` gives `minItems`/`maxItems`, and the `email`, `domain`, `ipv4`, `ipv6`, `url` and `uuid` validators give a `format`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "line", feature = "schemars", feature = "serde_json"))]
+# {
+use validators::{prelude::*, schemars::schema_for, serde_json::{self, json}};
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 1000)))]
+pub struct Comment(String);
+
+let schema = serde_json::to_value(schema_for!(Comment).schema).unwrap();
+
+assert_eq!(json!({ "title": "Comment", "type": "string", "maxLength": 1000 }), schema);
+# }
+```
+
+## OpenAPI Schema
+
+By enabling the `utoipa` feature, every type derived by `Validator` implements the `PartialSchema` and `ToSchema` traits of the `utoipa` crate, with the same constraints as the JSON schema. The `boolean` validator is documented with the strings and numbers it accepts, and the combined validators of `all` and `any` are added to the components by `ToSchema::schemas`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer", feature = "utoipa", feature = "serde_json"))]
+# {
+use validators::{prelude::*, serde_json::{self, json}, utoipa::PartialSchema};
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 100))))]
+pub struct Count(u8);
+
+let schema = serde_json::to_value(Count::schema()).unwrap();
+
+assert_eq!(json!({ "type": "integer", "minimum": 1, "maximum": 100 }), schema);
+# }
+```
+
+## Property-Based Testing
+
+By enabling the `proptest` feature, every type derived by `Validator` implements the `Arbitrary` trait of the `proptest` crate if it implements `Debug`, so `any::<T>()` generates values satisfying the options of its validator. Types validating strings also implement the `ValidatorStrategy` trait, whose `valid_strings` gives the strings accepted by the validator and `invalid_strings` gives near-miss strings rejected by it, such as a valid string with a character inserted, the padding removed, the case changed or a number just outside the range. Types holding borrowed strings are skipped.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "proptest"))]
+# {
+use validators::{prelude::*, proptest::prelude::*};
+
+#[derive(Debug, Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+proptest!(|(uuid in any::<Uuid>(), s in Uuid::invalid_strings())| {
+    prop_assert_eq!(uuid.0, Uuid::parse_string(uuid.to_uuid_string()).unwrap().0);
+    prop_assert!(Uuid::parse_str(s).is_err());
+});
+# }
+```
+
+## Fuzzing
+
+By enabling the `arbitrary` feature, every type derived by `Validator` implements the `Arbitrary` trait of the `arbitrary` crate. The input is parsed by the validator, and input rejected by it becomes `Error::IncorrectFormat`, so the type can be used directly in fuzz targets. Types holding borrowed strings borrow them from the input.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "arbitrary"))]
+# {
+use validators::{arbitrary::{Arbitrary, Unstructured}, prelude::*};
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+let uuid = Uuid::arbitrary_take_rest(Unstructured::new(b"a866664a-f9d3-4dde-89cb-182015fa4f41")).unwrap();
+
+assert_eq!(0xA866664AF9D34DDE89CB182015FA4F41, uuid.0);
+# }
+```
+
+The `fuzz` directory of the repository contains `cargo fuzz` targets for the parsers of the validators. The targets take raw bytes, and input which is not valid UTF-8 is only given to the `path` validator through `ValidateOsStr`. Each target checks that `validate_str` and `parse_str` (or `validate_os_str` and `parse_os_str`) agree on the input and that the string created from a parsed value can be parsed again.
+
+## Axum
+
+By enabling the `axum` feature, every type derived by `Validator` which validates strings implements the `FromRequestParts` trait of the `axum` crate, so it can be a parameter of a handler and is parsed from the only path parameter of the route. Set `axum = false` in the validator attribute to opt out, for example when another `FromRequestParts` implementation is written by hand. The `validators::web` module provides the `Path` and `Query` extractors, whose rejection status can be changed by their second generic parameter, and `validators::Result` is also an extractor which keeps the error instead of rejecting the request.
+
+The rejection responds with a JSON body made of the error code and the message, such as `{"code":"uuid.invalid","message":"invalid uuid"}`, with the `422 Unprocessable Entity` status by default.
+
+The `axum` feature requires Rust 1.75 or newer, the version which `axum` 0.8 requires, and the CI tests it with that version, while the rest of this crate still builds with Rust 1.69.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "axum"))]
+# {
+use axum::{routing::get, Router};
+use validators::{errors::UuidError, prelude::*, web::Path};
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid(u128);
+
+async fn get_user(id: Uuid) -> String {
+    format!("{:032x}", id.0)
+}
+
+async fn get_post(Path(id): Path<Uuid, 400>) -> String {
+    format!("{:032x}", id.0)
+}
+
+async fn get_comment(id: validators::Result<Uuid, UuidError>) -> String {
+    match id.into_std_result() {
+        Ok(id) => format!("{:032x}", id.0),
+        Err(error) => error.code().to_string(),
+    }
+}
+
+let app: Router = Router::new()
+    .route("/users/{id}", get(get_user))
+    .route("/posts/{id}", get(get_post))
+    .route("/comments/{id}", get(get_comment));
+# }
+```
+
+## Actix
+
+By enabling the `actix` feature, every type derived by `Validator` which validates strings implements the `FromRequest` trait of the `actix-web` crate, so it can be a parameter of a handler and is parsed from the only path parameter of the route. Set `actix = false` in the validator attribute to opt out, for example when another `FromRequest` implementation is written by hand. Because the feature also enables `serde`, derived types can be used in `web::Path`, `web::Query` and `web::Form` as well.
+
+Every error in `validators::errors` implements `ResponseError`, which responds with the `422 Unprocessable Entity` status and the same JSON body as the `axum` rejections. The body of `ValidationErrors` has the code `validation.invalid` and an additional `errors` field listing the errors of the fields.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "line", feature = "actix"))]
+# {
+use actix_web::{web, App};
+use serde::Deserialize;
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 32), actix = false))]
+pub struct Name(String);
+
+#[derive(Deserialize)]
+struct Search {
+    name: Name,
+}
+
+async fn get_user(id: Uuid) -> String {
+    format!("{:032x}", id.0)
+}
+
+async fn search(query: web::Query<Search>) -> String {
+    query.into_inner().name.0
+}
+
+let app = App::new()
+    .route("/users/{id}", web::get().to(get_user))
+    .route("/search", web::get().to(search));
+# }
+```
+
+## Clap
+
+By enabling the `clap` feature, every type derived by `Validator` which validates strings implements the `ValueParserFactory` trait of the `clap` crate, so it can be the type of an argument and is validated while `clap` parses the command line. An invalid argument is reported by `clap` with the message of the validator error, and types derived with the `boolean` validator list their possible values. The parser is `validators::cli::ValidatorParser`, which can also be set by `value_parser` explicitly.
+
+Like other types used by `clap`, the derived types need to implement `Clone`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "host", feature = "clap"))]
+# {
+use clap::Parser;
+use validators::prelude::*;
+
+#[derive(Validator, Clone)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct HostAllowPort {
+    pub host: validators::models::Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long)]
+    host: HostAllowPort,
+}
+
+let args = Args::try_parse_from(["app", "--host", "localhost:8000"]).unwrap();
+
+assert_eq!(Some(8000), args.host.port);
+
+let error = Args::try_parse_from(["app", "--host", "localhost:99999"]).err().unwrap();
+
+assert!(error.to_string().contains("invalid value 'localhost:99999' for '--host <HOST>'"));
+# }
+```
+
+## SQLx
+
+By enabling the `sqlx` feature, every type derived by `Validator` implements the `Type`, `Encode` and `Decode` traits of the `sqlx` crate for the databases which support its stored form. Types derived with the `unsigned_integer`, `signed_integer`, `number` and `boolean` validators are stored as their inner values, and types derived with the `mac_address` validator are stored as `i64`. Types derived with the `uuid` validator are stored as `sqlx::types::Uuid` if the `sqlx_uuid` feature is enabled, which suits a Postgres `uuid` column. Otherwise they are stored like the other types, as strings made by their `Serialize` implementations, which follow the `case` and `separator` options of the validator, so their columns should be text. A value read from a database is validated again, and an invalid one is a decode error.
+
+Types derived with the `length` validator or the `*_decoded` validators do not implement these traits.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "sqlx"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+async fn find_user(pool: &sqlx::SqlitePool, id: &Uuid) -> sqlx::Result<Option<Uuid>> {
+    sqlx::query_scalar("SELECT id FROM users WHERE id = ?").bind(id).fetch_optional(pool).await
+}
+# }
+```
+
+## Diesel
+
+By enabling the `diesel` feature, every type derived by `Validator` implements the `ToSql`, `FromSql`, `AsExpression` and `Queryable` traits of the `diesel` crate, so it can be the type of a field of an `Insertable` or `Queryable` struct. Types derived with the `unsigned_integer`, `signed_integer`, `number` and `boolean` validators are delegated to their inner values, so an `i32` is `Integer` and an `i64` is `BigInt`, for example. The other types are `Text`, made of the strings which their `Serialize` implementations produce. Types derived with the `mac_address` validator can also be `BigInt`, and types derived with the `uuid` validator can also be the `Uuid` type of Postgres if the `diesel_postgres` feature is enabled. A value read from a database is validated again, and an invalid one is a deserialization error.
+
+Because the strings of `Text` values are created when the values are used, `ToSql` for `Text` is only implemented for the PostgreSQL and MySQL backends, and SQLite uses `AsExpression` instead, which is what `diesel` uses to build queries. `ToSql` also needs the types to implement `Debug`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "signed_integer", feature = "diesel"))]
+# {
+use diesel::prelude::*;
+use validators::prelude::*;
+
+diesel::table! {
+    users (id) {
+        id -> Text,
+        age -> Integer,
+    }
+}
+
+#[derive(Debug, Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Debug, Validator)]
+#[validator(signed_integer(range(Inside(min = 0, max = 150))))]
+pub struct Age(i32);
+
+#[derive(Insertable, Queryable)]
+#[diesel(table_name = users)]
+struct User {
+    id:  Uuid,
+    age: Age,
+}
+# }
+```
+
 ## `validators::Result`
 
 When incorporating your validator type into another type, you might desire to obtain the original error instance provided by the validator.
@@ -851,47 +1626,101 @@ fn index(user: User) -> String {
 
 extern crate alloc;
 
+#[cfg(feature = "actix")]
+pub extern crate actix_web;
+#[cfg(feature = "arbitrary")]
+pub extern crate arbitrary;
+#[cfg(feature = "axum")]
+pub extern crate axum;
 #[cfg(feature = "byte-unit")]
 pub extern crate byte_unit;
+#[cfg(feature = "clap")]
+pub extern crate clap;
 extern crate core;
 #[cfg(feature = "data-encoding")]
 pub extern crate data_encoding;
+#[cfg(feature = "diesel")]
+pub extern crate diesel;
 #[cfg(feature = "idna")]
 pub extern crate idna;
 #[cfg(feature = "phonenumber")]
 pub extern crate phonenumber;
+#[cfg(feature = "proptest")]
+pub extern crate proptest;
 #[cfg(feature = "regex-dep")]
 pub extern crate regex_dep as regex;
 #[cfg(feature = "rocket")]
 pub extern crate rocket;
+#[cfg(feature = "schemars")]
+pub extern crate schemars;
 #[cfg(feature = "semver-dep")]
 pub extern crate semver_dep as semver;
 #[cfg(feature = "serde")]
 pub extern crate serde;
 #[cfg(feature = "serde_json")]
 pub extern crate serde_json;
+#[cfg(feature = "sqlx")]
+pub extern crate sqlx;
 #[cfg(feature = "str-utils")]
 pub extern crate str_utils;
+#[cfg(feature = "normalize")]
+pub extern crate unicode_normalization;
 #[cfg(feature = "url-dep")]
 pub extern crate url_dep as url;
-
+#[cfg(feature = "utoipa")]
+pub extern crate utoipa;
+
+/// Validators loaded from configuration files.
+#[cfg(feature = "config")]
+pub mod config;
+/// Typed environment variables.
+#[cfg(feature = "std")]
+pub mod env;
 /// Errors from validators.
 pub mod errors;
 /// Functions with validators.
 pub mod functions;
+/// Message catalogs for localizing errors.
+#[cfg(feature = "i18n")]
+pub mod i18n;
 /// Models with validators.
 pub mod models;
+/// Options of validators, such as `TriAllow` and `RangeOption`.
+pub mod options;
+/// Validators looked up by their names at runtime.
+pub mod registry;
 #[doc(hidden)]
 #[cfg(feature = "test")]
 pub mod test;
 /// Module of traits.
 pub mod traits;
 
+/// Validators whose options are decided at runtime.
+pub mod runtime;
+
+/// Value parsers for command-line arguments.
+#[cfg(feature = "clap")]
+pub mod cli;
+
+/// Helpers for storing validated values in databases.
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+#[doc(hidden)]
+pub mod sql;
+
+/// Extractors and error responses for web frameworks.
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub mod web;
+
+/// Helpers for the `Deserialize` implementations generated by the `Validator` derive macro.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod de;
+
 mod result;
 
 pub use result::*;
 #[cfg(feature = "derive")]
-pub use validators_derive::Validator;
+pub use validators_derive::{Validate, Validator};
 
 /**
 A convenience module appropriate for glob imports.
@@ -905,36 +1734,65 @@ pub mod prelude {
     pub mod validators_prelude {
         pub use alloc::{borrow::Cow, format, string::String, vec::Vec};
 
+        #[cfg(feature = "actix")]
+        pub use crate::actix_web;
+        #[cfg(feature = "arbitrary")]
+        pub use crate::arbitrary;
+        #[cfg(feature = "axum")]
+        pub use crate::axum;
         #[cfg(feature = "byte-unit")]
         pub use crate::byte_unit;
         #[cfg(feature = "data-encoding")]
         pub use crate::data_encoding;
+        #[cfg(feature = "serde")]
+        pub use crate::de;
+        #[cfg(feature = "diesel")]
+        pub use crate::diesel;
         pub use crate::errors::*;
         #[cfg(feature = "idna")]
         pub use crate::idna;
+        #[cfg(feature = "normalize")]
+        pub use crate::options::{NormalizationForm, NormalizeOption};
         #[cfg(feature = "phonenumber")]
         pub use crate::phonenumber;
+        #[cfg(feature = "proptest")]
+        pub use crate::proptest;
         #[cfg(feature = "regex")]
         pub use crate::regex;
         #[cfg(feature = "rocket")]
         pub use crate::rocket;
+        #[cfg(feature = "schemars")]
+        pub use crate::schemars;
         #[cfg(feature = "semver-dep")]
         pub use crate::semver;
         #[cfg(feature = "serde")]
         pub use crate::serde;
         #[cfg(feature = "serde_json")]
         pub use crate::serde_json;
+        #[cfg(any(feature = "sqlx", feature = "diesel"))]
+        pub use crate::sql;
+        #[cfg(feature = "sqlx")]
+        pub use crate::sqlx;
         #[cfg(feature = "str-utils")]
         pub use crate::str_utils;
-        #[cfg(feature = "test")]
-        pub use crate::test::*;
         #[cfg(feature = "url")]
         pub use crate::url;
+        #[cfg(feature = "utoipa")]
+        pub use crate::utoipa;
+        #[cfg(any(feature = "axum", feature = "actix"))]
+        pub use crate::web;
+        #[cfg(feature = "clap")]
+        pub use crate::{clap, cli};
         #[allow(unused_imports)]
-        pub use crate::{functions::*, models::*};
+        pub use crate::{
+            functions::*,
+            models::*,
+            options::{CaseOption, RangeOption, SeparatorOption, TriAllow},
+            traits::{FieldInput, FieldSource, ValidateFields, ValidateString},
+        };
     }
 
     pub use crate::traits::*;
     #[cfg(feature = "derive")]
-    pub use crate::Validator;
+    pub use crate::{Validate, Validator};
 }
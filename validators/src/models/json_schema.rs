@@ -0,0 +1,178 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+
+use super::{Spec, SpecValue};
+use crate::options::RangeOption;
+
+impl Spec {
+    /// Create the JSON schema of the values which can be serialized from or deserialized to the validated type.
+    ///
+    /// `inner` are the schemas of the inner types, which are needed by the `length` validator (the schema of the collection) and the `all`, `any` and `not` validators (the schemas of the validators being combined).
+    pub fn to_json_schema(&self, inner: Vec<Schema>) -> Schema {
+        let mut schema = SchemaObject::default();
+
+        match self.validator {
+            "all" => {
+                schema.subschemas().all_of = Some(inner);
+            },
+            "any" => {
+                schema.subschemas().any_of = Some(inner);
+            },
+            "not" => {
+                schema.instance_type = Some(InstanceType::String.into());
+
+                if let Some(inner) = inner.into_iter().next() {
+                    schema.subschemas().not = Some(Box::new(inner));
+                }
+            },
+            "length" => {
+                if let Some(inner) = inner.into_iter().next() {
+                    schema = inner.into_object();
+                }
+
+                let min = self.length("min").map(to_u32);
+                let max = self.length("max").map(to_u32);
+
+                // maps are serialized as objects
+                if schema.has_type(InstanceType::Object) {
+                    let object = schema.object();
+
+                    object.min_properties = min;
+                    object.max_properties = max;
+                } else {
+                    let array = schema.array();
+
+                    array.min_items = min;
+                    array.max_items = max;
+                }
+            },
+            "boolean" => {
+                schema.instance_type = Some(InstanceType::Boolean.into());
+            },
+            "number" | "signed_integer" | "unsigned_integer" => {
+                schema.instance_type = Some(
+                    if self.validator == "number" {
+                        InstanceType::Number
+                    } else {
+                        InstanceType::Integer
+                    }
+                    .into(),
+                );
+
+                let range = match self.get("range") {
+                    Some(SpecValue::NumberRange(range)) => to_f64_range(range, |v| v),
+                    Some(SpecValue::SignedRange(range)) => to_f64_range(range, |v| v as f64),
+                    Some(SpecValue::UnsignedRange(range)) => to_f64_range(range, |v| v as f64),
+                    _ => RangeOption::Unlimited,
+                };
+
+                match range {
+                    RangeOption::Inside {
+                        min,
+                        max,
+                        inclusive,
+                    } => {
+                        set_range(&mut schema, min, max, inclusive);
+                    },
+                    RangeOption::Outside {
+                        min,
+                        max,
+                        inclusive,
+                    } if min.is_some() || max.is_some() => {
+                        let mut forbidden = SchemaObject::default();
+
+                        set_range(&mut forbidden, min, max, inclusive);
+
+                        schema.subschemas().not = Some(Box::new(forbidden.into()));
+                    },
+                    _ => (),
+                }
+
+                if self.validator == "unsigned_integer" && schema.number().minimum.is_none() {
+                    schema.number().minimum = Some(0.0);
+                }
+            },
+            "base32_decoded" | "base64_decoded" | "base64_url_decoded" => {
+                // the decoded bytes are serialized as an array of numbers
+                let mut byte = SchemaObject {
+                    instance_type: Some(InstanceType::Integer.into()),
+                    ..SchemaObject::default()
+                };
+
+                byte.number().minimum = Some(0.0);
+                byte.number().maximum = Some(255.0);
+
+                schema.instance_type = Some(InstanceType::Array.into());
+                schema.array().items = Some(Schema::Object(byte).into());
+            },
+            "json" => (),
+            _ => {
+                schema.instance_type = Some(InstanceType::String.into());
+
+                schema.format = self.format().map(String::from);
+
+                match self.validator {
+                    "line" | "text" => {
+                        let string = schema.string();
+
+                        string.min_length = self.length("char_length_min").map(to_u32);
+                        string.max_length = self.length("char_length_max").map(to_u32);
+                    },
+                    "regex" => {
+                        if let Some(SpecValue::Str(regex)) = self.get("regex") {
+                            schema.string().pattern = Some(String::from(*regex));
+                        }
+                    },
+                    _ => (),
+                }
+            },
+        }
+
+        schema.into()
+    }
+}
+
+#[inline]
+fn to_u32(length: usize) -> u32 {
+    length.min(u32::MAX as usize) as u32
+}
+
+#[inline]
+fn to_f64_range<T: Copy>(range: &RangeOption<T>, f: impl Fn(T) -> f64) -> RangeOption<f64> {
+    match *range {
+        RangeOption::Inside {
+            min,
+            max,
+            inclusive,
+        } => RangeOption::Inside {
+            min: min.map(&f),
+            max: max.map(&f),
+            inclusive,
+        },
+        RangeOption::Outside {
+            min,
+            max,
+            inclusive,
+        } => RangeOption::Outside {
+            min: min.map(&f),
+            max: max.map(&f),
+            inclusive,
+        },
+        RangeOption::Unlimited => RangeOption::Unlimited,
+    }
+}
+
+/// Set the range in the same way as the code generated for the `range` parameter, which means `inclusive` only affects the maximum.
+#[inline]
+fn set_range(schema: &mut SchemaObject, min: Option<f64>, max: Option<f64>, inclusive: bool) {
+    let number = schema.number();
+
+    number.minimum = min;
+
+    if inclusive {
+        number.maximum = max;
+    } else {
+        number.exclusive_maximum = max;
+    }
+}
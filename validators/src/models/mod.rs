@@ -7,3 +7,16 @@ pub use host::*;
 mod protocol;
 #[cfg(feature = "http_ftp_url")]
 pub use protocol::*;
+
+mod spec;
+
+pub use spec::*;
+
+#[cfg(feature = "schemars")]
+mod json_schema;
+
+#[cfg(feature = "utoipa")]
+mod openapi_schema;
+
+#[cfg(feature = "proptest")]
+mod strategy;
@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+
+use utoipa::{
+    openapi::{
+        schema::{
+            AllOfBuilder, AnyOfBuilder, ArrayBuilder, KnownFormat, ObjectBuilder, OneOfBuilder,
+            Schema, SchemaFormat, SchemaType, Type,
+        },
+        RefOr,
+    },
+    Number,
+};
+
+use super::{Spec, SpecValue, BOOLEAN_STRINGS};
+use crate::options::RangeOption;
+
+impl Spec {
+    /// Create the OpenAPI schema of the values which can be serialized from or deserialized to the validated type.
+    ///
+    /// `inner` are the schemas of the inner types, which are needed by the `length` validator (the schema of the collection) and the `all` and `any` validators (the schemas of the validators being combined).
+    pub fn to_openapi_schema(&self, inner: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        let schema = match self.validator {
+            "all" => inner.into_iter().fold(AllOfBuilder::new(), |b, s| b.item(s)).build().into(),
+            "any" => inner.into_iter().fold(AnyOfBuilder::new(), |b, s| b.item(s)).build().into(),
+            "length" => match inner.into_iter().next() {
+                // maps are serialized as objects
+                Some(RefOr::T(Schema::Object(mut object))) => {
+                    object.min_properties = self.length("min");
+                    object.max_properties = self.length("max");
+
+                    object.into()
+                },
+                Some(RefOr::T(Schema::Array(mut array))) => {
+                    array.min_items = self.length("min");
+                    array.max_items = self.length("max");
+
+                    array.into()
+                },
+                _ => ArrayBuilder::new()
+                    .min_items(self.length("min"))
+                    .max_items(self.length("max"))
+                    .build()
+                    .into(),
+            },
+            "boolean" => OneOfBuilder::new()
+                .item(ObjectBuilder::new().schema_type(Type::Boolean))
+                .item(
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .enum_values(Some(BOOLEAN_STRINGS)),
+                )
+                .item(ObjectBuilder::new().schema_type(Type::Integer).enum_values(Some([0, 1])))
+                .build()
+                .into(),
+            "number" | "signed_integer" | "unsigned_integer" => {
+                let ty = if self.validator == "number" { Type::Number } else { Type::Integer };
+
+                let range = match self.get("range") {
+                    Some(SpecValue::NumberRange(range)) => to_number_range(range, Number::Float),
+                    Some(SpecValue::SignedRange(range)) => to_number_range(range, |v| {
+                        isize::try_from(v).map(Number::Int).unwrap_or(Number::Float(v as f64))
+                    }),
+                    Some(SpecValue::UnsignedRange(range)) => to_number_range(range, |v| {
+                        usize::try_from(v).map(Number::UInt).unwrap_or(Number::Float(v as f64))
+                    }),
+                    _ => RangeOption::Unlimited,
+                };
+
+                let min_unsigned =
+                    if self.validator == "unsigned_integer" { Some(Number::UInt(0)) } else { None };
+
+                match range {
+                    RangeOption::Inside {
+                        min,
+                        max,
+                        inclusive,
+                    } => set_range(
+                        ObjectBuilder::new().schema_type(ty),
+                        min.or(min_unsigned),
+                        max,
+                        inclusive,
+                    )
+                    .build()
+                    .into(),
+                    RangeOption::Outside {
+                        min,
+                        max,
+                        inclusive,
+                    } if min.is_some() || max.is_some() => {
+                        // the values less than `min` or greater than `max`
+                        let mut any_of = AnyOfBuilder::new();
+
+                        if let Some(min) = min {
+                            any_of = any_of.item(
+                                ObjectBuilder::new()
+                                    .schema_type(ty.clone())
+                                    .minimum(min_unsigned)
+                                    .exclusive_maximum(Some(min)),
+                            );
+                        }
+
+                        if let Some(max) = max {
+                            let object = ObjectBuilder::new().schema_type(ty);
+
+                            any_of = any_of.item(if inclusive {
+                                object.exclusive_minimum(Some(max))
+                            } else {
+                                object.minimum(Some(max))
+                            });
+                        }
+
+                        any_of.build().into()
+                    },
+                    _ => ObjectBuilder::new().schema_type(ty).minimum(min_unsigned).build().into(),
+                }
+            },
+            "base32_decoded" | "base64_decoded" | "base64_url_decoded" => {
+                // the decoded bytes are serialized as an array of numbers
+                ArrayBuilder::new()
+                    .items(
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .minimum(Some(0))
+                            .maximum(Some(255)),
+                    )
+                    .build()
+                    .into()
+            },
+            "json" => ObjectBuilder::new().schema_type(SchemaType::AnyValue).build().into(),
+            _ => {
+                let format = self.format().map(|format| match format {
+                    "email" => SchemaFormat::KnownFormat(KnownFormat::Email),
+                    "hostname" => SchemaFormat::KnownFormat(KnownFormat::Hostname),
+                    "ipv4" => SchemaFormat::KnownFormat(KnownFormat::Ipv4),
+                    "ipv6" => SchemaFormat::KnownFormat(KnownFormat::Ipv6),
+                    _ => SchemaFormat::Custom(format.into()),
+                });
+
+                let mut object = ObjectBuilder::new().schema_type(Type::String).format(format);
+
+                match self.validator {
+                    "line" | "text" => {
+                        object = object
+                            .min_length(self.length("char_length_min"))
+                            .max_length(self.length("char_length_max"));
+                    },
+                    "regex" => {
+                        if let Some(SpecValue::Str(regex)) = self.get("regex") {
+                            object = object.pattern(Some(*regex));
+                        }
+                    },
+                    _ => (),
+                }
+
+                object.build().into()
+            },
+        };
+
+        RefOr::T(schema)
+    }
+}
+
+#[inline]
+fn to_number_range<T: Copy>(
+    range: &RangeOption<T>,
+    f: impl Fn(T) -> Number,
+) -> RangeOption<Number> {
+    match *range {
+        RangeOption::Inside {
+            min,
+            max,
+            inclusive,
+        } => RangeOption::Inside {
+            min: min.map(&f),
+            max: max.map(&f),
+            inclusive,
+        },
+        RangeOption::Outside {
+            min,
+            max,
+            inclusive,
+        } => RangeOption::Outside {
+            min: min.map(&f),
+            max: max.map(&f),
+            inclusive,
+        },
+        RangeOption::Unlimited => RangeOption::Unlimited,
+    }
+}
+
+/// Set the range in the same way as the code generated for the `range` parameter, which means `inclusive` only affects the maximum.
+#[inline]
+fn set_range(
+    object: ObjectBuilder,
+    min: Option<Number>,
+    max: Option<Number>,
+    inclusive: bool,
+) -> ObjectBuilder {
+    let object = object.minimum(min);
+
+    if inclusive {
+        object.maximum(max)
+    } else {
+        object.exclusive_maximum(max)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::options::{CaseOption, RangeOption, SeparatorOption, TriAllow};
+
+/// The strings accepted by the `boolean` validator, case-insensitively.
+#[cfg(any(feature = "utoipa", feature = "proptest", feature = "clap"))]
+pub(crate) const BOOLEAN_STRINGS: [&str; 12] =
+    ["true", "false", "t", "f", "yes", "no", "y", "n", "on", "off", "1", "0"];
+
+/// The description of a validator and its options, provided by the `ValidatorSpec` trait.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spec {
+    /// The name of the validator, such as `"line"`.
+    pub validator: &'static str,
+    /// The options of the validator and their values, including the default ones.
+    pub options:   &'static [(&'static str, SpecValue)],
+}
+
+impl Spec {
+    /// Get the value of an option by its name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&SpecValue> {
+        self.options.iter().find(|(option, _)| *option == name).map(|(_, value)| value)
+    }
+
+    /// Get the value of a length option by its name.
+    #[cfg(any(feature = "schemars", feature = "utoipa", feature = "proptest"))]
+    #[inline]
+    pub(crate) fn length(&self, name: &str) -> Option<usize> {
+        match self.get(name) {
+            Some(SpecValue::Length(length)) => *length,
+            _ => None,
+        }
+    }
+
+    /// Get the name of the JSON schema format of the validated strings.
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub(crate) fn format(&self) -> Option<&'static str> {
+        let port_disallowed =
+            matches!(self.get("port"), None | Some(SpecValue::TriAllow(TriAllow::Disallow)));
+
+        match self.validator {
+            "email" => Some("email"),
+            "domain" if port_disallowed => Some("hostname"),
+            "ipv4" if port_disallowed => Some("ipv4"),
+            "ipv6" if port_disallowed => Some("ipv6"),
+            "url" | "http_url" | "http_ftp_url" => Some("uri"),
+            "uuid" => match self.get("separator") {
+                Some(SpecValue::Separator(SeparatorOption::Must(b'-'))) => Some("uuid"),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The value of an option of a validator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpecValue {
+    Bool(bool),
+    TriAllow(TriAllow),
+    Case(CaseOption),
+    Separator(SeparatorOption),
+    /// A length limit, in characters or bytes according to the name of the option.
+    Length(Option<usize>),
+    UnsignedRange(RangeOption<u128>),
+    SignedRange(RangeOption<i128>),
+    NumberRange(RangeOption<f64>),
+    Str(&'static str),
+    Strs(&'static [&'static str]),
+}
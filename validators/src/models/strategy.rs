@@ -0,0 +1,699 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use proptest::{
+    arbitrary::any,
+    collection,
+    sample::{select, Index},
+    strategy::{BoxedStrategy, Just, Strategy, Union},
+    string::string_regex,
+};
+
+use super::{Spec, SpecValue, BOOLEAN_STRINGS};
+use crate::options::{CaseOption, RangeOption, SeparatorOption, TriAllow};
+
+/// The characters inserted into valid strings to create near-miss invalid ones.
+const NEAR_MISS_CHARS: [char; 14] =
+    [' ', '\n', '\0', '.', '-', ':', '@', '=', '/', '[', '%', 'Z', 'g', 'é'];
+
+/// The characters of the strings created for the `line` validator with byte lengths.
+const LINE_ASCII_CHARS: [char; 8] = ['a', 'B', 'c', 'X', 'y', '0', '9', ' '];
+/// The characters of the strings created for the `line` validator.
+const LINE_CHARS: [char; 10] = ['a', 'B', 'c', 'X', 'y', '0', '9', ' ', 'é', '中'];
+/// The characters of the strings created for the `text` validator.
+const TEXT_CHARS: [char; 12] = ['a', 'B', 'c', 'X', 'y', '0', '9', ' ', 'é', '中', '\n', '\t'];
+
+const BASE32_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+impl Spec {
+    /// Create a strategy of strings which are expected to be accepted by the validator.
+    ///
+    /// `inner` are the strategies of the strings needed by the `all`, `any` and `not` validators (the strings accepted, or rejected for `not`, by the validators being combined) and the `number`, `signed_integer` and `unsigned_integer` validators (the strings of any value of the field type).
+    ///
+    /// The strings are created from the options of the spec only, so they should still be filtered by the validator itself, which the code generated by the `Validator` derive does.
+    pub fn valid_strings(&self, inner: Vec<BoxedStrategy<String>>) -> BoxedStrategy<String> {
+        match self.validator {
+            "all" | "any" | "not" => union(inner),
+            "base32" | "base32_decoded" => {
+                encoded(BASE32_ALPHABET, 5, 8, self.tri_allow("padding"))
+            },
+            "base64" | "base64_decoded" => {
+                encoded(BASE64_ALPHABET, 6, 4, self.tri_allow("padding"))
+            },
+            "base64_url" | "base64_url_decoded" => {
+                encoded(BASE64_URL_ALPHABET, 6, 4, self.tri_allow("padding"))
+            },
+            "bit" | "byte" => match self.get("range") {
+                Some(SpecValue::UnsignedRange(RangeOption::Inside {
+                    min,
+                    max,
+                    ..
+                })) => (min.unwrap_or(0)..=max.unwrap_or(u64::MAX as u128))
+                    .prop_map(|n| n.to_string())
+                    .boxed(),
+                _ => any::<u64>().prop_map(|n| n.to_string()).boxed(),
+            },
+            "boolean" => select(BOOLEAN_STRINGS.as_slice()).prop_map(String::from).boxed(),
+            "domain" => {
+                let domain = labels(self.tri_allow("at_least_two_labels"));
+
+                let host = match (self.tri_allow("local"), self.tri_allow("ipv4")) {
+                    (TriAllow::Must, _) => Just(String::from("localhost")).boxed(),
+                    (_, TriAllow::Must) => ipv4(TriAllow::Allow),
+                    (_, TriAllow::Allow) => union(vec![domain, ipv4(TriAllow::Allow)]),
+                    (_, TriAllow::Disallow) => domain,
+                };
+
+                with_port(host, self.tri_allow("port"))
+            },
+            "email" => {
+                let local_part = if self.tri_allow("non_ascii").must() {
+                    pattern("[a-z0-9]{1,8}é[a-z0-9]{0,8}")
+                } else {
+                    pattern("[a-z0-9]{1,10}(\\.[a-z0-9]{1,10}){0,2}")
+                };
+
+                let domain_part = match (self.tri_allow("local"), self.tri_allow("ip")) {
+                    (TriAllow::Must, _) => Just(String::from("localhost")).boxed(),
+                    (_, TriAllow::Must) => {
+                        ipv4(TriAllow::Disallow).prop_map(|ip| format!("[{ip}]")).boxed()
+                    },
+                    _ => labels(self.tri_allow("at_least_two_labels")),
+                };
+
+                let comment = if self.tri_allow("comment").must() { "(comment)" } else { "" };
+
+                (local_part, domain_part)
+                    .prop_map(move |(local_part, domain_part)| {
+                        format!("{comment}{local_part}@{domain_part}")
+                    })
+                    .boxed()
+            },
+            "host" => {
+                let local = self.tri_allow("local");
+
+                let host = if local.must() {
+                    union(vec![
+                        Just(String::from("localhost")).boxed(),
+                        ipv4(TriAllow::Must),
+                        ipv6(TriAllow::Must),
+                    ])
+                } else {
+                    union(vec![
+                        labels(self.tri_allow("at_least_two_labels")),
+                        ipv4(local),
+                        ipv6(local),
+                    ])
+                };
+
+                with_port(host, self.tri_allow("port"))
+            },
+            "http_url" | "http_ftp_url" | "url" => {
+                let schemes: &'static [&'static str] = match self.validator {
+                    "http_url" => &["http", "https"],
+                    _ => &["http", "https", "ftp"],
+                };
+
+                let host = if self.tri_allow("local").must() {
+                    select(&["localhost", "127.0.0.1", "192.168.0.1"][..])
+                        .prop_map(String::from)
+                        .boxed()
+                } else {
+                    labels(TriAllow::Must)
+                };
+
+                (select(schemes), host, pattern("(/[a-z0-9]{1,8}){0,3}"))
+                    .prop_map(|(scheme, host, path)| format!("{scheme}://{host}{path}"))
+                    .boxed()
+            },
+            "ip" => {
+                let local = self.tri_allow("local");
+
+                with_port(union(vec![ipv4(local), ipv6(local)]), self.tri_allow("port"))
+            },
+            "ipv4" => with_port(ipv4(self.tri_allow("local")), self.tri_allow("port")),
+            "ipv6" => with_port(ipv6(self.tri_allow("local")), self.tri_allow("port")),
+            "json" => union(vec![
+                any::<i32>().prop_map(|n| n.to_string()).boxed(),
+                any::<bool>().prop_map(|b| b.to_string()).boxed(),
+                pattern("\"[a-zA-Z0-9 ]{0,16}\""),
+                pattern("\\[([0-9]{1,3}(,[0-9]{1,3}){0,4})?\\]"),
+                pattern("\\{(\"[a-z]{1,8}\":[0-9]{1,3})?\\}"),
+                Just(String::from("null")).boxed(),
+            ]),
+            "line" | "text" => {
+                let (unit, min, max) = self.length_limits();
+
+                let chars: &'static [char] = match (unit, self.validator) {
+                    ("byte", _) => &LINE_ASCII_CHARS,
+                    (_, "line") => &LINE_CHARS,
+                    _ => &TEXT_CHARS,
+                };
+
+                collection::vec(select(chars), min..=max)
+                    .prop_map(|mut chars| {
+                        // keep the trimmed length as long as the length
+                        for i in [0, chars.len().saturating_sub(1)] {
+                            if matches!(chars.get(i), Some(c) if c.is_whitespace()) {
+                                chars[i] = 'a';
+                            }
+                        }
+
+                        chars.into_iter().collect()
+                    })
+                    .boxed()
+            },
+            "mac_address" => {
+                hex_groups(6, &[1, 1, 1, 1, 1, 1], self.case("case"), self.separator("separator"))
+            },
+            "number" => {
+                let nan = match self.get("nan") {
+                    Some(SpecValue::TriAllow(nan)) => *nan,
+                    _ => TriAllow::Allow,
+                };
+
+                if nan.must() {
+                    return Just(String::from("NaN")).boxed();
+                }
+
+                match self.get("range") {
+                    Some(SpecValue::NumberRange(RangeOption::Inside {
+                        min: Some(min),
+                        max: Some(max),
+                        ..
+                    })) => {
+                        if min < max {
+                            (*min..*max).prop_map(|n| n.to_string()).boxed()
+                        } else {
+                            Just(min.to_string()).boxed()
+                        }
+                    },
+                    _ => union(inner),
+                }
+            },
+            "path" => {
+                let names = collection::vec(pattern("[a-z0-9_-]{1,12}"), 1..4)
+                    .prop_map(|names| names.join("/"));
+
+                let names = if self.tri_allow("absolute").must() {
+                    names.prop_map(|names| format!("/{names}")).boxed()
+                } else {
+                    names.boxed()
+                };
+
+                match self.get("extensions") {
+                    Some(SpecValue::Strs(extensions)) => (names, select(*extensions))
+                        .prop_map(|(names, extension)| format!("{names}.{extension}"))
+                        .boxed(),
+                    _ => names,
+                }
+            },
+            "phone" => {
+                let countries = match self.get("countries") {
+                    Some(SpecValue::Strs(countries)) => *countries,
+                    _ => &[],
+                };
+
+                let numbers = phone_examples(countries);
+
+                if numbers.is_empty() {
+                    Just(String::new()).boxed()
+                } else {
+                    select(numbers).boxed()
+                }
+            },
+            "regex" => match self.get("regex") {
+                Some(SpecValue::Str(regex)) => {
+                    // anchors are not supported by `string_regex`, but the generated strings are always whole matches
+                    let regex = regex.strip_prefix('^').unwrap_or(regex);
+                    let regex = match regex.strip_suffix('$') {
+                        Some(r) if !r.ends_with('\\') => r,
+                        _ => regex,
+                    };
+
+                    match string_regex(regex) {
+                        Ok(strategy) => strategy.boxed(),
+                        Err(_) => pattern("\\PC{0,32}"),
+                    }
+                },
+                _ => pattern("\\PC{0,32}"),
+            },
+            "semver" => (0..100u32, 0..100u32, 0..100u32, pattern("(-(alpha|beta|rc)\\.[0-9])?"))
+                .prop_map(|(major, minor, patch, pre)| format!("{major}.{minor}.{patch}{pre}"))
+                .boxed(),
+            "semver_req" => (
+                select(&["", "=", "^", "~", ">", ">=", "<", "<="][..]),
+                0..100u32,
+                0..100u32,
+                0..100u32,
+            )
+                .prop_map(|(op, major, minor, patch)| format!("{op}{major}.{minor}.{patch}"))
+                .boxed(),
+            "signed_integer" => match self.get("range") {
+                Some(SpecValue::SignedRange(RangeOption::Inside {
+                    min: Some(min),
+                    max: Some(max),
+                    ..
+                })) if min <= max => (*min..=*max).prop_map(|n| n.to_string()).boxed(),
+                _ => union(inner),
+            },
+            "unsigned_integer" => match self.get("range") {
+                Some(SpecValue::UnsignedRange(RangeOption::Inside {
+                    min: Some(min),
+                    max: Some(max),
+                    ..
+                })) if min <= max => (*min..=*max).prop_map(|n| n.to_string()).boxed(),
+                _ => union(inner),
+            },
+            "uuid" => {
+                hex_groups(16, &[4, 2, 2, 2, 6], self.case("case"), self.separator("separator"))
+            },
+            _ => pattern("\\PC{0,32}"),
+        }
+    }
+
+    /// Create a strategy of strings which are similar to the valid ones but expected to be rejected by the validator.
+    ///
+    /// `valid` are the valid strings, which are slightly changed, and `inner` are the same as the ones of `valid_strings`, except that the `all` and `any` validators need the rejected strings of the validators being combined and the `not` validator needs the accepted strings.
+    ///
+    /// The strings should still be filtered by the validator itself, which the code generated by the `Validator` derive does.
+    pub fn invalid_strings(
+        &self,
+        valid: BoxedStrategy<String>,
+        inner: Vec<BoxedStrategy<String>>,
+    ) -> BoxedStrategy<String> {
+        let mut strategies = vec![near_miss(valid.clone())];
+
+        match self.validator {
+            "all" | "any" | "not" => strategies.extend(inner),
+            "base32" | "base32_decoded" | "base64" | "base64_decoded" | "base64_url"
+            | "base64_url_decoded" => {
+                strategies
+                    .push(valid.clone().prop_map(|s| s.trim_end_matches('=').to_string()).boxed());
+                strategies.push(valid.prop_map(|s| format!("{s}=")).boxed());
+            },
+            "bit" | "byte" | "number" | "signed_integer" | "unsigned_integer" => {
+                strategies.push(self.out_of_range());
+                strategies.push(valid.prop_map(|s| format!("{s}0")).boxed());
+                strategies.push(Just(String::from("NaN")).boxed());
+                strategies.push(Just(String::from("-1")).boxed());
+            },
+            "domain" | "email" | "host" | "http_url" | "http_ftp_url" | "url" | "ip" | "ipv4"
+            | "ipv6" => {
+                strategies.push(valid.clone().prop_map(|s| format!("{s}:8080")).boxed());
+                strategies.push(
+                    valid
+                        .clone()
+                        .prop_map(|s| s.split('.').next().unwrap_or_default().to_string())
+                        .boxed(),
+                );
+                strategies.push(valid.prop_map(|s| s.replacen('.', "..", 1)).boxed());
+                strategies.push(
+                    select(
+                        &[
+                            "localhost",
+                            "127.0.0.1",
+                            "192.168.0.1",
+                            "8.8.8.8",
+                            "::1",
+                            "a-.com",
+                            "a.b",
+                        ][..],
+                    )
+                    .prop_map(String::from)
+                    .boxed(),
+                );
+                strategies.push(Just(format!("{}.com", "a".repeat(64))).boxed());
+            },
+            "line" | "text" => {
+                let (_, min, max) = self.length_limits();
+
+                strategies.push(pattern("[a-z]").prop_map(move |c| c.repeat(max + 1)).boxed());
+
+                if min > 0 {
+                    strategies.push(pattern("[a-z]").prop_map(move |c| c.repeat(min - 1)).boxed());
+                }
+
+                strategies.push(valid.prop_map(|s| format!("{s}\r\n\x7F")).boxed());
+            },
+            "path" => {
+                strategies.push(valid.clone().prop_map(|s| format!("../{s}")).boxed());
+                strategies.push(valid.clone().prop_map(|s| format!("/{s}")).boxed());
+                strategies.push(valid.prop_map(|s| format!("{s}.exe")).boxed());
+                strategies.push(
+                    select(&["", "CON", "nul.txt", "C:\\Windows", "a/../../b", "a\0b"][..])
+                        .prop_map(String::from)
+                        .boxed(),
+                );
+                strategies.push(Just("a".repeat(256)).boxed());
+            },
+            "mac_address" | "uuid" => {
+                strategies.push(valid.clone().prop_map(|s| s.to_lowercase()).boxed());
+                strategies.push(valid.clone().prop_map(|s| s.to_uppercase()).boxed());
+                strategies.push(
+                    valid
+                        .clone()
+                        .prop_map(|s| s.chars().filter(char::is_ascii_hexdigit).collect())
+                        .boxed(),
+                );
+                strategies.push(
+                    valid
+                        .prop_map(|s| {
+                            s.chars().map(|c| if c.is_ascii_hexdigit() { c } else { '_' }).collect()
+                        })
+                        .boxed(),
+                );
+            },
+            "boolean" | "json" | "semver" | "semver_req" => {
+                strategies.push(
+                    select(
+                        &[
+                            "", "tru", "maybe", "2", "{", "[1,", "'a'", "1.2", "01.2.3", "1.2.3-",
+                            ">>1",
+                        ][..],
+                    )
+                    .prop_map(String::from)
+                    .boxed(),
+                );
+            },
+            _ => (),
+        }
+
+        union(strategies)
+    }
+
+    #[inline]
+    fn tri_allow(&self, name: &str) -> TriAllow {
+        match self.get(name) {
+            Some(SpecValue::TriAllow(tri_allow)) => *tri_allow,
+            _ => TriAllow::Allow,
+        }
+    }
+
+    #[inline]
+    fn case(&self, name: &str) -> CaseOption {
+        match self.get(name) {
+            Some(SpecValue::Case(case)) => *case,
+            _ => CaseOption::Any,
+        }
+    }
+
+    #[inline]
+    fn separator(&self, name: &str) -> SeparatorOption {
+        match self.get(name) {
+            Some(SpecValue::Separator(separator)) => *separator,
+            _ => SeparatorOption::Disallow,
+        }
+    }
+
+    /// Get the unit (`"char"` or `"byte"`) and the minimum and maximum lengths of the `line` and `text` validators.
+    fn length_limits(&self) -> (&'static str, usize, usize) {
+        let unit = if self.get("byte_length_max").is_some() { "byte" } else { "char" };
+
+        let min = self.length(&format!("{unit}_length_min")).unwrap_or(0);
+        let max = self.length(&format!("{unit}_length_max")).unwrap_or(min + 32).max(min);
+
+        (unit, min, max)
+    }
+
+    /// Create the strings of the numbers just outside the range, or just inside it for `Outside`.
+    fn out_of_range(&self) -> BoxedStrategy<String> {
+        let mut numbers: Vec<String> = Vec::new();
+
+        match self.get("range") {
+            Some(SpecValue::UnsignedRange(range)) => push_bounds(&mut numbers, range, |n, d| {
+                if d < 0 {
+                    n.checked_sub(1)
+                } else {
+                    n.checked_add(1)
+                }
+            }),
+            Some(SpecValue::SignedRange(range)) => {
+                push_bounds(&mut numbers, range, |n, d| n.checked_add(d as i128))
+            },
+            Some(SpecValue::NumberRange(range)) => push_bounds(&mut numbers, range, |n, d| {
+                Some(n + d as f64 * (n.abs() * 1e-9).max(1e-9))
+            }),
+            _ => (),
+        }
+
+        if numbers.is_empty() {
+            Just(String::from("-")).boxed()
+        } else {
+            select(numbers).boxed()
+        }
+    }
+}
+
+/// Push the bounds of the range and the numbers next to them. `step` moves a number down (`-1`) or up (`1`).
+fn push_bounds<T: Copy + ToString>(
+    numbers: &mut Vec<String>,
+    range: &RangeOption<T>,
+    step: impl Fn(T, i8) -> Option<T>,
+) {
+    let (min, max) = match range {
+        RangeOption::Inside {
+            min,
+            max,
+            ..
+        }
+        | RangeOption::Outside {
+            min,
+            max,
+            ..
+        } => (*min, *max),
+        RangeOption::Unlimited => return,
+    };
+
+    for n in min.into_iter().chain(max) {
+        numbers.push(n.to_string());
+
+        numbers.extend(step(n, -1).map(|n| n.to_string()));
+        numbers.extend(step(n, 1).map(|n| n.to_string()));
+    }
+}
+
+#[inline]
+fn pattern(regex: &str) -> BoxedStrategy<String> {
+    string_regex(regex).expect("the regular expression should be valid").boxed()
+}
+
+/// Choose one of the strategies evenly. An empty string is created if there is no strategy.
+#[inline]
+fn union(strategies: Vec<BoxedStrategy<String>>) -> BoxedStrategy<String> {
+    if strategies.is_empty() {
+        Just(String::new()).boxed()
+    } else {
+        Union::new(strategies).boxed()
+    }
+}
+
+/// Slightly change the strings by inserting, removing or truncating characters.
+fn near_miss(valid: BoxedStrategy<String>) -> BoxedStrategy<String> {
+    (valid, any::<Index>(), select(NEAR_MISS_CHARS.as_slice()), 0..3u8)
+        .prop_map(|(s, index, c, action)| {
+            let mut chars: Vec<char> = s.chars().collect();
+
+            match action {
+                0 => {
+                    let i = index.index(chars.len() + 1);
+
+                    chars.insert(i, c);
+                },
+                1 if !chars.is_empty() => {
+                    chars.remove(index.index(chars.len()));
+                },
+                _ => chars.truncate(chars.len() / 2),
+            }
+
+            chars.into_iter().collect()
+        })
+        .boxed()
+}
+
+/// Create encoded strings of random bytes. `bits` is the number of bits of a character and `block` is the number of characters in a padded block.
+fn encoded(
+    alphabet: &'static [u8],
+    bits: usize,
+    block: usize,
+    padding: TriAllow,
+) -> BoxedStrategy<String> {
+    (collection::vec(any::<u8>(), 1..64), any::<bool>())
+        .prop_map(move |(bytes, pad)| {
+            let mut s = String::new();
+            let mut buffer = 0u32;
+            let mut buffer_bits = 0;
+
+            for byte in bytes {
+                buffer = (buffer << 8) | byte as u32;
+                buffer_bits += 8;
+
+                while buffer_bits >= bits {
+                    buffer_bits -= bits;
+
+                    s.push(
+                        alphabet[((buffer >> buffer_bits) as usize) & ((1 << bits) - 1)] as char,
+                    );
+                }
+            }
+
+            if buffer_bits > 0 {
+                s.push(
+                    alphabet[((buffer << (bits - buffer_bits)) as usize) & ((1 << bits) - 1)]
+                        as char,
+                );
+            }
+
+            let pad = match padding {
+                TriAllow::Must => true,
+                TriAllow::Allow => pad,
+                TriAllow::Disallow => false,
+            };
+
+            if pad {
+                while s.len() % block != 0 {
+                    s.push('=');
+                }
+            }
+
+            s
+        })
+        .boxed()
+}
+
+/// Create strings of random bytes in hexadecimal. `groups` are the numbers of bytes between separators.
+fn hex_groups(
+    length: usize,
+    groups: &'static [usize],
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> BoxedStrategy<String> {
+    (collection::vec(any::<u8>(), length), any::<bool>(), any::<bool>())
+        .prop_map(move |(bytes, upper, separate)| {
+            let upper = match case {
+                CaseOption::Any => upper,
+                CaseOption::Upper => true,
+                CaseOption::Lower => false,
+            };
+
+            let separator = match separator {
+                SeparatorOption::Must(c) => Some(c as char),
+                SeparatorOption::Allow(c) if separate => Some(c as char),
+                _ => None,
+            };
+
+            let mut s = String::new();
+            let mut bytes = bytes.into_iter();
+
+            for (i, group) in groups.iter().enumerate() {
+                if i > 0 {
+                    s.extend(separator);
+                }
+
+                for byte in bytes.by_ref().take(*group) {
+                    if upper {
+                        s.push_str(&format!("{byte:02X}"));
+                    } else {
+                        s.push_str(&format!("{byte:02x}"));
+                    }
+                }
+            }
+
+            s
+        })
+        .boxed()
+}
+
+/// Create domains whose labels consist of alphanumerics and hyphens. The last label of a domain with more than one label consists of letters only.
+fn labels(at_least_two_labels: TriAllow) -> BoxedStrategy<String> {
+    let count = match at_least_two_labels {
+        TriAllow::Must => 1..4usize,
+        TriAllow::Allow => 0..4usize,
+        TriAllow::Disallow => 0..1usize,
+    };
+
+    (collection::vec(pattern("[a-z0-9]([a-z0-9-]{0,8}[a-z0-9])?"), count), pattern("[a-z]{2,6}"))
+        .prop_map(|(mut labels, top)| {
+            labels.push(top);
+
+            labels.join(".")
+        })
+        .boxed()
+}
+
+fn ipv4(local: TriAllow) -> BoxedStrategy<String> {
+    if local.must() {
+        (select(&[10u8, 127][..]), any::<[u8; 3]>())
+            .prop_map(|(first, [b, c, d])| Ipv4Addr::new(first, b, c, d).to_string())
+            .boxed()
+    } else {
+        any::<Ipv4Addr>().prop_map(|ip| ip.to_string()).boxed()
+    }
+}
+
+fn ipv6(local: TriAllow) -> BoxedStrategy<String> {
+    if local.must() {
+        any::<[u16; 4]>()
+            .prop_map(|[e, f, g, h]| Ipv6Addr::new(0xFE80, 0, 0, 0, e, f, g, h).to_string())
+            .boxed()
+    } else {
+        any::<Ipv6Addr>().prop_map(|ip| ip.to_string()).boxed()
+    }
+}
+
+/// Append a port to the hosts. IPv6 addresses are enclosed in brackets if a port is appended.
+fn with_port(host: BoxedStrategy<String>, port: TriAllow) -> BoxedStrategy<String> {
+    (host, 1..=u16::MAX, any::<bool>())
+        .prop_map(move |(host, p, append)| {
+            let append = match port {
+                TriAllow::Must => true,
+                TriAllow::Allow => append,
+                TriAllow::Disallow => false,
+            };
+
+            if append {
+                if host.contains(':') {
+                    format!("[{host}]:{p}")
+                } else {
+                    format!("{host}:{p}")
+                }
+            } else {
+                host
+            }
+        })
+        .boxed()
+}
+
+/// Collect the example numbers of the countries from the metadata of `phonenumber` in the international format. All countries are used if `countries` is empty.
+#[cfg(feature = "phone")]
+fn phone_examples(countries: &[&str]) -> Vec<String> {
+    use phonenumber::metadata::DATABASE;
+
+    DATABASE
+        .iter()
+        .filter(|metadata| countries.is_empty() || countries.contains(&metadata.id()))
+        .flat_map(|metadata| {
+            let descriptors = metadata.descriptors();
+
+            [descriptors.fixed_line(), descriptors.mobile()]
+                .into_iter()
+                .flatten()
+                .filter_map(|descriptor| descriptor.example())
+                .map(move |example| format!("+{} {example}", metadata.country_code()))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "phone"))]
+#[inline]
+fn phone_examples(_countries: &[&str]) -> Vec<String> {
+    Vec::new()
+}
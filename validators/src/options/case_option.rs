@@ -1,3 +1,4 @@
+/// The letter case of the `mac_address` and `uuid` validators, such as `case(Upper)`.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CaseOption {
     Any,
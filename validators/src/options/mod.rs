@@ -0,0 +1,13 @@
+mod case_option;
+#[cfg(feature = "normalize")]
+mod normalize_option;
+mod range_option;
+mod separator_option;
+mod tri_allow;
+
+pub use case_option::*;
+#[cfg(feature = "normalize")]
+pub use normalize_option::*;
+pub use range_option::*;
+pub use separator_option::*;
+pub use tri_allow::*;
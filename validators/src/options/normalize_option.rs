@@ -0,0 +1,18 @@
+/// The Unicode normalization form of the `normalize` parameter, such as `normalize(nfc)`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NormalizationForm {
+    NFC,
+    NFKC,
+}
+
+/// The normalization applied to strings before they are validated, such as `normalize(trim, lowercase)`.
+///
+/// The steps run in the order of `strip_zero_width`, `form`, `trim`, `collapse_whitespace` and `lowercase`, no matter how the parameter is written.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct NormalizeOption {
+    pub trim:                bool,
+    pub collapse_whitespace: bool,
+    pub lowercase:           bool,
+    pub form:                Option<NormalizationForm>,
+    pub strip_zero_width:    bool,
+}
@@ -1,3 +1,4 @@
+/// The range of numeric validators, such as `range(Inside(min = 1, max = 10))`.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum RangeOption<T> {
     Inside { min: Option<T>, max: Option<T>, inclusive: bool },
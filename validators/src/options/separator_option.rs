@@ -1,3 +1,4 @@
+/// The separator of the `mac_address` and `uuid` validators, such as `separator(Allow(b'-'))`.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum SeparatorOption {
     Must(u8),
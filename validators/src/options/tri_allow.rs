@@ -1,3 +1,4 @@
+/// Whether a feature must be, may be or must not be present, such as `local(Disallow)` of the `email` validator.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TriAllow {
     Must,
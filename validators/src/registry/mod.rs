@@ -0,0 +1,147 @@
+use alloc::{
+    boxed::Box,
+    collections::{btree_map, BTreeMap},
+    string::String,
+};
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use crate::{
+    errors::DynValidatorError,
+    traits::{DynError, DynValidator, ValidateString},
+};
+
+/// A boxed `DynValidator` which can be shared between threads.
+pub type BoxedValidator = Box<dyn DynValidator + Send + Sync>;
+
+/// Validators looked up by their names, for validating input whose types are only known at runtime.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "ipv4", feature = "uuid"))]
+/// # {
+/// use std::net::Ipv4Addr;
+///
+/// use validators::{prelude::*, registry::Registry};
+///
+/// #[derive(Validator)]
+/// #[validator(uuid(case(Any), separator(Allow(b'-'))))]
+/// pub struct Uuid(pub u128);
+///
+/// #[derive(Validator)]
+/// #[validator(ipv4(local(Allow), port(Disallow)))]
+/// pub struct Ipv4(pub Ipv4Addr);
+///
+/// let mut registry = Registry::new();
+///
+/// registry.register::<Uuid, _>("uuid");
+/// registry.register::<Ipv4, _>("ipv4");
+///
+/// assert!(registry.validate_str("ipv4", "127.0.0.1").is_ok());
+/// assert_eq!(
+///     "uuid.invalid",
+///     registry.validate_str("uuid", "127.0.0.1").unwrap_err().code()
+/// );
+/// assert_eq!(
+///     "dyn_validator.unknown_validator",
+///     registry.validate_str("email", "len@magiclen.org").unwrap_err().code()
+/// );
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    validators: BTreeMap<String, BoxedValidator>,
+}
+
+impl Debug for Registry {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.debug_set().entries(self.validators.keys()).finish()
+    }
+}
+
+impl Registry {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a type which implements `ValidateString`, such as a struct with the `#[derive(Validator)]` attribute. The validator previously registered with the name is returned.
+    #[inline]
+    pub fn register<T: ValidateString + Send + Sync + 'static, N: Into<String>>(
+        &mut self,
+        name: N,
+    ) -> Option<BoxedValidator>
+    where
+        T::Error: DynError + 'static, {
+        self.insert(name, PhantomData::<T>)
+    }
+
+    /// Register a validator, such as one in the `validators::runtime` module. The validator previously registered with the name is returned.
+    #[inline]
+    pub fn insert<N: Into<String>, V: DynValidator + Send + Sync + 'static>(
+        &mut self,
+        name: N,
+        validator: V,
+    ) -> Option<BoxedValidator> {
+        self.validators.insert(name.into(), Box::new(validator))
+    }
+
+    #[inline]
+    pub fn remove<N: AsRef<str>>(&mut self, name: N) -> Option<BoxedValidator> {
+        self.validators.remove(name.as_ref())
+    }
+
+    #[inline]
+    pub fn get<N: AsRef<str>>(&self, name: N) -> Option<&(dyn DynValidator + Send + Sync)> {
+        self.validators.get(name.as_ref()).map(|validator| validator.as_ref())
+    }
+
+    #[inline]
+    pub fn contains<N: AsRef<str>>(&self, name: N) -> bool {
+        self.validators.contains_key(name.as_ref())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    #[inline]
+    pub fn names(&self) -> btree_map::Keys<'_, String, BoxedValidator> {
+        self.validators.keys()
+    }
+
+    /// Validate a string with the validator registered with `name`. If there is no such validator, `DynValidatorError::UnknownValidator` is returned.
+    #[inline]
+    pub fn validate_str<N: AsRef<str>>(&self, name: N, s: &str) -> Result<(), Box<dyn DynError>> {
+        self.lookup(name.as_ref())?.validate_str(s)
+    }
+
+    /// Validate a `Value` with the validator registered with `name`. If there is no such validator, `DynValidatorError::UnknownValidator` is returned.
+    #[cfg(feature = "serde_json")]
+    #[inline]
+    pub fn validate_json_value<N: AsRef<str>>(
+        &self,
+        name: N,
+        v: &Value,
+    ) -> Result<(), Box<dyn DynError>> {
+        self.lookup(name.as_ref())?.validate_json_value(v)
+    }
+
+    #[inline]
+    fn lookup(&self, name: &str) -> Result<&(dyn DynValidator + Send + Sync), Box<dyn DynError>> {
+        self.get(name).ok_or_else(|| {
+            Box::new(DynValidatorError::UnknownValidator(String::from(name))) as Box<dyn DynError>
+        })
+    }
+}
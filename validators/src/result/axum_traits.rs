@@ -0,0 +1,27 @@
+use core::marker::PhantomData;
+
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+use super::Result;
+use crate::{
+    traits::ValidateString,
+    web::{path_parameter, Rejection},
+};
+
+impl<S, T, E> FromRequestParts<S> for Result<T, E>
+where
+    S: Send + Sync,
+    T: ValidateString<Error = E>,
+{
+    type Rejection = Rejection;
+
+    #[inline]
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> core::result::Result<Self, Self::Rejection> {
+        let v = path_parameter(parts, state).await?;
+
+        Ok(Self(T::parse_string(v), PhantomData))
+    }
+}
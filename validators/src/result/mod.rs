@@ -1,3 +1,5 @@
+#[cfg(feature = "axum")]
+mod axum_traits;
 mod built_in_traits;
 #[cfg(feature = "rocket")]
 mod rocket_traits;
@@ -10,6 +12,7 @@ use core::marker::PhantomData;
 ///
 /// * This struct uses the `FromParam` trait to implement the `FromFormField` trait (only impl the `from_value` method), allowing it to serve as the error type for subsequent checks.
 /// * This struct implements the `Deserialize` trait, allowing it to serve as the error type for subsequent checks.
+/// * This struct implements the `FromRequestParts` trait of `axum` by parsing the only path parameter of the route, allowing it to serve as the error type for subsequent checks.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Result<T, E, K = ()>(core::result::Result<T, E>, PhantomData<K>);
 
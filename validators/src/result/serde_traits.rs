@@ -874,6 +874,41 @@ impl<'de, T: ValidateString<Error = NumberError> + ValidateNumber<Error = Number
     }
 }
 
+#[cfg(feature = "path")]
+impl<'de, T: ValidateString<Error = PathError>> serde::Deserialize<'de> for Result<T, PathError> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: ValidateString<Error = PathError>> Visitor<'de> for MyVisitor<T> {
+            type Value = Result<T, PathError>;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("PathError")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> core::result::Result<Self::Value, E>
+            where
+                E: DeError, {
+                Ok(Result::new(T::parse_str(v)))
+            }
+
+            #[inline]
+            fn visit_string<E>(self, v: String) -> core::result::Result<Self::Value, E>
+            where
+                E: DeError, {
+                Ok(Result::new(T::parse_string(v)))
+            }
+        }
+
+        deserializer.deserialize_string(MyVisitor(PhantomData))
+    }
+}
+
 #[cfg(feature = "phone")]
 impl<'de, T: ValidateString<Error = PhoneError>> serde::Deserialize<'de> for Result<T, PhoneError> {
     #[inline]
@@ -0,0 +1,165 @@
+use alloc::string::String;
+
+use crate::{
+    errors::{EmailError, OptionsError},
+    functions::{parse_email, EmailParts},
+    models::Host,
+    options::TriAllow,
+};
+
+/// An email address parsed by `EmailValidator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email {
+    pub local_part:                 String,
+    pub need_quoted:                bool,
+    pub domain_part:                Host,
+    pub comment_before_local_part:  Option<String>,
+    pub comment_after_local_part:   Option<String>,
+    pub comment_before_domain_part: Option<String>,
+    pub comment_after_domain_part:  Option<String>,
+    pub is_local:                   bool,
+}
+
+/// The runtime version of the `email` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::EmailError, options::TriAllow, runtime::EmailValidator,
+/// };
+///
+/// let validator =
+///     EmailValidator::builder().local(TriAllow::Disallow).build().unwrap();
+///
+/// assert!(validator.parse_str("len@magiclen.org").is_ok());
+/// assert!(matches!(
+///     validator.parse_str("len@localhost"),
+///     Err(EmailError::LocalDisallow)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmailValidator {
+    comment:             TriAllow,
+    ip:                  TriAllow,
+    local:               TriAllow,
+    at_least_two_labels: TriAllow,
+    non_ascii:           TriAllow,
+}
+
+/// The builder of `EmailValidator`. Every option is `Allow` by default, the same as the `email` attribute. `comment` and `non_ascii` (which applies to the local part) can only be `Allow` or `Disallow`.
+#[derive(Debug, Clone)]
+pub struct EmailValidatorBuilder {
+    validator: EmailValidator,
+}
+
+impl EmailValidator {
+    #[inline]
+    pub const fn builder() -> EmailValidatorBuilder {
+        EmailValidatorBuilder {
+            validator: EmailValidator {
+                comment:             TriAllow::Allow,
+                ip:                  TriAllow::Allow,
+                local:               TriAllow::Allow,
+                at_least_two_labels: TriAllow::Allow,
+                non_ascii:           TriAllow::Allow,
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<Email, EmailError> {
+        let EmailParts {
+            local_part,
+            need_quoted,
+            domain_part,
+            comment_before_local_part,
+            comment_after_local_part,
+            comment_before_domain_part,
+            comment_after_domain_part,
+            is_local,
+        } = parse_email(
+            s.as_ref(),
+            self.comment,
+            self.ip,
+            self.local,
+            self.at_least_two_labels,
+            self.non_ascii,
+        )?;
+
+        Ok(Email {
+            local_part,
+            need_quoted,
+            domain_part,
+            comment_before_local_part,
+            comment_after_local_part,
+            comment_before_domain_part,
+            comment_after_domain_part,
+            is_local,
+        })
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), EmailError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
+
+impl EmailValidatorBuilder {
+    #[inline]
+    pub const fn comment(mut self, comment: TriAllow) -> Self {
+        self.validator.comment = comment;
+
+        self
+    }
+
+    #[inline]
+    pub const fn ip(mut self, ip: TriAllow) -> Self {
+        self.validator.ip = ip;
+
+        self
+    }
+
+    #[inline]
+    pub const fn local(mut self, local: TriAllow) -> Self {
+        self.validator.local = local;
+
+        self
+    }
+
+    #[inline]
+    pub const fn at_least_two_labels(mut self, at_least_two_labels: TriAllow) -> Self {
+        self.validator.at_least_two_labels = at_least_two_labels;
+
+        self
+    }
+
+    #[inline]
+    pub const fn non_ascii(mut self, non_ascii: TriAllow) -> Self {
+        self.validator.non_ascii = non_ascii;
+
+        self
+    }
+
+    /// Build the validator. `comment` and `non_ascii` cannot be `Must`, and `ip(Must)` and `at_least_two_labels(Disallow)` cannot be used together, the same as the `email` attribute.
+    #[inline]
+    pub const fn build(self) -> Result<EmailValidator, OptionsError> {
+        if self.validator.comment.must() {
+            return Err(OptionsError::MustUnsupported {
+                option: "comment"
+            });
+        }
+
+        if self.validator.non_ascii.must() {
+            return Err(OptionsError::MustUnsupported {
+                option: "non_ascii"
+            });
+        }
+
+        if self.validator.ip.must() && self.validator.at_least_two_labels.disallow() {
+            return Err(OptionsError::IPMustAtLeastTwoLabelsDisallow);
+        }
+
+        Ok(self.validator)
+    }
+}
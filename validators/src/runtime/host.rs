@@ -0,0 +1,107 @@
+use crate::{
+    errors::{HostError, OptionsError},
+    functions::parse_host,
+    models::Host,
+    options::TriAllow,
+};
+
+/// A host parsed by `HostValidator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostWithPort {
+    pub host:     Host,
+    pub port:     Option<u16>,
+    pub is_local: bool,
+}
+
+/// The runtime version of the `host` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::HostError, options::TriAllow, runtime::HostValidator,
+/// };
+///
+/// let validator =
+///     HostValidator::builder().port(TriAllow::Must).build().unwrap();
+///
+/// assert_eq!(
+///     Some(8080),
+///     validator.parse_str("magiclen.org:8080").unwrap().port
+/// );
+/// assert!(matches!(
+///     validator.parse_str("magiclen.org"),
+///     Err(HostError::PortMust)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HostValidator {
+    local:               TriAllow,
+    port:                TriAllow,
+    at_least_two_labels: TriAllow,
+}
+
+/// The builder of `HostValidator`. Every option is `Allow` by default, the same as the `host` attribute.
+#[derive(Debug, Clone)]
+pub struct HostValidatorBuilder {
+    validator: HostValidator,
+}
+
+impl HostValidator {
+    #[inline]
+    pub const fn builder() -> HostValidatorBuilder {
+        HostValidatorBuilder {
+            validator: HostValidator {
+                local:               TriAllow::Allow,
+                port:                TriAllow::Allow,
+                at_least_two_labels: TriAllow::Allow,
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<HostWithPort, HostError> {
+        let (host, port, is_local) =
+            parse_host(s.as_ref(), self.local, self.port, self.at_least_two_labels)?;
+
+        Ok(HostWithPort {
+            host,
+            port,
+            is_local,
+        })
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), HostError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
+
+impl HostValidatorBuilder {
+    #[inline]
+    pub const fn local(mut self, local: TriAllow) -> Self {
+        self.validator.local = local;
+
+        self
+    }
+
+    #[inline]
+    pub const fn port(mut self, port: TriAllow) -> Self {
+        self.validator.port = port;
+
+        self
+    }
+
+    #[inline]
+    pub const fn at_least_two_labels(mut self, at_least_two_labels: TriAllow) -> Self {
+        self.validator.at_least_two_labels = at_least_two_labels;
+
+        self
+    }
+
+    /// Build the validator. Every combination of options is accepted, the same as the `host` attribute.
+    #[inline]
+    pub const fn build(self) -> Result<HostValidator, OptionsError> {
+        Ok(self.validator)
+    }
+}
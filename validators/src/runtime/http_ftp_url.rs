@@ -0,0 +1,125 @@
+use crate::{
+    errors::{HttpFtpURLError, OptionsError},
+    functions::{is_local_domain, is_local_ipv4, is_local_ipv6},
+    models::Protocol,
+    options::TriAllow,
+    prelude::*,
+    url::{self, Url},
+};
+
+#[derive(Validator)]
+#[validator(http_ftp_url(local(Allow)))]
+struct AnyHttpFtpUrl {
+    url:      Url,
+    protocol: Protocol,
+}
+
+/// A URL parsed by `HttpFtpUrlValidator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpFtpUrl {
+    pub url:      Url,
+    pub protocol: Protocol,
+    pub is_local: bool,
+}
+
+/// The runtime version of the `http_ftp_url` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::{HttpFtpURLError, OptionsError},
+///     models::Protocol,
+///     options::TriAllow,
+///     runtime::HttpFtpUrlValidator,
+/// };
+///
+/// let validator = HttpFtpUrlValidator::builder()
+///     .local(TriAllow::Disallow)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     Protocol::FTP,
+///     validator.parse_str("ftp://magiclen.org").unwrap().protocol
+/// );
+/// assert!(matches!(
+///     validator.parse_str("http://127.0.0.1"),
+///     Err(HttpFtpURLError::LocalDisallow)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpFtpUrlValidator {
+    local: TriAllow,
+}
+
+/// The builder of `HttpFtpUrlValidator`. Every option is `Allow` by default, the same as the `http_ftp_url` attribute.
+#[derive(Debug, Clone)]
+pub struct HttpFtpUrlValidatorBuilder {
+    validator: HttpFtpUrlValidator,
+}
+
+impl HttpFtpUrlValidator {
+    #[inline]
+    pub const fn builder() -> HttpFtpUrlValidatorBuilder {
+        HttpFtpUrlValidatorBuilder {
+            validator: HttpFtpUrlValidator {
+                local: TriAllow::Allow
+            },
+        }
+    }
+
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<HttpFtpUrl, HttpFtpURLError> {
+        let AnyHttpFtpUrl {
+            url,
+            protocol,
+        } = AnyHttpFtpUrl::parse_str(s)?;
+
+        let is_local = match url.host() {
+            Some(url::Host::Domain(domain)) => is_local_domain(domain),
+            Some(url::Host::Ipv4(ip)) => is_local_ipv4(ip),
+            Some(url::Host::Ipv6(ip)) => is_local_ipv6(ip),
+            None => false,
+        };
+
+        match self.local {
+            TriAllow::Allow => (),
+            TriAllow::Must => {
+                if !is_local {
+                    return Err(HttpFtpURLError::LocalMust);
+                }
+            },
+            TriAllow::Disallow => {
+                if is_local {
+                    return Err(HttpFtpURLError::LocalDisallow);
+                }
+            },
+        }
+
+        Ok(HttpFtpUrl {
+            url,
+            protocol,
+            is_local,
+        })
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), HttpFtpURLError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
+
+impl HttpFtpUrlValidatorBuilder {
+    #[inline]
+    pub const fn local(mut self, local: TriAllow) -> Self {
+        self.validator.local = local;
+
+        self
+    }
+
+    /// Build the validator. Every combination of options is accepted, the same as the `http_ftp_url` attribute.
+    #[inline]
+    pub const fn build(self) -> Result<HttpFtpUrlValidator, OptionsError> {
+        Ok(self.validator)
+    }
+}
@@ -0,0 +1,105 @@
+use alloc::string::String;
+
+use super::LengthOption;
+use crate::{
+    errors::{LengthUnit, LineError, OptionsError},
+    functions::validate_line,
+};
+
+/// The runtime version of the `line` validator.
+///
+/// ```rust
+/// use validators::{errors::LineError, runtime::LineValidator};
+///
+/// let validator = LineValidator::builder().min(3).max(32).build().unwrap();
+///
+/// assert_eq!("magiclen", validator.parse_str("magiclen").unwrap());
+/// assert!(matches!(
+///     validator.parse_str("ml"),
+///     Err(LineError::TooShort { .. })
+/// ));
+/// assert!(matches!(
+///     validator.parse_str("magic\nlen"),
+///     Err(LineError::Invalid)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineValidator {
+    length: LengthOption,
+}
+
+/// The builder of `LineValidator`. By default, the length is unlimited and measured in characters, the same as the `line` attribute.
+#[derive(Debug, Clone)]
+pub struct LineValidatorBuilder {
+    validator: LineValidator,
+}
+
+impl LineValidator {
+    #[inline]
+    pub const fn builder() -> LineValidatorBuilder {
+        LineValidatorBuilder {
+            validator: LineValidator {
+                length: LengthOption::new()
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: Into<String>>(&self, s: S) -> Result<String, LineError> {
+        let s = s.into();
+
+        self.validate_str(&s)?;
+
+        Ok(s)
+    }
+
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), LineError> {
+        let s = s.as_ref();
+
+        validate_line(
+            s,
+            self.length.unit,
+            self.length.min,
+            self.length.trimmed_min,
+            self.length.max,
+        )
+    }
+}
+
+impl LineValidatorBuilder {
+    #[inline]
+    pub const fn unit(mut self, unit: LengthUnit) -> Self {
+        self.validator.length.unit = unit;
+
+        self
+    }
+
+    #[inline]
+    pub const fn min(mut self, min: usize) -> Self {
+        self.validator.length.min = Some(min);
+
+        self
+    }
+
+    #[inline]
+    pub const fn trimmed_min(mut self, trimmed_min: usize) -> Self {
+        self.validator.length.trimmed_min = Some(trimmed_min);
+
+        self
+    }
+
+    #[inline]
+    pub const fn max(mut self, max: usize) -> Self {
+        self.validator.length.max = Some(max);
+
+        self
+    }
+
+    /// Build the validator. The bounds of the length are checked in the same way as the `line` attribute.
+    #[inline]
+    pub fn build(self) -> Result<LineValidator, OptionsError> {
+        self.validator.length.check()?;
+
+        Ok(self.validator)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    errors::{MacAddressError, OptionsError},
+    functions::parse_mac_address,
+    options::{CaseOption, SeparatorOption},
+};
+
+/// The runtime version of the `mac_address` validator. A MAC address is parsed into its 48-bit value.
+///
+/// ```rust
+/// use validators::{
+///     errors::MacAddressError,
+///     options::{CaseOption, SeparatorOption},
+///     runtime::MacAddressValidator,
+/// };
+///
+/// let validator = MacAddressValidator::builder()
+///     .case(CaseOption::Upper)
+///     .separator(SeparatorOption::Disallow)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(0x08002B010203, validator.parse_str("08002B010203").unwrap());
+/// assert!(matches!(
+///     validator.parse_str("08:00:2B:01:02:03"),
+///     Err(MacAddressError::SeparatorDisallow)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MacAddressValidator {
+    case:      CaseOption,
+    separator: SeparatorOption,
+}
+
+/// The builder of `MacAddressValidator`. The options are `case(Any)` and `separator(Allow(b':'))` by default, the same as the `mac_address` attribute.
+#[derive(Debug, Clone)]
+pub struct MacAddressValidatorBuilder {
+    validator: MacAddressValidator,
+}
+
+impl MacAddressValidator {
+    #[inline]
+    pub const fn builder() -> MacAddressValidatorBuilder {
+        MacAddressValidatorBuilder {
+            validator: MacAddressValidator {
+                case:      CaseOption::Any,
+                separator: SeparatorOption::Allow(b':'),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<u64, MacAddressError> {
+        parse_mac_address(s.as_ref(), self.case, self.separator)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), MacAddressError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
+
+impl MacAddressValidatorBuilder {
+    #[inline]
+    pub const fn case(mut self, case: CaseOption) -> Self {
+        self.validator.case = case;
+
+        self
+    }
+
+    #[inline]
+    pub const fn separator(mut self, separator: SeparatorOption) -> Self {
+        self.validator.separator = separator;
+
+        self
+    }
+
+    /// Build the validator. Every combination of options is accepted, the same as the `mac_address` attribute.
+    #[inline]
+    pub const fn build(self) -> Result<MacAddressValidator, OptionsError> {
+        Ok(self.validator)
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(all(feature = "derive", feature = "email"))]
+mod email;
+#[cfg(all(feature = "derive", feature = "email"))]
+pub use email::*;
+
+#[cfg(all(feature = "derive", feature = "host"))]
+mod host;
+#[cfg(all(feature = "derive", feature = "host"))]
+pub use host::*;
+
+#[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+mod http_ftp_url;
+#[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+pub use http_ftp_url::*;
+
+#[cfg(feature = "line")]
+mod line;
+#[cfg(feature = "line")]
+pub use line::*;
+
+#[cfg(feature = "mac_address")]
+mod mac_address;
+#[cfg(feature = "mac_address")]
+pub use self::mac_address::*;
+
+#[cfg(feature = "number")]
+mod number;
+#[cfg(feature = "number")]
+pub use number::*;
+
+#[cfg(feature = "signed_integer")]
+mod signed_integer;
+#[cfg(feature = "signed_integer")]
+pub use signed_integer::*;
+
+#[cfg(feature = "text")]
+mod text;
+#[cfg(feature = "text")]
+pub use text::*;
+
+#[cfg(feature = "unsigned_integer")]
+mod unsigned_integer;
+#[cfg(feature = "unsigned_integer")]
+pub use unsigned_integer::*;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "uuid")]
+pub use self::uuid::*;
+#[allow(unused_imports)]
+use crate::errors::OptionsError;
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+use crate::options::RangeOption;
+#[allow(unused_imports)]
+use crate::traits::{DynError, DynValidator};
+
+macro_rules! impl_dyn_validator {
+    ($($(#[$attr:meta])* $t:ident,)*) => {
+        $(
+            $(#[$attr])*
+            impl DynValidator for $t {
+                #[inline]
+                fn validate_str(&self, s: &str) -> Result<(), alloc::boxed::Box<dyn DynError>> {
+                    $t::validate_str(self, s)
+                        .map_err(|error| alloc::boxed::Box::new(error) as alloc::boxed::Box<dyn DynError>)
+                }
+            }
+        )*
+    };
+}
+
+impl_dyn_validator! {
+    #[cfg(all(feature = "derive", feature = "email"))]
+    EmailValidator,
+    #[cfg(all(feature = "derive", feature = "host"))]
+    HostValidator,
+    #[cfg(all(feature = "derive", feature = "http_ftp_url"))]
+    HttpFtpUrlValidator,
+    #[cfg(feature = "line")]
+    LineValidator,
+    #[cfg(feature = "mac_address")]
+    MacAddressValidator,
+    #[cfg(feature = "number")]
+    NumberValidator,
+    #[cfg(feature = "signed_integer")]
+    SignedIntegerValidator,
+    #[cfg(feature = "text")]
+    TextValidator,
+    #[cfg(feature = "unsigned_integer")]
+    UnsignedIntegerValidator,
+    #[cfg(feature = "uuid")]
+    UuidValidator,
+}
+
+/// Check the bounds of a `RangeOption` in the same way as the `range` parameter is checked at compile time.
+#[cfg(any(feature = "number", feature = "signed_integer", feature = "unsigned_integer"))]
+fn check_range_option<T: PartialOrd>(range: &RangeOption<T>) -> Result<(), OptionsError> {
+    if let RangeOption::Inside {
+        min: Some(min),
+        max: Some(max),
+        inclusive,
+    }
+    | RangeOption::Outside {
+        min: Some(min),
+        max: Some(max),
+        inclusive,
+    } = range
+    {
+        if if *inclusive { min > max } else { min >= max } {
+            return Err(OptionsError::RangeMinMax {
+                inclusive: *inclusive
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(any(feature = "line", feature = "text"))]
+use crate::errors::LengthUnit;
+
+/// The `char_length` or `byte_length` parameter.
+#[cfg(any(feature = "line", feature = "text"))]
+#[derive(Debug, Clone)]
+struct LengthOption {
+    unit:        LengthUnit,
+    min:         Option<usize>,
+    trimmed_min: Option<usize>,
+    max:         Option<usize>,
+}
+
+#[cfg(any(feature = "line", feature = "text"))]
+impl LengthOption {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            unit:        LengthUnit::Char,
+            min:         None,
+            trimmed_min: None,
+            max:         None,
+        }
+    }
+
+    /// Check the bounds in the same way as the `char_length` and `byte_length` parameters are checked at compile time.
+    fn check(&self) -> Result<(), OptionsError> {
+        if let (Some(trimmed_min), Some(min)) = (self.trimmed_min, self.min) {
+            if trimmed_min > min {
+                return Err(OptionsError::TrimmedMinGreaterThanMin {
+                    trimmed_min,
+                    min,
+                });
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return Err(OptionsError::MinGreaterThanMax {
+                    min,
+                    max,
+                });
+            }
+        }
+
+        if let (Some(trimmed_min), Some(max)) = (self.trimmed_min, self.max) {
+            if trimmed_min > max {
+                return Err(OptionsError::TrimmedMinGreaterThanMax {
+                    trimmed_min,
+                    max,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
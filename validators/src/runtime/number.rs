@@ -0,0 +1,138 @@
+use super::check_range_option;
+use crate::{
+    errors::{NumberError, OptionsError},
+    functions::check_range,
+    options::{RangeOption, TriAllow},
+};
+
+/// The runtime version of the `number` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::{NumberError, OptionsError},
+///     options::{RangeOption, TriAllow},
+///     runtime::NumberValidator,
+/// };
+///
+/// let validator = NumberValidator::builder()
+///     .nan(TriAllow::Disallow)
+///     .range(RangeOption::Inside {
+///         min:       Some(0.0),
+///         max:       Some(1.0),
+///         inclusive: true,
+///     })
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(0.5, validator.parse_str("0.5").unwrap());
+/// assert!(matches!(
+///     validator.parse_str("2"),
+///     Err(NumberError::TooLarge { .. })
+/// ));
+/// assert!(matches!(
+///     validator.parse_f64(f64::NAN),
+///     Err(NumberError::NaNDisallow)
+/// ));
+///
+/// assert_eq!(
+///     Some(OptionsError::NaNMustRangeInside),
+///     NumberValidator::builder()
+///         .nan(TriAllow::Must)
+///         .range(RangeOption::Inside {
+///             min:       Some(0.0),
+///             max:       None,
+///             inclusive: true,
+///         })
+///         .build()
+///         .err()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct NumberValidator {
+    nan:   TriAllow,
+    range: RangeOption<f64>,
+}
+
+/// The builder of `NumberValidator`. By default, `nan = Allow` and `range = Unlimited`, the same as the `number` attribute.
+#[derive(Debug, Clone)]
+pub struct NumberValidatorBuilder {
+    validator: NumberValidator,
+}
+
+impl NumberValidator {
+    #[inline]
+    pub const fn builder() -> NumberValidatorBuilder {
+        NumberValidatorBuilder {
+            validator: NumberValidator {
+                nan: TriAllow::Allow, range: RangeOption::Unlimited
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<f64, NumberError> {
+        self.parse_f64(s.as_ref().parse()?)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), NumberError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+
+    pub fn parse_f64(&self, f: f64) -> Result<f64, NumberError> {
+        check_range(&self.range, f)?;
+
+        match self.nan {
+            TriAllow::Allow => (),
+            TriAllow::Must => {
+                if !f.is_nan() {
+                    return Err(NumberError::NaNMust);
+                }
+            },
+            TriAllow::Disallow => {
+                if f.is_nan() {
+                    return Err(NumberError::NaNDisallow);
+                }
+            },
+        }
+
+        Ok(f)
+    }
+
+    #[inline]
+    pub fn validate_f64(&self, f: f64) -> Result<(), NumberError> {
+        self.parse_f64(f)?;
+
+        Ok(())
+    }
+}
+
+impl NumberValidatorBuilder {
+    #[inline]
+    pub const fn nan(mut self, nan: TriAllow) -> Self {
+        self.validator.nan = nan;
+
+        self
+    }
+
+    #[inline]
+    pub const fn range(mut self, range: RangeOption<f64>) -> Self {
+        self.validator.range = range;
+
+        self
+    }
+
+    /// Build the validator. `nan(Must)` and `range(Inside)` cannot be used together, and the bounds of the range are checked, the same as the `number` attribute.
+    #[inline]
+    pub fn build(self) -> Result<NumberValidator, OptionsError> {
+        if self.validator.nan.must() && matches!(self.validator.range, RangeOption::Inside { .. }) {
+            return Err(OptionsError::NaNMustRangeInside);
+        }
+
+        check_range_option(&self.validator.range)?;
+
+        Ok(self.validator)
+    }
+}
@@ -0,0 +1,93 @@
+use super::check_range_option;
+use crate::{
+    errors::{OptionsError, SignedIntegerError},
+    functions::check_range,
+    options::RangeOption,
+};
+
+/// The runtime version of the `signed_integer` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::SignedIntegerError, options::RangeOption,
+///     runtime::SignedIntegerValidator,
+/// };
+///
+/// let validator = SignedIntegerValidator::builder()
+///     .range(RangeOption::Outside {
+///         min:       Some(1),
+///         max:       Some(9),
+///         inclusive: true,
+///     })
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(10, validator.parse_str("10").unwrap());
+/// assert!(matches!(
+///     validator.parse_str("5"),
+///     Err(SignedIntegerError::Forbidden { .. })
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SignedIntegerValidator {
+    range: RangeOption<i128>,
+}
+
+/// The builder of `SignedIntegerValidator`. By default, `range = Unlimited`, the same as the `signed_integer` attribute.
+#[derive(Debug, Clone)]
+pub struct SignedIntegerValidatorBuilder {
+    validator: SignedIntegerValidator,
+}
+
+impl SignedIntegerValidator {
+    #[inline]
+    pub const fn builder() -> SignedIntegerValidatorBuilder {
+        SignedIntegerValidatorBuilder {
+            validator: SignedIntegerValidator {
+                range: RangeOption::Unlimited
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<i128, SignedIntegerError> {
+        self.parse_i128(s.as_ref().parse()?)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), SignedIntegerError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+
+    pub fn parse_i128(&self, i: i128) -> Result<i128, SignedIntegerError> {
+        check_range(&self.range, i)?;
+
+        Ok(i)
+    }
+
+    #[inline]
+    pub fn validate_i128(&self, i: i128) -> Result<(), SignedIntegerError> {
+        self.parse_i128(i)?;
+
+        Ok(())
+    }
+}
+
+impl SignedIntegerValidatorBuilder {
+    #[inline]
+    pub const fn range(mut self, range: RangeOption<i128>) -> Self {
+        self.validator.range = range;
+
+        self
+    }
+
+    /// Build the validator. The bounds of the range are checked in the same way as the `signed_integer` attribute.
+    #[inline]
+    pub fn build(self) -> Result<SignedIntegerValidator, OptionsError> {
+        check_range_option(&self.validator.range)?;
+
+        Ok(self.validator)
+    }
+}
@@ -0,0 +1,115 @@
+use alloc::string::String;
+
+use super::LengthOption;
+use crate::{
+    errors::{LengthUnit, OptionsError, TextError},
+    functions::validate_text,
+};
+
+/// The runtime version of the `text` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::{OptionsError, TextError},
+///     runtime::TextValidator,
+/// };
+///
+/// let validator = TextValidator::builder().min(3).max(32).build().unwrap();
+///
+/// assert_eq!("magiclen", validator.parse_str("magiclen").unwrap());
+/// assert!(matches!(
+///     validator.parse_str("ml"),
+///     Err(TextError::TooShort { .. })
+/// ));
+/// assert!(matches!(
+///     validator.parse_str("magic\0len"),
+///     Err(TextError::Invalid)
+/// ));
+///
+/// assert_eq!(
+///     Some(OptionsError::MinGreaterThanMax {
+///         min: 32, max: 3
+///     }),
+///     TextValidator::builder().min(32).max(3).build().err()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextValidator {
+    length: LengthOption,
+}
+
+/// The builder of `TextValidator`. By default, the length is unlimited and measured in characters, the same as the `text` attribute.
+#[derive(Debug, Clone)]
+pub struct TextValidatorBuilder {
+    validator: TextValidator,
+}
+
+impl TextValidator {
+    #[inline]
+    pub const fn builder() -> TextValidatorBuilder {
+        TextValidatorBuilder {
+            validator: TextValidator {
+                length: LengthOption::new()
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: Into<String>>(&self, s: S) -> Result<String, TextError> {
+        let s = s.into();
+
+        self.validate_str(&s)?;
+
+        Ok(s)
+    }
+
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), TextError> {
+        let s = s.as_ref();
+
+        validate_text(
+            s,
+            self.length.unit,
+            self.length.min,
+            self.length.trimmed_min,
+            self.length.max,
+        )
+    }
+}
+
+impl TextValidatorBuilder {
+    #[inline]
+    pub const fn unit(mut self, unit: LengthUnit) -> Self {
+        self.validator.length.unit = unit;
+
+        self
+    }
+
+    #[inline]
+    pub const fn min(mut self, min: usize) -> Self {
+        self.validator.length.min = Some(min);
+
+        self
+    }
+
+    #[inline]
+    pub const fn trimmed_min(mut self, trimmed_min: usize) -> Self {
+        self.validator.length.trimmed_min = Some(trimmed_min);
+
+        self
+    }
+
+    #[inline]
+    pub const fn max(mut self, max: usize) -> Self {
+        self.validator.length.max = Some(max);
+
+        self
+    }
+
+    /// Build the validator. The bounds of the length are checked in the same way as the `text` attribute.
+    #[inline]
+    pub fn build(self) -> Result<TextValidator, OptionsError> {
+        self.validator.length.check()?;
+
+        Ok(self.validator)
+    }
+}
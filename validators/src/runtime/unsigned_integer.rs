@@ -0,0 +1,93 @@
+use super::check_range_option;
+use crate::{
+    errors::{OptionsError, UnsignedIntegerError},
+    functions::check_range,
+    options::RangeOption,
+};
+
+/// The runtime version of the `unsigned_integer` validator.
+///
+/// ```rust
+/// use validators::{
+///     errors::UnsignedIntegerError, options::RangeOption,
+///     runtime::UnsignedIntegerValidator,
+/// };
+///
+/// let validator = UnsignedIntegerValidator::builder()
+///     .range(RangeOption::Outside {
+///         min:       Some(1),
+///         max:       Some(9),
+///         inclusive: true,
+///     })
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(10, validator.parse_str("10").unwrap());
+/// assert!(matches!(
+///     validator.parse_str("5"),
+///     Err(UnsignedIntegerError::Forbidden { .. })
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnsignedIntegerValidator {
+    range: RangeOption<u128>,
+}
+
+/// The builder of `UnsignedIntegerValidator`. By default, `range = Unlimited`, the same as the `unsigned_integer` attribute.
+#[derive(Debug, Clone)]
+pub struct UnsignedIntegerValidatorBuilder {
+    validator: UnsignedIntegerValidator,
+}
+
+impl UnsignedIntegerValidator {
+    #[inline]
+    pub const fn builder() -> UnsignedIntegerValidatorBuilder {
+        UnsignedIntegerValidatorBuilder {
+            validator: UnsignedIntegerValidator {
+                range: RangeOption::Unlimited
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<u128, UnsignedIntegerError> {
+        self.parse_u128(s.as_ref().parse()?)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), UnsignedIntegerError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+
+    pub fn parse_u128(&self, i: u128) -> Result<u128, UnsignedIntegerError> {
+        check_range(&self.range, i)?;
+
+        Ok(i)
+    }
+
+    #[inline]
+    pub fn validate_u128(&self, i: u128) -> Result<(), UnsignedIntegerError> {
+        self.parse_u128(i)?;
+
+        Ok(())
+    }
+}
+
+impl UnsignedIntegerValidatorBuilder {
+    #[inline]
+    pub const fn range(mut self, range: RangeOption<u128>) -> Self {
+        self.validator.range = range;
+
+        self
+    }
+
+    /// Build the validator. The bounds of the range are checked in the same way as the `unsigned_integer` attribute.
+    #[inline]
+    pub fn build(self) -> Result<UnsignedIntegerValidator, OptionsError> {
+        check_range_option(&self.validator.range)?;
+
+        Ok(self.validator)
+    }
+}
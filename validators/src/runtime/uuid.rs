@@ -0,0 +1,87 @@
+use crate::{
+    errors::{OptionsError, UuidError},
+    functions::parse_uuid,
+    options::{CaseOption, SeparatorOption},
+};
+
+/// The runtime version of the `uuid` validator. A UUID is parsed into its 128-bit value.
+///
+/// ```rust
+/// use validators::{
+///     errors::UuidError,
+///     options::{CaseOption, SeparatorOption},
+///     runtime::UuidValidator,
+/// };
+///
+/// let validator = UuidValidator::builder()
+///     .case(CaseOption::Lower)
+///     .separator(SeparatorOption::Must(b'-'))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     0xA866664AC9704CD19F5C6F0FA4E7B0E6,
+///     validator.parse_str("a866664a-c970-4cd1-9f5c-6f0fa4e7b0e6").unwrap()
+/// );
+/// assert!(matches!(
+///     validator.parse_str("a866664ac9704cd19f5c6f0fa4e7b0e6"),
+///     Err(UuidError::SeparatorMust)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UuidValidator {
+    case:      CaseOption,
+    separator: SeparatorOption,
+}
+
+/// The builder of `UuidValidator`. The options are `case(Any)` and `separator(Allow(b'-'))` by default, the same as the `uuid` attribute.
+#[derive(Debug, Clone)]
+pub struct UuidValidatorBuilder {
+    validator: UuidValidator,
+}
+
+impl UuidValidator {
+    #[inline]
+    pub const fn builder() -> UuidValidatorBuilder {
+        UuidValidatorBuilder {
+            validator: UuidValidator {
+                case:      CaseOption::Any,
+                separator: SeparatorOption::Allow(b'-'),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<u128, UuidError> {
+        parse_uuid(s.as_ref(), self.case, self.separator)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), UuidError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
+
+impl UuidValidatorBuilder {
+    #[inline]
+    pub const fn case(mut self, case: CaseOption) -> Self {
+        self.validator.case = case;
+
+        self
+    }
+
+    #[inline]
+    pub const fn separator(mut self, separator: SeparatorOption) -> Self {
+        self.validator.separator = separator;
+
+        self
+    }
+
+    /// Build the validator. Every combination of options is accepted, the same as the `uuid` attribute.
+    #[inline]
+    pub const fn build(self) -> Result<UuidValidator, OptionsError> {
+        Ok(self.validator)
+    }
+}
@@ -0,0 +1,203 @@
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+use serde::{
+    ser::{self, Impossible},
+    Serialize, Serializer,
+};
+
+/// Error from `canonical_string`, when the value is not serialized as a string, a boolean or a number.
+#[derive(Debug, Clone)]
+pub struct CanonicalStringError(String);
+
+impl Display for CanonicalStringError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for CanonicalStringError {}
+
+impl ser::Error for CanonicalStringError {
+    #[inline]
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Get the string which a value is stored as in a database, by its `Serialize` implementation. Booleans and numbers are formatted.
+#[inline]
+pub fn canonical_string<T: Serialize + ?Sized>(value: &T) -> Result<String, CanonicalStringError> {
+    value.serialize(CanonicalStringSerializer)
+}
+
+/// Get the string which a value is stored as in a database, for values which are always serialized to strings, booleans or numbers.
+#[cfg(feature = "diesel")]
+#[inline]
+pub fn canonical_text<T: Serialize + ?Sized>(value: &T) -> String {
+    canonical_string(value).expect("the value should be serialized to a string")
+}
+
+struct CanonicalStringSerializer;
+
+macro_rules! serialize_display {
+    ($($f:ident: $t:ty),* $(,)*) => {
+        $(
+            #[inline]
+            fn $f(self, v: $t) -> Result<Self::Ok, Self::Error> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+#[inline]
+fn unsupported(form: &str) -> CanonicalStringError {
+    CanonicalStringError(format!("{form} cannot be stored as a string"))
+}
+
+impl Serializer for CanonicalStringSerializer {
+    type Error = CanonicalStringError;
+    type Ok = String;
+    type SerializeMap = Impossible<String, CanonicalStringError>;
+    type SerializeSeq = Impossible<String, CanonicalStringError>;
+    type SerializeStruct = Impossible<String, CanonicalStringError>;
+    type SerializeStructVariant = Impossible<String, CanonicalStringError>;
+    type SerializeTuple = Impossible<String, CanonicalStringError>;
+    type SerializeTupleStruct = Impossible<String, CanonicalStringError>;
+    type SerializeTupleVariant = Impossible<String, CanonicalStringError>;
+
+    serialize_display! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+    }
+
+    #[inline]
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(String::from(v))
+    }
+
+    #[inline]
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("bytes"))
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("none"))
+    }
+
+    #[inline]
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("a unit"))
+    }
+
+    #[inline]
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("a unit"))
+    }
+
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(String::from(variant))
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("an enum"))
+    }
+
+    #[inline]
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(unsupported("a sequence"))
+    }
+
+    #[inline]
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(unsupported("a tuple"))
+    }
+
+    #[inline]
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(unsupported("a tuple"))
+    }
+
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported("an enum"))
+    }
+
+    #[inline]
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(unsupported("a map"))
+    }
+
+    #[inline]
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(unsupported("a struct"))
+    }
+
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported("an enum"))
+    }
+}
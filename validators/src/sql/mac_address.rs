@@ -0,0 +1,24 @@
+use core::fmt::{self, Display, Formatter};
+use std::error::Error;
+
+/// Error from `mac_address_from_i64`, when the integer is negative or longer than 48 bits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MacAddressRangeError(pub i64);
+
+impl Display for MacAddressRangeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{} is not a 48-bit MAC address", self.0)
+    }
+}
+
+impl Error for MacAddressRangeError {}
+
+/// Get the MAC address which an integer stored in a database represents.
+#[inline]
+pub fn mac_address_from_i64(v: i64) -> Result<u64, MacAddressRangeError> {
+    match u64::try_from(v) {
+        Ok(mac_address) if mac_address <= 0xFFFF_FFFF_FFFF => Ok(mac_address),
+        _ => Err(MacAddressRangeError(v)),
+    }
+}
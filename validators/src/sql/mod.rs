@@ -0,0 +1,5 @@
+mod canonical_string;
+mod mac_address;
+
+pub use canonical_string::*;
+pub use mac_address::*;
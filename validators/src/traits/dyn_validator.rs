@@ -0,0 +1,75 @@
+use alloc::boxed::Box;
+#[cfg(feature = "serde_json")]
+use alloc::string::ToString;
+use core::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use super::{ErrorCode, ValidateString};
+#[cfg(feature = "serde_json")]
+use crate::errors::DynValidatorError;
+
+/// Errors returned by `DynValidator`s. Every error in the `validators::errors` module implements this trait.
+pub trait DynError: ErrorCode + Display + Debug + Send + Sync {}
+
+impl<T: ErrorCode + Display + Debug + Send + Sync> DynError for T {}
+
+/// An object-safe validator, whose type can be erased in order to be chosen at runtime.
+///
+/// `PhantomData<T>` implements this trait for every `T` which implements `ValidateString`, and so do the validators in the `validators::runtime` module.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "ipv4", feature = "uuid"))]
+/// # {
+/// use std::{marker::PhantomData, net::Ipv4Addr};
+///
+/// use validators::{prelude::*, traits::DynValidator};
+///
+/// #[derive(Validator)]
+/// #[validator(uuid(case(Any), separator(Allow(b'-'))))]
+/// pub struct Uuid(pub u128);
+///
+/// #[derive(Validator)]
+/// #[validator(ipv4(local(Allow), port(Disallow)))]
+/// pub struct Ipv4(pub Ipv4Addr);
+///
+/// let validators: [Box<dyn DynValidator>; 2] =
+///     [Box::new(PhantomData::<Uuid>), Box::new(PhantomData::<Ipv4>)];
+///
+/// assert!(validators[0]
+///     .validate_str("a866664a-f9d3-4dde-89cb-182015fa4f41")
+///     .is_ok());
+/// assert_eq!(
+///     "ipv4.port_disallow",
+///     validators[1].validate_str("127.0.0.1:80").unwrap_err().code()
+/// );
+/// # }
+/// ```
+pub trait DynValidator {
+    fn validate_str(&self, s: &str) -> Result<(), Box<dyn DynError>>;
+
+    /// Validate a string, a number or a boolean in its JSON representation, such as `42` or `true`. Other values result in `DynValidatorError::UnsupportedValue`.
+    #[cfg(feature = "serde_json")]
+    #[inline]
+    fn validate_json_value(&self, v: &Value) -> Result<(), Box<dyn DynError>> {
+        match v {
+            Value::String(s) => self.validate_str(s),
+            Value::Number(_) | Value::Bool(_) => self.validate_str(&v.to_string()),
+            _ => Err(Box::new(DynValidatorError::UnsupportedValue)),
+        }
+    }
+}
+
+impl<T: ValidateString> DynValidator for PhantomData<T>
+where
+    T::Error: DynError + 'static,
+{
+    #[inline]
+    fn validate_str(&self, s: &str) -> Result<(), Box<dyn DynError>> {
+        T::validate_str(s).map_err(|error| Box::new(error) as Box<dyn DynError>)
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "i18n")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "i18n")]
+use crate::i18n::MessageArg;
+
+/// Stable, machine-readable codes of errors, such as `email.local_disallow` or `text.too_long`.
+///
+/// A code is made of the name of the validator and the name of the error variant in snake case. Unlike the messages from `Display`, codes do not change between versions, so they can be used as keys of translations and analytics.
+pub trait ErrorCode {
+    fn code(&self) -> &'static str;
+
+    /// The key of the message in catalogs, used by `Localize`. It is the code of the error by default.
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_key(&self) -> &'static str {
+        self.code()
+    }
+
+    /// The arguments for the placeholders of the message, such as `max` for `{max}`, used by `Localize`.
+    #[cfg(feature = "i18n")]
+    #[inline]
+    fn message_args(&self) -> Vec<(&'static str, MessageArg)> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,70 @@
+use alloc::{borrow::Cow, collections::BTreeMap};
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// The input of a field looked up by `FieldSource::lookup_field`. Every variant carries the name which the errors of the field are reported under. It is the name of the field unless the input is looked up by another name, like the environment variable of `Vars`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldInput<'a> {
+    Present {
+        name:  Cow<'a, str>,
+        value: &'a str,
+    },
+    Missing {
+        name: Cow<'a, str>,
+    },
+    /// The field has an input, but it is not valid Unicode.
+    NotUnicode {
+        name: Cow<'a, str>,
+    },
+}
+
+/// Look up raw inputs by field name.
+pub trait FieldSource {
+    fn lookup_field<'a>(&'a self, name: &'a str) -> FieldInput<'a>;
+}
+
+impl<'a> FieldInput<'a> {
+    #[inline]
+    fn from_option(name: &'a str, value: Option<&'a str>) -> Self {
+        let name = Cow::Borrowed(name);
+
+        match value {
+            Some(value) => Self::Present {
+                name,
+                value,
+            },
+            None => Self::Missing {
+                name,
+            },
+        }
+    }
+}
+
+impl<K: Borrow<str> + Ord, V: AsRef<str>> FieldSource for BTreeMap<K, V> {
+    #[inline]
+    fn lookup_field<'a>(&'a self, name: &'a str) -> FieldInput<'a> {
+        FieldInput::from_option(name, self.get(name).map(|v| v.as_ref()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Borrow<str> + Hash + Eq, V: AsRef<str>, S: BuildHasher> FieldSource for HashMap<K, V, S> {
+    #[inline]
+    fn lookup_field<'a>(&'a self, name: &'a str) -> FieldInput<'a> {
+        FieldInput::from_option(name, self.get(name).map(|v| v.as_ref()))
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> FieldSource for [(K, V)] {
+    #[inline]
+    fn lookup_field<'a>(&'a self, name: &'a str) -> FieldInput<'a> {
+        FieldInput::from_option(
+            name,
+            self.iter().find(|(k, _)| k.as_ref() == name).map(|(_, v)| v.as_ref()),
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+use super::ErrorCode;
+use crate::i18n;
+
+/// Messages of errors in different locales, looked up in the catalogs of the `validators::i18n` module.
+///
+/// Every type which implements `ErrorCode` and `Display` implements this trait. The key and the placeholders of its message come from `ErrorCode::message_key` and `ErrorCode::message_args`.
+///
+/// ```rust
+/// use validators::{errors::EmailError, traits::Localize};
+///
+/// assert_eq!("不可包含註解", EmailError::CommentDisallow.localize("zh-TW"));
+/// assert_eq!("コメントを含めることはできません", EmailError::CommentDisallow.localize("ja"));
+/// assert_eq!("must not contain comments", EmailError::CommentDisallow.localize("en"));
+/// ```
+pub trait Localize: ErrorCode + Display {
+    /// Render the message of this error in `locale`, such as `zh-TW`. The message falls back to the more general locales and then English. If no catalog has the message, the result of `Display` is used.
+    #[inline]
+    fn localize(&self, locale: &str) -> String {
+        i18n::message(locale, self.message_key(), &self.message_args())
+            .unwrap_or_else(|| self.to_string())
+    }
+}
+
+impl<T: ErrorCode + Display + ?Sized> Localize for T {}
@@ -1,28 +1,55 @@
 mod collection_length;
+mod dyn_validator;
+mod error_code;
+mod field_source;
 mod validate_boolean;
+mod validate_borrowed_str;
 mod validate_bytes;
 mod validate_char;
+mod validate_fields;
 mod validate_length;
 mod validate_number;
 mod validate_signed_integer;
 mod validate_string;
 mod validate_unsigned_integer;
+mod validator_spec;
 
 pub use collection_length::*;
+pub use dyn_validator::*;
+pub use error_code::*;
+pub use field_source::*;
 pub use validate_boolean::*;
+pub use validate_borrowed_str::*;
 pub use validate_bytes::*;
 pub use validate_char::*;
+pub use validate_fields::*;
 pub use validate_length::*;
 pub use validate_number::*;
 pub use validate_signed_integer::*;
 pub use validate_string::*;
 pub use validate_unsigned_integer::*;
+pub use validator_spec::*;
 
 #[cfg(feature = "serde_json")]
 mod validate_json_value;
 #[cfg(feature = "serde_json")]
 pub use validate_json_value::*;
 
+#[cfg(feature = "std")]
+mod validate_os_str;
+#[cfg(feature = "std")]
+pub use validate_os_str::*;
+
+#[cfg(feature = "i18n")]
+mod localize;
+#[cfg(feature = "i18n")]
+pub use localize::*;
+
+#[cfg(feature = "proptest")]
+mod validator_strategy;
+#[cfg(feature = "proptest")]
+pub use validator_strategy::*;
+
 #[cfg(feature = "domain")]
 mod qualify_domain;
 #[cfg(feature = "domain")]
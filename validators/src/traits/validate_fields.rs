@@ -0,0 +1,10 @@
+use crate::traits::FieldSource;
+
+/// Validate and deserialize structs whose fields are validators, from a set of raw inputs.
+pub trait ValidateFields: Sized {
+    type Error;
+
+    fn parse_fields<F: FieldSource + ?Sized>(fields: &F) -> Result<Self, Self::Error>;
+
+    fn validate_fields<F: FieldSource + ?Sized>(fields: &F) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,12 @@
+use std::ffi::{OsStr, OsString};
+
+/// Validate and deserialize OS strings, such as file names and command-line arguments, which may not be valid UTF-8.
+pub trait ValidateOsStr: Sized {
+    type Error;
+
+    fn parse_os_string<S: Into<OsString>>(s: S) -> Result<Self, Self::Error>;
+
+    fn parse_os_str<S: AsRef<OsStr>>(s: S) -> Result<Self, Self::Error>;
+
+    fn validate_os_str<S: AsRef<OsStr>>(s: S) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,6 @@
+use crate::models::Spec;
+
+/// The `Validator` derive will implement this to describe the validator and its options of a type.
+pub trait ValidatorSpec {
+    const SPEC: Spec;
+}
@@ -0,0 +1,12 @@
+use alloc::string::String;
+
+use proptest::strategy::BoxedStrategy;
+
+/// The `Validator` derive will implement this with the `proptest` feature to generate strings for a type.
+pub trait ValidatorStrategy {
+    /// Create a strategy of strings which are accepted by the validator.
+    fn valid_strings() -> BoxedStrategy<String>;
+
+    /// Create a strategy of strings which are similar to the valid ones but rejected by the validator.
+    fn invalid_strings() -> BoxedStrategy<String>;
+}
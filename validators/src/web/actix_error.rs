@@ -0,0 +1,157 @@
+use alloc::string::String;
+
+use actix_web::{error::InternalError, http::StatusCode, HttpRequest, HttpResponse, ResponseError};
+
+use super::ErrorBody;
+use crate::{errors::*, traits::ErrorCode};
+
+macro_rules! impl_response_error {
+    ($($(#[$attr:meta])* $t:ident,)*) => {
+        $(
+            $(#[$attr])*
+            impl ResponseError for $t {
+                #[inline]
+                fn status_code(&self) -> StatusCode {
+                    StatusCode::UNPROCESSABLE_ENTITY
+                }
+
+                #[inline]
+                fn error_response(&self) -> HttpResponse {
+                    HttpResponse::build(self.status_code()).json(ErrorBody::from_error(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_response_error! {
+    #[cfg(feature = "all")]
+    AllError,
+    #[cfg(feature = "any")]
+    AnyError,
+    #[cfg(feature = "base32")]
+    Base32Error,
+    #[cfg(feature = "base32_decoded")]
+    Base32DecodedError,
+    #[cfg(feature = "base64")]
+    Base64Error,
+    #[cfg(feature = "base64_decoded")]
+    Base64DecodedError,
+    #[cfg(feature = "base64_url")]
+    Base64UrlError,
+    #[cfg(feature = "base64_url_decoded")]
+    Base64UrlDecodedError,
+    #[cfg(feature = "bit")]
+    BitError,
+    #[cfg(feature = "boolean")]
+    BooleanError,
+    #[cfg(feature = "byte")]
+    ByteError,
+    #[cfg(feature = "domain")]
+    DomainError,
+    DynValidatorError,
+    #[cfg(feature = "email")]
+    EmailError,
+    #[cfg(feature = "host")]
+    HostError,
+    #[cfg(feature = "http_url")]
+    HttpURLError,
+    #[cfg(feature = "http_ftp_url")]
+    HttpFtpURLError,
+    #[cfg(feature = "ip")]
+    IpError,
+    #[cfg(feature = "ipv4")]
+    Ipv4Error,
+    #[cfg(feature = "ipv6")]
+    Ipv6Error,
+    #[cfg(feature = "json")]
+    JsonError,
+    #[cfg(feature = "length")]
+    LengthError,
+    #[cfg(feature = "line")]
+    LineError,
+    #[cfg(feature = "mac_address")]
+    MacAddressError,
+    #[cfg(feature = "not")]
+    NotError,
+    #[cfg(feature = "number")]
+    NumberError,
+    #[cfg(feature = "path")]
+    PathError,
+    #[cfg(feature = "phone")]
+    PhoneError,
+    #[cfg(feature = "regex")]
+    RegexError,
+    #[cfg(any(feature = "semver", feature = "semver_req"))]
+    SemverError,
+    #[cfg(feature = "signed_integer")]
+    SignedIntegerError,
+    #[cfg(feature = "text")]
+    TextError,
+    #[cfg(feature = "unsigned_integer")]
+    UnsignedIntegerError,
+    #[cfg(feature = "url")]
+    UrlError,
+    #[cfg(feature = "uuid")]
+    UuidError,
+    MissingFieldError,
+    NotUnicodeFieldError,
+}
+
+impl<E: ErrorCode + core::fmt::Debug + core::fmt::Display> ResponseError for CustomError<E> {
+    #[inline]
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    #[inline]
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorBody::from_error(self))
+    }
+}
+
+/// The body is like `ErrorBody` with the code `validation.invalid`, plus an `errors` field which is the serialized `ValidationErrors`.
+impl ResponseError for ValidationErrors {
+    #[inline]
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    #[inline]
+    fn error_response(&self) -> HttpResponse {
+        struct Body<'a>(&'a ValidationErrors);
+
+        impl<'a> serde::Serialize for Body<'a> {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeStruct;
+
+                let mut s = serializer.serialize_struct("ErrorBody", 3)?;
+
+                s.serialize_field("code", "validation.invalid")?;
+                s.serialize_field("message", &alloc::string::ToString::to_string(self.0))?;
+                s.serialize_field("errors", self.0)?;
+
+                s.end()
+            }
+        }
+
+        HttpResponse::build(self.status_code()).json(Body(self))
+    }
+}
+
+/// Get the only path parameter of the route for the `FromRequest` implementations generated by the `Validator` derive macro.
+#[doc(hidden)]
+pub fn actix_path_parameter(req: &HttpRequest) -> Result<String, actix_web::Error> {
+    let mut segments = req.match_info().iter();
+
+    match (segments.next(), segments.next()) {
+        (Some((_, v)), None) => Ok(String::from(v)),
+        _ => {
+            let body = ErrorBody::new("path.invalid", "expected exactly one path parameter");
+            let response = HttpResponse::InternalServerError().json(&body);
+
+            Err(InternalError::from_response(body.message, response).into())
+        },
+    }
+}
@@ -0,0 +1,267 @@
+use alloc::string::String;
+use core::{
+    cell::RefCell,
+    fmt::{self, Display, Formatter},
+};
+use std::error::Error;
+
+use axum::{
+    extract::{
+        rejection::{PathRejection, QueryRejection},
+        FromRequestParts,
+    },
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use super::ErrorBody;
+use crate::traits::{ErrorCode, ValidateString};
+
+/// The rejection of the extractors in this module, which responds with its status and an `ErrorBody` in JSON.
+#[derive(Debug, Clone)]
+pub struct Rejection {
+    status: StatusCode,
+    body:   ErrorBody,
+}
+
+impl Rejection {
+    /// Create a new instance.
+    #[inline]
+    pub const fn new(status: StatusCode, body: ErrorBody) -> Self {
+        Self {
+            status,
+            body,
+        }
+    }
+
+    /// Create a new instance by the code and the message of an error.
+    #[inline]
+    pub fn from_error<E: ErrorCode + Display + ?Sized>(status: StatusCode, error: &E) -> Self {
+        Self::new(status, ErrorBody::from_error(error))
+    }
+
+    /// Get the status of the response.
+    #[inline]
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Get the body of the response.
+    #[inline]
+    pub const fn body(&self) -> &ErrorBody {
+        &self.body
+    }
+
+    /// Change the status of the response.
+    #[inline]
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+
+        self
+    }
+}
+
+impl Display for Rejection {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&self.body.message)
+    }
+}
+
+impl Error for Rejection {}
+
+impl IntoResponse for Rejection {
+    #[inline]
+    fn into_response(self) -> Response {
+        (self.status, Json(self.body)).into_response()
+    }
+}
+
+impl From<PathRejection> for Rejection {
+    #[inline]
+    fn from(rejection: PathRejection) -> Self {
+        Self::new(rejection.status(), ErrorBody::new("path.invalid", rejection.body_text()))
+    }
+}
+
+impl From<QueryRejection> for Rejection {
+    #[inline]
+    fn from(rejection: QueryRejection) -> Self {
+        Self::new(rejection.status(), ErrorBody::new("query.invalid", rejection.body_text()))
+    }
+}
+
+/// The `STATUS` parameter of the extractors. A status out of the range of `StatusCode` fails to compile.
+struct Status<const STATUS: u16>;
+
+impl<const STATUS: u16> Status<STATUS> {
+    const VALID: () = assert!(
+        STATUS >= 100 && STATUS <= 999,
+        "the `STATUS` of an extractor must be a status code from 100 to 999"
+    );
+
+    #[inline]
+    fn code() -> StatusCode {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID;
+
+        StatusCode::from_u16(STATUS).unwrap()
+    }
+}
+
+std::thread_local! {
+    /// The last validator error raised by a derived `Deserialize` implementation on this thread.
+    static DESERIALIZE_ERROR: RefCell<Option<ErrorBody>> = const { RefCell::new(None) };
+}
+
+#[inline]
+pub(crate) fn set_deserialize_error(body: ErrorBody) {
+    DESERIALIZE_ERROR.with(|error| *error.borrow_mut() = Some(body));
+}
+
+#[inline]
+pub(crate) async fn path_parameter<S: Send + Sync>(
+    parts: &mut Parts,
+    state: &S,
+) -> Result<String, Rejection> {
+    let axum::extract::Path(v) =
+        <axum::extract::Path<String> as FromRequestParts<S>>::from_request_parts(parts, state)
+            .await?;
+
+    Ok(v)
+}
+
+/**
+An extractor which parses the only path parameter of the route with a validator.
+
+The rejection has the `STATUS` status (`422 Unprocessable Entity` by default) and the code of the validator error. If the route does not have exactly one path parameter, the status and the code `path.invalid` come from `axum`.
+
+Structs derived with the `Validator` derive macro can also be extracted without this wrapper, which is the same as using this with the default status.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "axum", feature = "uuid"))]
+# {
+use axum::{routing::get, Router};
+use validators::{prelude::*, web::Path};
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid(pub u128);
+
+async fn get_user(Path(id): Path<Uuid, 400>) -> String {
+    format!("{:032x}", id.0)
+}
+
+async fn get_post(id: Uuid) -> String {
+    format!("{:032x}", id.0)
+}
+
+let app: Router =
+    Router::new().route("/users/{id}", get(get_user)).route("/posts/{id}", get(get_post));
+# }
+```
+
+A `STATUS` which is not a valid status code fails to compile.
+
+```rust,compile_fail
+use std::collections::HashMap;
+
+use axum::{routing::get, Router};
+use validators::web::Query;
+
+async fn search(Query(query): Query<HashMap<String, String>, 1000>) -> String {
+    query.len().to_string()
+}
+
+let app: Router = Router::new().route("/search", get(search));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Path<T, const STATUS: u16 = 422>(pub T);
+
+impl<S, T, const STATUS: u16> FromRequestParts<S> for Path<T, STATUS>
+where
+    S: Send + Sync,
+    T: ValidateString,
+    T::Error: ErrorCode + Display,
+{
+    type Rejection = Rejection;
+
+    #[inline]
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let v = path_parameter(parts, state).await?;
+
+        T::parse_string(v)
+            .map(Self)
+            .map_err(|error| Rejection::from_error(Status::<STATUS>::code(), &error))
+    }
+}
+
+/**
+An extractor which deserializes the query string, whose fields can be structs derived with the `Validator` derive macro.
+
+The rejection has the `STATUS` status (`422 Unprocessable Entity` by default). If a field is rejected by its validator, the rejection has the code of the validator error, the same as `Path`. Otherwise the code is `query.invalid`. Use `validators::Result` as the type of a field to keep its error instead of rejecting the request.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "axum", feature = "email"))]
+# {
+use axum::{routing::get, Router};
+use serde::Deserialize;
+use validators::{errors::EmailError, prelude::*, web::Query};
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Allow), local(Allow), at_least_two_labels(Allow), non_ascii(Allow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: validators::models::Host,
+}
+
+#[derive(Deserialize)]
+struct Subscribe {
+    email: validators::Result<Email, EmailError>,
+}
+
+async fn subscribe(Query(query): Query<Subscribe>) -> String {
+    match query.email.into_std_result() {
+        Ok(email) => email.to_email_string(),
+        Err(error) => error.code().to_string(),
+    }
+}
+
+let app: Router = Router::new().route("/subscribe", get(subscribe));
+# }
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Query<T, const STATUS: u16 = 422>(pub T);
+
+impl<S, T, const STATUS: u16> FromRequestParts<S> for Query<T, STATUS>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = Rejection;
+
+    #[inline]
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        DESERIALIZE_ERROR.with(RefCell::take);
+
+        match axum::extract::Query::<T>::try_from_uri(&parts.uri) {
+            Ok(axum::extract::Query(v)) => Ok(Self(v)),
+            Err(rejection) => {
+                let body_text = rejection.body_text();
+
+                // the error kept by the derived `Deserialize` implementation is only used if it is the one which rejected the query string
+                let body = match DESERIALIZE_ERROR.with(RefCell::take) {
+                    Some(body) if body_text.contains(&body.message) => body,
+                    _ => ErrorBody::new("query.invalid", body_text),
+                };
+
+                Err(Rejection::new(Status::<STATUS>::code(), body))
+            },
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use alloc::{borrow::Cow, string::String};
+use core::fmt::Display;
+
+use crate::traits::ErrorCode;
+
+/// The JSON body of responses to invalid requests, such as `{"code":"email.invalid","message":"invalid Email"}`.
+///
+/// The fields are the same as those of an entry serialized from `ValidationErrors`, except that there is no path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorBody {
+    /// The error code, such as `email.invalid`.
+    pub code:    Cow<'static, str>,
+    /// The message of the error.
+    pub message: String,
+}
+
+impl ErrorBody {
+    /// Create a new instance.
+    #[inline]
+    pub fn new<C: Into<Cow<'static, str>>, M: Into<String>>(code: C, message: M) -> Self {
+        Self {
+            code: code.into(), message: message.into()
+        }
+    }
+
+    /// Create a new instance by the code and the message of an error.
+    #[inline]
+    pub fn from_error<E: ErrorCode + Display + ?Sized>(error: &E) -> Self {
+        Self::new(error.code(), alloc::string::ToString::to_string(error))
+    }
+}
+
+impl serde::Serialize for ErrorBody {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("ErrorBody", 2)?;
+
+        s.serialize_field("code", &self.code)?;
+        s.serialize_field("message", &self.message)?;
+
+        s.end()
+    }
+}
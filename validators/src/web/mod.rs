@@ -0,0 +1,11 @@
+#[cfg(feature = "actix")]
+mod actix_error;
+#[cfg(feature = "axum")]
+mod axum_extract;
+mod error_body;
+
+#[cfg(feature = "actix")]
+pub use actix_error::*;
+#[cfg(feature = "axum")]
+pub use axum_extract::*;
+pub use error_body::*;
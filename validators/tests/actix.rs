@@ -0,0 +1,121 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "actix",
+    feature = "serde_json",
+    feature = "line",
+    feature = "uuid"
+))]
+
+use serde::Deserialize;
+use validators::{
+    actix_web::{
+        body::to_bytes,
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        web, App, HttpResponse, ResponseError,
+    },
+    errors::{LineError, UuidError, ValidationErrors},
+    prelude::*,
+    serde_json::{self, Value},
+};
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(pub u128);
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-')), actix = false))]
+pub struct PlainUuid(pub u128);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Name(pub String);
+
+#[derive(Deserialize)]
+struct Greet {
+    name: Name,
+}
+
+async fn request(uri: &str) -> (StatusCode, String) {
+    let app = init_service(
+        App::new()
+            .route("/derived/{id}", web::get().to(|id: Uuid| async move { format!("{:x}", id.0) }))
+            .route(
+                "/path/{id}",
+                web::get().to(|id: web::Path<PlainUuid>| async move { format!("{:x}", id.0) }),
+            )
+            .route("/two/{a}/{b}", web::get().to(|id: Uuid| async move { format!("{:x}", id.0) }))
+            .route(
+                "/greet",
+                web::get().to(|query: web::Query<Greet>| async move { query.name.0.clone() }),
+            ),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+
+    let status = response.status();
+    let body = read_body(response).await;
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+async fn reject(uri: &str) -> (StatusCode, Value) {
+    let (status, body) = request(uri).await;
+
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+async fn response_body(response: HttpResponse) -> Value {
+    serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap()
+}
+
+const ID: &str = "a866664a-f9d3-4dde-89cb-182015fa4f41";
+
+#[actix_rt::test]
+async fn from_request() {
+    assert_eq!((StatusCode::OK, ID.replace('-', "")), request(&format!("/derived/{ID}")).await);
+
+    let (status, body) = reject("/derived/a866664a").await;
+
+    assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+    assert_eq!("uuid.separator_must", body["code"]);
+    assert_eq!(UuidError::SeparatorMust.to_string(), body["message"]);
+
+    let (status, body) = reject(&format!("/two/{ID}/{ID}")).await;
+
+    assert!(status.is_server_error());
+    assert_eq!("path.invalid", body["code"]);
+}
+
+#[actix_rt::test]
+async fn serde_extractors() {
+    assert_eq!((StatusCode::OK, ID.replace('-', "")), request(&format!("/path/{ID}")).await);
+    assert_eq!(StatusCode::NOT_FOUND, request("/path/a866664a").await.0);
+
+    assert_eq!((StatusCode::OK, "len".to_string()), request("/greet?name=len").await);
+    assert_eq!(StatusCode::BAD_REQUEST, request("/greet?name=magiclen.org").await.0);
+}
+
+#[actix_rt::test]
+async fn response_error() {
+    let error = LineError::Invalid;
+
+    assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, error.status_code());
+
+    let body = response_body(error.error_response()).await;
+
+    assert_eq!("line.invalid", body["code"]);
+    assert_eq!(error.to_string(), body["message"]);
+
+    let mut errors = ValidationErrors::new();
+
+    errors.push("id", UuidError::Invalid);
+    errors.push("name", LineError::Invalid);
+
+    let body = response_body(errors.error_response()).await;
+
+    assert_eq!("validation.invalid", body["code"]);
+    assert_eq!(errors.to_string(), body["message"]);
+    assert_eq!(serde_json::to_value(&errors).unwrap(), body["errors"]);
+}
@@ -0,0 +1,74 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "arbitrary",
+    feature = "length",
+    feature = "line",
+    feature = "signed_integer",
+    feature = "uuid"
+))]
+
+use validators::{
+    arbitrary::{Arbitrary, Error, Unstructured},
+    prelude::*,
+};
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Inside(min = -10, max = 10))))]
+pub struct Small(i8);
+
+#[derive(Validator)]
+#[validator(length(min = 1, max = 3))]
+pub struct Tags(Vec<u8>);
+
+fn take_rest<'a, T: Arbitrary<'a>>(data: &'a [u8]) -> Result<T, Error> {
+    T::arbitrary_take_rest(Unstructured::new(data))
+}
+
+#[test]
+fn string() {
+    let uuid: Uuid = take_rest(b"a866664a-f9d3-4dde-89cb-182015fa4f41").unwrap();
+
+    assert_eq!(0xA866664AF9D34DDE89CB182015FA4F41, uuid.0);
+    assert!(matches!(
+        take_rest::<Uuid>(b"A866664A-F9D3-4DDE-89CB-182015FA4F41"),
+        Err(Error::IncorrectFormat)
+    ));
+}
+
+#[test]
+fn borrowed() {
+    let data = b"slug".to_vec();
+
+    let slug: Slug = take_rest(&data).unwrap();
+
+    assert_eq!("slug", slug.0);
+    assert_eq!(data.as_ptr(), slug.0.as_ptr());
+    assert!(matches!(take_rest::<Slug>(b"too long slug"), Err(Error::IncorrectFormat)));
+}
+
+#[test]
+fn number() {
+    assert_eq!(5, take_rest::<Small>(&[5]).unwrap().0);
+    assert!(matches!(take_rest::<Small>(&[100]), Err(Error::IncorrectFormat)));
+}
+
+#[test]
+fn collection() {
+    let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    for _ in 0..8 {
+        if let Ok(tags) = Tags::arbitrary(&mut u) {
+            assert!((1..=3).contains(&tags.0.len()));
+        }
+    }
+
+    assert!(matches!(take_rest::<Tags>(&[]), Err(Error::IncorrectFormat)));
+}
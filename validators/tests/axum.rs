@@ -0,0 +1,152 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "axum",
+    feature = "serde_json",
+    feature = "length",
+    feature = "line",
+    feature = "uuid"
+))]
+
+use serde::Deserialize;
+use tower::ServiceExt;
+use validators::{
+    axum::{
+        body::{to_bytes, Body},
+        http::{Request, StatusCode},
+        routing::get,
+        Router,
+    },
+    errors::{LineError, UuidError},
+    prelude::*,
+    serde_json::{self, Value},
+    web::{Path, Query},
+};
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(pub u128);
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-')), axum = false))]
+pub struct PlainUuid(pub u128);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Name(pub String);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Slug<'a>(pub &'a str);
+
+#[derive(Validator)]
+#[validator(length(max = 2))]
+pub struct Tags(pub Vec<String>);
+
+#[derive(Deserialize)]
+struct Greet {
+    name: Name,
+}
+
+#[derive(Deserialize)]
+struct TryGreet {
+    name: validators::Result<Name, LineError>,
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/derived/{id}", get(|id: Uuid| async move { format!("{:x}", id.0) }))
+        .route(
+            "/path/{id}",
+            get(|Path(id): Path<PlainUuid, 400>| async move { format!("{:x}", id.0) }),
+        )
+        .route(
+            "/result/{id}",
+            get(|id: validators::Result<Uuid, UuidError>| async move {
+                match id.into_std_result() {
+                    Ok(id) => format!("{:x}", id.0),
+                    Err(error) => error.code().to_string(),
+                }
+            }),
+        )
+        .route("/two/{a}/{b}", get(|id: Uuid| async move { format!("{:x}", id.0) }))
+        .route("/greet", get(|Query(query): Query<Greet>| async move { query.name.0 }))
+        .route(
+            "/try_greet",
+            get(|Query(query): Query<TryGreet, 400>| async move {
+                match query.name.into_std_result() {
+                    Ok(name) => name.0,
+                    Err(error) => error.code().to_string(),
+                }
+            }),
+        )
+}
+
+async fn request(uri: &str) -> (StatusCode, String) {
+    let response = app().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+async fn reject(uri: &str) -> (StatusCode, Value) {
+    let (status, body) = request(uri).await;
+
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+const ID: &str = "a866664a-f9d3-4dde-89cb-182015fa4f41";
+
+#[tokio::test]
+async fn path() {
+    assert_eq!((StatusCode::OK, ID.replace('-', "")), request(&format!("/derived/{ID}")).await);
+    assert_eq!((StatusCode::OK, ID.replace('-', "")), request(&format!("/path/{ID}")).await);
+
+    let (status, body) = reject("/derived/a866664a").await;
+
+    assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+    assert_eq!("uuid.separator_must", body["code"]);
+    assert_eq!(UuidError::SeparatorMust.to_string(), body["message"]);
+
+    let (status, body) = reject(&format!("/path/{}", ID.to_uppercase())).await;
+
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!("uuid.invalid", body["code"]);
+
+    let (status, body) = reject(&format!("/two/{ID}/{ID}")).await;
+
+    assert!(status.is_server_error());
+    assert_eq!("path.invalid", body["code"]);
+}
+
+#[tokio::test]
+async fn result() {
+    assert_eq!((StatusCode::OK, ID.replace('-', "")), request(&format!("/result/{ID}")).await);
+    assert_eq!((StatusCode::OK, "uuid.separator_must".to_string()), request("/result/uuid").await);
+}
+
+#[tokio::test]
+async fn query() {
+    assert_eq!((StatusCode::OK, "len".to_string()), request("/greet?name=len").await);
+
+    let (status, body) = reject("/greet?name=magiclen.org").await;
+
+    assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+    assert_eq!("line.too_long", body["code"]);
+
+    let (status, body) = reject("/greet").await;
+
+    assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+    assert_eq!("query.invalid", body["code"]);
+
+    assert_eq!(
+        (StatusCode::OK, "line.too_long".to_string()),
+        request("/try_greet?name=magiclen.org").await
+    );
+
+    let (status, body) = reject("/try_greet").await;
+
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!("query.invalid", body["code"]);
+}
@@ -54,6 +54,8 @@ fn basic() {
                     test("GEZDGNBVGY3TQOI=", Validator::V_PADDING.allow());
                     test("GEZDGNBVGY3TQOI", !Validator::V_PADDING.must());
                     test("GEZDGNBV=GY3TQOI", false);
+                    test("J", false);
+                    test("MZXW6===MZXQ====", false);
                 }
             )*
         }
@@ -54,6 +54,8 @@ fn basic() {
                     test("MTIzNDU2Nz-5MA==", Validator::V_PADDING.allow());
                     test("MTIzNDU2Nz-5MA", !Validator::V_PADDING.must());
                     test("MTIzND=U2Nzg5MA", false);
+                    test("M", false);
+                    test("MTI=MTI=", false);
                 }
             )*
         }
@@ -0,0 +1,124 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "base64",
+    feature = "domain",
+    feature = "line",
+    feature = "uuid"
+))]
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+};
+
+use validators::{errors::CustomError, prelude::*};
+
+#[derive(Debug)]
+pub struct ReservedError;
+
+impl Display for ReservedError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("reserved")
+    }
+}
+
+impl ErrorCode for ReservedError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "reserved"
+    }
+}
+
+fn not_reserved(title: &Title) -> Result<(), ReservedError> {
+    if title.0 == "admin" {
+        Err(ReservedError)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 16)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 16), custom = not_reserved))]
+pub struct Title<'a>(Cow<'a, str>);
+
+#[derive(Validator)]
+#[validator(base64(padding(Must)))]
+pub struct Base64<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Allow), at_least_two_labels(Allow), port(Disallow)))]
+pub struct Domain<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid<'a>(Cow<'a, str>);
+
+#[test]
+fn parse_borrowed() {
+    let s = String::from("hello-world");
+
+    let slug = Slug::parse_borrowed(&s).unwrap();
+
+    assert_eq!(s.as_ptr(), slug.0.as_ptr());
+    assert_eq!(
+        "line.too_long",
+        Slug::parse_borrowed("hello-world-hello-world").err().unwrap().code()
+    );
+    assert_eq!("line.invalid", Slug::parse_borrowed("hello\nworld").err().unwrap().code());
+
+    assert_eq!("aGk=", Base64::parse_borrowed("aGk=").unwrap().0);
+    assert!(Base64::parse_borrowed("aGk").is_err());
+
+    // the domain is not converted by IDNA, so it must already be canonical
+    assert_eq!("magiclen.org", Domain::parse_borrowed("magiclen.org").unwrap().0);
+    assert_eq!("xn--bcher-kva.example", Domain::parse_borrowed("xn--bcher-kva.example").unwrap().0);
+    assert!(Domain::parse_borrowed("MagicLen.org").is_err());
+    assert!(Domain::parse_borrowed("管理員.台灣").is_err());
+    assert!(Domain::parse_borrowed("127.0.0.1").is_err());
+
+    assert_eq!(
+        "A866664AF9E94B5C8A59E6B5A7A5D4C8",
+        Uuid::parse_borrowed("A866664AF9E94B5C8A59E6B5A7A5D4C8").unwrap().0
+    );
+    assert!(Uuid::parse_borrowed("A866664AF9E94B5C8A59").is_err());
+}
+
+#[test]
+fn cow() {
+    assert!(matches!(Title::parse_borrowed("hello").unwrap().0, Cow::Borrowed("hello")));
+    assert!(matches!(Title::parse_str("hello").unwrap().0, Cow::Owned(_)));
+    assert!(Title::validate_str("hello").is_ok());
+
+    assert!(matches!(
+        Title::parse_borrowed("admin").err().unwrap(),
+        CustomError::Custom(error) if error.code() == "reserved"
+    ));
+    assert!(matches!(
+        Title::parse_string("hello\nworld").err().unwrap(),
+        CustomError::Validator(error) if error.code() == "line.invalid"
+    ));
+    assert!(Title::validate_str("admin").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use validators::serde_json;
+
+    let json = String::from("\"hello-world\"");
+
+    let slug = serde_json::from_str::<Slug>(&json).unwrap();
+
+    assert_eq!("hello-world", slug.0);
+    assert_eq!(json, serde_json::to_string(&slug).unwrap());
+    assert!(serde_json::from_str::<Slug>("\"hello\\nworld\"").is_err());
+
+    assert!(matches!(serde_json::from_str::<Title>(&json).unwrap().0, Cow::Borrowed(_)));
+    assert!(matches!(serde_json::from_str::<Title>("\"hello\\tworld\"").unwrap().0, Cow::Owned(_)));
+    assert!(serde_json::from_str::<Title>("\"admin\"").is_err());
+}
@@ -122,3 +122,24 @@ fn basic() {
         },
     }
 }
+
+#[test]
+fn error_context() {
+    use validators::errors::ByteError;
+
+    #[derive(Validator)]
+    #[validator(byte(range(min = 1, max = 1024)))]
+    pub struct Size(pub byte_unit::Byte);
+
+    assert!(matches!(
+        Size::parse_u64(2048),
+        Err(ByteError::TooLarge {
+            max: 1024, inclusive: true, value: 2048
+        })
+    ));
+
+    assert_eq!(
+        "byte is too small, must be at least 1 bytes (got 0)",
+        Size::parse_str("0").err().unwrap().to_string()
+    );
+}
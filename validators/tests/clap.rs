@@ -0,0 +1,96 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "clap",
+    feature = "boolean",
+    feature = "host",
+    feature = "unsigned_integer"
+))]
+
+use clap::{error::ErrorKind, CommandFactory, Parser};
+use validators::{
+    errors::{HostError, UnsignedIntegerError},
+    prelude::*,
+};
+
+#[derive(Validator, Clone)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct HostAllowPort {
+    pub host: validators::models::Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator, Clone)]
+#[validator(unsigned_integer(range(Inside(min = 1024, max = 65535))))]
+pub struct Port(u16);
+
+#[derive(Validator, Clone)]
+#[validator(boolean)]
+pub struct Flag(bool);
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long)]
+    host: HostAllowPort,
+    #[arg(long)]
+    port: Option<Port>,
+    #[arg(long)]
+    tls:  Option<Flag>,
+    #[arg(long)]
+    peer: Vec<HostAllowPort>,
+}
+
+#[test]
+fn value_parser() {
+    let args = Args::try_parse_from([
+        "app",
+        "--host",
+        "localhost:8000",
+        "--port",
+        "8080",
+        "--tls",
+        "on",
+        "--peer",
+        "magiclen.org",
+        "--peer",
+        "localhost:8001",
+    ])
+    .unwrap();
+
+    assert_eq!(Some(8000), args.host.port);
+    assert_eq!(8080, args.port.unwrap().0);
+    assert!(args.tls.unwrap().0);
+    assert_eq!(2, args.peer.len());
+}
+
+#[test]
+fn error() {
+    let error = Args::try_parse_from(["app", "--host", "localhost:99999"]).err().unwrap();
+
+    assert_eq!(ErrorKind::ValueValidation, error.kind());
+    assert!(error.to_string().contains(&format!(
+        "invalid value 'localhost:99999' for '--host <HOST>': {}",
+        HostError::Invalid
+    )));
+
+    let error = Args::try_parse_from(["app", "--host", "localhost", "--port", "80"]).err().unwrap();
+
+    assert_eq!(ErrorKind::ValueValidation, error.kind());
+    assert!(error.to_string().contains(
+        &UnsignedIntegerError::TooSmall {
+            min: 1024, value: 80
+        }
+        .to_string()
+    ));
+}
+
+#[test]
+fn possible_values() {
+    let help = Args::command().render_long_help().to_string();
+
+    assert!(help.contains("[possible values: true, false]"));
+
+    let error =
+        Args::try_parse_from(["app", "--host", "localhost", "--tls", "maybe"]).err().unwrap();
+
+    assert_eq!(ErrorKind::ValueValidation, error.kind());
+}
@@ -0,0 +1,107 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "all",
+    feature = "any",
+    feature = "not",
+    feature = "domain",
+    feature = "email",
+    feature = "ipv4",
+    feature = "regex"
+))]
+
+use std::net::Ipv4Addr;
+
+use validators::{
+    errors::{AllError, AnyError, NotError},
+    prelude::*,
+};
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), port(Disallow), at_least_two_labels(Must)))]
+pub struct Domain(pub String);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[a-z.]+$"))]
+pub struct Lowercase(pub String);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[^x]+$"))]
+pub struct WithoutX(pub String);
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(pub Ipv4Addr);
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+#[validator(all(Domain, Lowercase, WithoutX))]
+pub struct LowercaseDomain(pub Domain);
+
+#[derive(Validator)]
+#[validator(any(Ipv4, Email))]
+pub struct Contact(pub String);
+
+#[derive(Validator)]
+#[validator(not(Ipv4))]
+pub struct NotIpv4(pub String);
+
+#[test]
+fn all() {
+    // the parsed representation of the first validator is kept
+    assert_eq!("magiclen.org", LowercaseDomain::parse_str("magiclen.org").unwrap().0 .0);
+
+    let error: AllError = LowercaseDomain::parse_str("localhost").err().unwrap();
+
+    assert_eq!(0, error.index);
+    assert_eq!("domain.local_disallow", error.code());
+
+    let error = LowercaseDomain::parse_str("MagicLen.org").err().unwrap();
+
+    assert_eq!(1, error.index);
+    assert_eq!("regex.invalid", error.code());
+
+    let error = LowercaseDomain::validate_str("example.xyz").unwrap_err();
+
+    assert_eq!(2, error.index);
+    assert_eq!(Lowercase::validate_str("X").unwrap_err().to_string(), error.to_string());
+}
+
+#[test]
+fn any() {
+    assert_eq!("127.0.0.1", Contact::parse_str("127.0.0.1").unwrap().0);
+    assert_eq!("len@magiclen.org", Contact::parse_string("len@magiclen.org").unwrap().0);
+
+    let error: AnyError = Contact::parse_str("len(comment)@magiclen.org").err().unwrap();
+
+    assert_eq!("any.invalid", error.code());
+    assert_eq!(
+        vec!["ipv4.invalid", "email.comment_disallow"],
+        error.errors.iter().map(|error| error.code()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn not() {
+    assert!(NotIpv4::validate_str("magiclen.org").is_ok());
+    assert!(matches!(NotIpv4::parse_str("127.0.0.1").err(), Some(NotError)));
+    assert_eq!("not.invalid", NotError.code());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use validators::serde_json;
+
+    let contact: Contact = serde_json::from_str("\"127.0.0.1\"").unwrap();
+
+    assert_eq!("\"127.0.0.1\"", serde_json::to_string(&contact).unwrap());
+    assert!(serde_json::from_str::<NotIpv4>("\"127.0.0.1\"").is_err());
+    assert!(serde_json::from_str::<LowercaseDomain>("\"MagicLen.org\"").is_err());
+}
@@ -0,0 +1,314 @@
+#![cfg(all(
+    feature = "config",
+    feature = "derive",
+    feature = "email",
+    feature = "line",
+    feature = "number",
+    feature = "unsigned_integer"
+))]
+
+use validators::{
+    config::{RuntimeValidator, ValidatorConfig},
+    errors::{ConfigError, EmailError, LineError, NumberError, OptionsError, UnsignedIntegerError},
+    prelude::*,
+    registry::Registry,
+};
+
+#[derive(Validator)]
+#[validator(line(char_length(min = 3, max = 32)))]
+pub struct Username(pub String);
+
+const TOML: &str = r#"
+[validators.username]
+kind = "line"
+char_length = { min = 3, max = 32 }
+
+[validators.age]
+kind = "unsigned_integer"
+range = { Inside = { min = 0, max = 150 } }
+
+[validators.ratio]
+kind = "number"
+nan = "Disallow"
+range = { Inside = { min = 0, max = 1, inclusive = false } }
+
+[validators.contact]
+kind = "email"
+local = "Disallow"
+"#;
+
+#[test]
+fn from_toml() {
+    let config = ValidatorConfig::from_toml(TOML).unwrap();
+
+    assert_eq!(4, config.len());
+    assert_eq!(
+        vec!["age", "contact", "ratio", "username"],
+        config.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>()
+    );
+
+    let username = match config.get("username") {
+        Some(RuntimeValidator::Line(validator)) => validator,
+        _ => unreachable!(),
+    };
+
+    for s in ["magiclen", "ml", "magic\nlen", "  ml  ", "0123456789012345678901234567890123"] {
+        assert_eq!(
+            Username::parse_str(s).err().map(|error| error.to_string()),
+            username.parse_str(s).err().map(|error| error.to_string()),
+            "{s}"
+        );
+    }
+
+    assert!(matches!(username.parse_str("ml"), Err(LineError::TooShort { .. })));
+
+    match config.get("age") {
+        Some(RuntimeValidator::UnsignedInteger(validator)) => {
+            assert_eq!(42, validator.parse_str("42").unwrap());
+            assert!(matches!(
+                validator.parse_str("151"),
+                Err(UnsignedIntegerError::TooLarge { .. })
+            ));
+        },
+        _ => unreachable!(),
+    }
+
+    match config.get("ratio") {
+        Some(RuntimeValidator::Number(validator)) => {
+            assert!(matches!(validator.parse_str("1"), Err(NumberError::TooLarge { .. })));
+            assert!(matches!(validator.parse_str("NaN"), Err(NumberError::NaNDisallow)));
+        },
+        _ => unreachable!(),
+    }
+
+    match config.get("contact") {
+        Some(validator @ RuntimeValidator::Email(email)) => {
+            assert_eq!("email", validator.kind());
+            assert!(matches!(email.parse_str("len@localhost"), Err(EmailError::LocalDisallow)));
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn registry() {
+    let registry = Registry::from(ValidatorConfig::from_toml(TOML).unwrap());
+
+    assert_eq!(4, registry.len());
+    assert!(registry.validate_str("username", "magiclen").is_ok());
+    assert_eq!("line.too_short", registry.validate_str("username", "ml").unwrap_err().code());
+    assert_eq!(
+        "email.local_disallow",
+        registry.validate_str("contact", "len@localhost").unwrap_err().code()
+    );
+}
+
+#[test]
+fn from_json() {
+    let config = ValidatorConfig::from_json(
+        r#"{
+            "validators": {
+                "username": { "kind": "line", "byte_length": { "trimmed_min": 1 } }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    match config.get("username") {
+        Some(RuntimeValidator::Line(validator)) => {
+            assert!(matches!(validator.parse_str("   "), Err(LineError::TooShort { .. })));
+        },
+        _ => unreachable!(),
+    }
+
+    assert!(ValidatorConfig::from_json("{}").unwrap().is_empty());
+    assert!(matches!(ValidatorConfig::from_json("[]"), Err(ConfigError::JsonError(_))));
+}
+
+#[cfg(all(feature = "mac_address", feature = "uuid"))]
+#[test]
+fn separators() {
+    use validators::errors::{MacAddressError, UuidError};
+
+    let config = ValidatorConfig::from_toml(
+        r#"
+[validators.id]
+kind = "uuid"
+case = "Lower"
+separator = { Must = "-" }
+
+[validators.device]
+kind = "mac_address"
+separator = "Disallow"
+"#,
+    )
+    .unwrap();
+
+    match config.get("id") {
+        Some(RuntimeValidator::Uuid(validator)) => {
+            assert!(validator.parse_str("a866664a-c970-4cd1-9f5c-6f0fa4e7b0e6").is_ok());
+            assert!(matches!(
+                validator.parse_str("a866664ac9704cd19f5c6f0fa4e7b0e6"),
+                Err(UuidError::SeparatorMust)
+            ));
+            assert!(matches!(
+                validator.parse_str("A866664A-C970-4CD1-9F5C-6F0FA4E7B0E6"),
+                Err(UuidError::Invalid)
+            ));
+        },
+        _ => unreachable!(),
+    }
+
+    match config.get("device") {
+        Some(RuntimeValidator::MacAddress(validator)) => {
+            assert_eq!(0x08002B010203, validator.parse_str("08002b010203").unwrap());
+            assert!(matches!(
+                validator.parse_str("08:00:2b:01:02:03"),
+                Err(MacAddressError::SeparatorDisallow)
+            ));
+        },
+        _ => unreachable!(),
+    }
+
+    let error = ValidatorConfig::from_toml(
+        "[validators.id]\nkind = \"uuid\"\nseparator = { Must = \"--\" }",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        "expected `validators.id.separator.Must = a single ASCII character`",
+        error.to_string()
+    );
+
+    let error =
+        ValidatorConfig::from_toml("[validators.id]\nkind = \"uuid\"\nseparator = \"Must\"")
+            .unwrap_err();
+
+    assert_eq!(
+        "expected `validators.id.separator = \"Disallow\"/{ Must = \"..\" }/{ Allow = \"..\" }`",
+        error.to_string()
+    );
+}
+
+#[test]
+fn errors() {
+    let error = ValidatorConfig::from_toml(
+        r#"
+[validators.username]
+kind = "line"
+char_lenght = { min = 3 }
+"#,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        "unsupported parameter `validators.username.char_lenght`, available parameters:\n    \
+         kind\n    char_length\n    byte_length",
+        error.to_string()
+    );
+
+    let error = ValidatorConfig::from_toml(
+        r#"
+[validators.age]
+kind = "unsigned_integer"
+range = { Inside = { min = 0, maximum = 150 } }
+"#,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        "unsupported parameter `validators.age.range.Inside.maximum`, available parameters:\n    \
+         min\n    max\n    inclusive",
+        error.to_string()
+    );
+
+    let error =
+        ValidatorConfig::from_toml("[validators.username]\nkind = \"username\"").unwrap_err();
+
+    assert!(error.to_string().starts_with(
+        "unsupported validator `username` of `validators.username`, available validators:\n    \
+         email\n"
+    ));
+
+    assert!(matches!(
+        ValidatorConfig::from_toml("[validators.username]\nchar_length = { min = 3 }"),
+        Err(ConfigError::ParameterMissing(name)) if name == "validators.username.kind"
+    ));
+
+    assert!(matches!(
+        ValidatorConfig::from_toml(
+            "[validators.username]\nkind = \"line\"\nchar_length = {}\nbyte_length = {}"
+        ),
+        Err(ConfigError::ParameterReset(name)) if name == "validators.username.byte_length"
+    ));
+
+    let error = ValidatorConfig::from_toml("[validators.contact]\nkind = \"email\"\nlocal = true")
+        .unwrap_err();
+
+    assert_eq!(
+        "expected `validators.contact.local = \"Must\"/\"Allow\"/\"Disallow\"`",
+        error.to_string()
+    );
+
+    assert!(matches!(
+        ValidatorConfig::from_toml("[validators.age]\nkind = \"unsigned_integer\"\nrange = { Inside = { min = -1 } }"),
+        Err(ConfigError::IncorrectValue { name, .. }) if name == "validators.age.range.Inside.min"
+    ));
+    let error =
+        ValidatorConfig::from_toml("[validators.contact]\nkind = \"email\"\ncomment = \"Must\"")
+            .unwrap_err();
+
+    assert_eq!("expected `validators.contact.comment = \"Allow\"/\"Disallow\"`", error.to_string());
+
+    assert!(matches!(
+        ValidatorConfig::from_toml("[validators.contact]\nkind = \"email\"\nnon_ascii = \"Must\""),
+        Err(ConfigError::IncorrectValue { name, .. }) if name == "validators.contact.non_ascii"
+    ));
+}
+
+#[test]
+fn incorrect_options() {
+    for (toml, expected) in [
+        (
+            "kind = \"line\"\nchar_length = { min = 3, trimmed_min = 4 }",
+            OptionsError::TrimmedMinGreaterThanMin {
+                trimmed_min: 4, min: 3
+            },
+        ),
+        ("kind = \"line\"\nchar_length = { min = 4, max = 3 }", OptionsError::MinGreaterThanMax {
+            min: 4,
+            max: 3,
+        }),
+        (
+            "kind = \"line\"\nbyte_length = { trimmed_min = 4, max = 3 }",
+            OptionsError::TrimmedMinGreaterThanMax {
+                trimmed_min: 4, max: 3
+            },
+        ),
+        (
+            "kind = \"email\"\nip = \"Must\"\nat_least_two_labels = \"Disallow\"",
+            OptionsError::IPMustAtLeastTwoLabelsDisallow,
+        ),
+        (
+            "kind = \"number\"\nnan = \"Must\"\nrange = { Inside = { max = 1.0 } }",
+            OptionsError::NaNMustRangeInside,
+        ),
+        (
+            "kind = \"unsigned_integer\"\nrange = { Inside = { min = 2, max = 1 } }",
+            OptionsError::RangeMinMax {
+                inclusive: true
+            },
+        ),
+    ] {
+        match ValidatorConfig::from_toml(format!("[validators.field]\n{toml}")) {
+            Err(ConfigError::IncorrectOptions {
+                name,
+                error,
+            }) => {
+                assert_eq!("validators.field", name);
+                assert_eq!(expected, error);
+            },
+            _ => panic!("{toml}"),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "domain",
+    feature = "host",
+    feature = "length",
+    feature = "signed_integer"
+))]
+
+use std::fmt::{self, Display, Formatter};
+
+use validators::{errors::CustomError, models::Host, prelude::*};
+
+#[derive(Debug)]
+pub struct BlockedError;
+
+impl Display for BlockedError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("blocked")
+    }
+}
+
+impl ErrorCode for BlockedError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "blocked"
+    }
+}
+
+fn not_blocked(domain: &Domain) -> Result<(), BlockedError> {
+    if domain.0.ends_with("example.com") {
+        Err(BlockedError)
+    } else {
+        Ok(())
+    }
+}
+
+fn not_blocked_host(host: &HostWithPort) -> Result<(), BlockedError> {
+    match &host.host {
+        Host::Domain(domain) if domain == "localhost" && host.port == Some(22) => Err(BlockedError),
+        _ => Ok(()),
+    }
+}
+
+fn even(number: &EvenNumber) -> Result<(), BlockedError> {
+    if number.0 % 2 == 0 {
+        Ok(())
+    } else {
+        Err(BlockedError)
+    }
+}
+
+fn sorted(tags: &Tags) -> Result<(), BlockedError> {
+    if tags.0.windows(2).all(|w| w[0] <= w[1]) {
+        Ok(())
+    } else {
+        Err(BlockedError)
+    }
+}
+
+#[derive(Validator)]
+#[validator(domain(
+    ipv4(Disallow),
+    local(Disallow),
+    at_least_two_labels(Must),
+    port(Disallow),
+    custom = not_blocked
+))]
+pub struct Domain(pub String);
+
+#[derive(Validator)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow), custom = not_blocked_host))]
+pub struct HostWithPort {
+    pub host: Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Inside(min = -10, max = 10)), custom = even))]
+pub struct EvenNumber(i8);
+
+#[derive(Validator)]
+#[validator(length(max = 3, custom = sorted))]
+pub struct Tags(Vec<u8>);
+
+#[test]
+fn custom() {
+    assert_eq!("magiclen.org", Domain::parse_str("magiclen.org").unwrap().0);
+
+    assert!(matches!(Domain::parse_str("localhost"), Err(CustomError::Validator(_))));
+    assert!(matches!(Domain::parse_str("www.example.com"), Err(CustomError::Custom(_))));
+    assert_eq!("domain.local_disallow", Domain::validate_str("localhost").unwrap_err().code());
+    assert_eq!("blocked", Domain::validate_str("www.example.com").unwrap_err().code());
+    assert_eq!("blocked", Domain::validate_str("www.example.com").unwrap_err().to_string());
+
+    assert_eq!(Some(80), HostWithPort::parse_str("localhost:80").unwrap().port);
+    assert_eq!("blocked", HostWithPort::validate_str("localhost:22").unwrap_err().code());
+}
+
+#[test]
+fn integer() {
+    assert_eq!(4, EvenNumber::parse_i64(4).unwrap().0);
+    assert_eq!(-6, EvenNumber::parse_str("-6").unwrap().0);
+
+    assert_eq!("blocked", EvenNumber::validate_i32(3).unwrap_err().code());
+    assert_eq!("blocked", EvenNumber::validate_str("3").unwrap_err().code());
+    assert_eq!("signed_integer.too_large", EvenNumber::validate_i16(12).unwrap_err().code());
+}
+
+#[test]
+fn length() {
+    assert!(Tags::validate_collection(&vec![1, 2]).is_ok());
+    assert!(matches!(Tags::validate_collection(&vec![2, 1]), Err(CustomError::Custom(_))));
+    assert!(matches!(Tags::parse_collection(vec![1, 2, 3, 4]), Err(CustomError::Validator(_))));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use validators::serde_json;
+
+    assert!(serde_json::from_str::<Domain>("\"magiclen.org\"").is_ok());
+    assert!(serde_json::from_str::<Domain>("\"example.com\"").is_err());
+    assert!(serde_json::from_str::<EvenNumber>("-8").is_ok());
+    assert!(serde_json::from_str::<EvenNumber>("-7").is_err());
+}
@@ -0,0 +1,171 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "diesel",
+    feature = "boolean",
+    feature = "line",
+    feature = "mac_address",
+    feature = "number",
+    feature = "signed_integer",
+    feature = "uuid"
+))]
+
+use diesel::{prelude::*, sql_query, sqlite::SqliteConnection};
+use validators::prelude::*;
+
+diesel::table! {
+    users (id) {
+        id -> Text,
+        mac -> BigInt,
+        name -> Nullable<Text>,
+        score -> Integer,
+        ratio -> Double,
+        active -> Bool,
+    }
+}
+
+#[derive(Debug, Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(pub u128);
+
+#[derive(Debug, Validator)]
+#[validator(mac_address(case(Upper), separator(Must(b':'))))]
+pub struct MacAddress(pub u64);
+
+#[derive(Debug, Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Name(pub String);
+
+#[derive(Debug, Validator)]
+#[validator(signed_integer(range(Inside(min = 1, max = 100))))]
+pub struct Score(pub i32);
+
+#[derive(Debug, Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0.0, max = 1.0))))]
+pub struct Ratio(pub f64);
+
+#[derive(Debug, Validator)]
+#[validator(boolean)]
+pub struct Flag(pub bool);
+
+#[derive(Insertable, Queryable)]
+#[diesel(table_name = users)]
+struct User {
+    id:     Uuid,
+    mac:    MacAddress,
+    name:   Option<Name>,
+    score:  Score,
+    ratio:  Ratio,
+    active: Flag,
+}
+
+fn connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+    sql_query(
+        "CREATE TABLE users (id TEXT PRIMARY KEY NOT NULL, mac BIGINT NOT NULL, name TEXT, score \
+         INTEGER NOT NULL, ratio DOUBLE NOT NULL, active BOOLEAN NOT NULL)",
+    )
+    .execute(&mut connection)
+    .unwrap();
+
+    connection
+}
+
+#[test]
+fn round_trip() {
+    let mut connection = connection();
+
+    diesel::insert_into(users::table)
+        .values(User {
+            id:     Uuid::parse_str("a866664a-f9d3-4dde-89cb-182015fa4f41").unwrap(),
+            mac:    MacAddress::parse_str("08:00:27:B2:46:C3").unwrap(),
+            name:   Some(Name::parse_str("len").unwrap()),
+            score:  Score::parse_i128(42).unwrap(),
+            ratio:  Ratio::parse_f64(0.5).unwrap(),
+            active: Flag::parse_bool(true).unwrap(),
+        })
+        .execute(&mut connection)
+        .unwrap();
+
+    let (id, mac): (String, i64) =
+        users::table.select((users::id, users::mac)).first(&mut connection).unwrap();
+
+    assert_eq!("a866664a-f9d3-4dde-89cb-182015fa4f41", id);
+    assert_eq!(0x080027B246C3, mac);
+
+    let user: User = users::table
+        .filter(users::id.eq(Uuid::parse_str("a866664a-f9d3-4dde-89cb-182015fa4f41").unwrap()))
+        .first(&mut connection)
+        .unwrap();
+
+    assert_eq!(0xA866664AF9D34DDE89CB182015FA4F41, user.id.0);
+    assert_eq!(0x080027B246C3, user.mac.0);
+    assert_eq!("len", user.name.unwrap().0);
+    assert_eq!(42, user.score.0);
+    assert_eq!(0.5, user.ratio.0);
+    assert!(user.active.0);
+}
+
+#[test]
+fn invalid_row() {
+    let mut connection = connection();
+
+    sql_query("INSERT INTO users VALUES ('A866664A', -1, 'magiclen.org', 0, 2.0, 1)")
+        .execute(&mut connection)
+        .unwrap();
+
+    assert!(users::table.select(users::id).first::<Uuid>(&mut connection).is_err());
+    assert!(users::table.select(users::mac).first::<MacAddress>(&mut connection).is_err());
+    assert!(users::table.select(users::name).first::<Option<Name>>(&mut connection).is_err());
+    assert!(users::table.select(users::score).first::<Score>(&mut connection).is_err());
+    assert!(users::table.select(users::ratio).first::<Ratio>(&mut connection).is_err());
+    assert!(users::table.select(users::active).first::<Flag>(&mut connection).is_ok());
+}
+
+#[cfg(feature = "diesel_postgres")]
+mod postgres {
+    use diesel::{
+        debug_query,
+        deserialize::FromSql,
+        pg::{Pg, PgMetadataLookup, PgTypeMetadata},
+        query_builder::{bind_collector::RawBytesBindCollector, BindCollector},
+        sql_types,
+    };
+
+    use super::*;
+
+    diesel::table! {
+        devices (id) {
+            id -> Uuid,
+            mac -> BigInt,
+        }
+    }
+
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(&mut self, _type_name: &str, _schema: Option<&str>) -> PgTypeMetadata {
+            unreachable!()
+        }
+    }
+
+    fn from_sql<T: FromSql<sql_types::Uuid, Pg>>() {}
+
+    #[test]
+    fn uuid() {
+        from_sql::<Uuid>();
+
+        let uuid = Uuid::parse_str("a866664a-f9d3-4dde-89cb-182015fa4f41").unwrap();
+
+        let mut collector = RawBytesBindCollector::<Pg>::new();
+
+        collector.push_bound_value::<sql_types::Uuid, _>(&uuid, &mut NoLookup).unwrap();
+
+        let query = devices::table.select(devices::mac).filter(devices::id.eq(&uuid));
+
+        assert_eq!(
+            r#"SELECT "devices"."mac" FROM "devices" WHERE ("devices"."id" = $1) -- binds: [Uuid(223841992317417236112672951818652372801)]"#,
+            debug_query::<Pg, _>(&query).to_string()
+        );
+    }
+}
@@ -10,6 +10,16 @@ fn basic() {
             let test = $test;
 
             test("", false);
+            test(
+                "\u{2082}.\u{2082}.\u{2082}.\u{2082}",
+                !Validator::V_IPV4.must()
+                    && !Validator::V_LOCAL.must()
+                    && !Validator::V_AT_LEAST_TWO_LABELS.must()
+                    && Validator::V_IPV4.allow()
+                    && Validator::V_LOCAL.allow()
+                    && Validator::V_AT_LEAST_TWO_LABELS.allow()
+                    && !Validator::V_PORT.must(),
+            );
             test(
                 "127.0.0.1",
                 Validator::V_IPV4.allow()
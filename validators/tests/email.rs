@@ -10,6 +10,7 @@ fn basic() {
             let test = $test;
 
             test("", false);
+            test("simple@example.\u{ad}", false);
             test("Abc.example.com", false);
             test("A@b@c@example.com", false);
             test("a\"b(c)d,e:f;g<h>i[j\\k]l@example.com", false);
@@ -453,3 +454,41 @@ fn basic() {
         },
     }
 }
+
+#[test]
+fn to_email_string() {
+    #[derive(Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Allow),
+        local(Allow),
+        at_least_two_labels(Allow),
+        non_ascii(Allow)
+    ))]
+    pub struct Validator {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: validators::models::Host,
+    }
+
+    let email = Validator::parse_str("\"joh\\\"n\"@example.com").unwrap();
+
+    assert_eq!("\"joh\\\"n\"@example.com", email.to_email_string());
+    assert!(Validator::parse_str(email.to_email_string()).is_ok());
+}
+
+#[test]
+fn non_ascii_local_part_length() {
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), ip(Allow), local(Allow), non_ascii(Allow)))]
+    pub struct Validator {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: validators::models::Host,
+    }
+
+    // the length of the local part is also limited when it has non-ASCII characters
+    assert!(Validator::parse_str(format!("{}@example.com", "\u{3bf}".repeat(32))).is_ok());
+    assert!(Validator::parse_str(format!("\"{}\"@example.com", "\u{3bf}".repeat(40))).is_err());
+    assert!(Validator::parse_str(format!("{}@example.com", "\u{3bf}".repeat(40))).is_err());
+}
@@ -0,0 +1,160 @@
+#![cfg(all(feature = "derive", feature = "std", feature = "host", feature = "unsigned_integer"))]
+
+use std::error::Error;
+
+use validators::{
+    env::{self, Vars},
+    errors::{EnvError, HostError, UnsignedIntegerError},
+    models::Host,
+    prelude::*,
+};
+
+#[derive(Debug, Validator)]
+#[validator(host(local(Allow), port(Must), at_least_two_labels(Allow)))]
+pub struct HostMustPort {
+    pub host: Host,
+    pub port: u16,
+}
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 64))))]
+pub struct Workers(pub u8);
+
+#[derive(Debug, Validate)]
+pub struct Config {
+    pub database_host: HostMustPort,
+    pub workers:       Option<Workers>,
+    #[validate(rename = "CACHE")]
+    pub cache_host:    Option<HostMustPort>,
+}
+
+#[test]
+fn var() {
+    std::env::set_var("TEST_ENV_VAR_HOST", "localhost:5432");
+    std::env::set_var("TEST_ENV_VAR_NO_PORT", "localhost");
+
+    let host = env::var::<HostMustPort>("TEST_ENV_VAR_HOST").unwrap();
+
+    assert_eq!(5432, host.port);
+
+    let error = env::var::<HostMustPort>("TEST_ENV_VAR_NO_PORT").unwrap_err();
+
+    assert_eq!("TEST_ENV_VAR_NO_PORT", error.name());
+    assert!(matches!(error.validator_error(), Some(HostError::PortMust)));
+    assert_eq!("host.port_must", error.code());
+    assert!(error.source().is_some());
+    assert_eq!(
+        format!("environment variable `TEST_ENV_VAR_NO_PORT` is invalid: {}", HostError::PortMust),
+        error.to_string()
+    );
+
+    let error = env::var::<Workers>("TEST_ENV_VAR_MISSING").unwrap_err();
+
+    assert!(matches!(error, EnvError::NotPresent { .. }));
+    assert_eq!("env.not_present", error.code());
+    assert!(error.source().is_none());
+    assert_eq!("environment variable `TEST_ENV_VAR_MISSING` is not set", error.to_string());
+}
+
+#[test]
+fn var_opt() {
+    std::env::set_var("TEST_ENV_VAR_OPT_WORKERS", "65");
+
+    assert!(env::var_opt::<Workers>("TEST_ENV_VAR_OPT_MISSING").unwrap().is_none());
+    assert!(matches!(
+        env::var_opt::<Workers>("TEST_ENV_VAR_OPT_WORKERS").unwrap_err().validator_error(),
+        Some(UnsignedIntegerError::TooLarge { .. })
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn not_unicode() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    std::env::set_var("TEST_ENV_NOT_UNICODE", OsStr::from_bytes(b"\xff"));
+
+    let error = env::var::<Workers>("TEST_ENV_NOT_UNICODE").unwrap_err();
+
+    assert!(matches!(error, EnvError::NotUnicode { .. }));
+    assert_eq!("env.not_unicode", error.code());
+
+    std::env::set_var("TEST_ENV_VARS_NOT_UNICODE_DATABASE_HOST", "db:5432");
+    std::env::set_var("TEST_ENV_VARS_NOT_UNICODE_WORKERS", OsStr::from_bytes(b"\xff"));
+
+    let errors =
+        Config::validate_fields(&Vars::with_prefix("TEST_ENV_VARS_NOT_UNICODE_")).unwrap_err();
+
+    assert_eq!(1, errors.len());
+    assert_eq!("TEST_ENV_VARS_NOT_UNICODE_WORKERS", errors.errors()[0].0.to_string());
+    assert_eq!(Some("field.not_unicode"), errors.code(0));
+}
+
+#[test]
+fn vars() {
+    std::env::set_var("TEST_ENV_VARS_OK_DATABASE_HOST", "db:5432");
+    std::env::set_var("TEST_ENV_VARS_OK_workers", "4");
+
+    let config = Config::parse_fields(&Vars::with_prefix("TEST_ENV_VARS_OK_")).unwrap();
+
+    assert_eq!(5432, config.database_host.port);
+    assert_eq!(4, config.workers.unwrap().0);
+    assert!(config.cache_host.is_none());
+
+    std::env::set_var("TEST_ENV_VARS_BAD_WORKERS", "0");
+    std::env::set_var("TEST_ENV_VARS_BAD_CACHE", "cache");
+
+    let errors = Config::parse_fields(&Vars::with_prefix("TEST_ENV_VARS_BAD_")).unwrap_err();
+
+    let fields = errors.iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            "TEST_ENV_VARS_BAD_DATABASE_HOST",
+            "TEST_ENV_VARS_BAD_WORKERS",
+            "TEST_ENV_VARS_BAD_CACHE"
+        ],
+        fields
+    );
+}
+
+#[test]
+fn lookup_field() {
+    use std::borrow::Cow;
+
+    use validators::traits::FieldInput;
+
+    std::env::set_var("TEST_ENV_LOOKUP_workers", "4");
+    std::env::set_var("TEST_ENV_LOOKUP_DATABASE_HOST", "db:5432");
+
+    let vars = Vars::with_prefix("TEST_ENV_LOOKUP_");
+
+    assert_eq!(
+        FieldInput::Present {
+            name: Cow::Borrowed("TEST_ENV_LOOKUP_workers"), value: "4"
+        },
+        vars.lookup_field("workers")
+    );
+    assert_eq!(
+        FieldInput::Present {
+            name:  Cow::Borrowed("TEST_ENV_LOOKUP_DATABASE_HOST"),
+            value: "db:5432",
+        },
+        vars.lookup_field("database_host")
+    );
+    assert_eq!(
+        FieldInput::Missing {
+            name: Cow::Borrowed("TEST_ENV_LOOKUP_CACHE")
+        },
+        vars.lookup_field("cache")
+    );
+
+    let map = [("workers", "4")];
+
+    assert_eq!(
+        FieldInput::Missing {
+            name: Cow::Borrowed("cache")
+        },
+        map[..].lookup_field("cache")
+    );
+}
@@ -0,0 +1,32 @@
+#![cfg(all(feature = "derive", feature = "email", feature = "text", feature = "number"))]
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+#[validator(text(char_length(max = 3)))]
+pub struct Text(pub String);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Outside(min = 0, max = 1))))]
+pub struct Number(pub f64);
+
+#[test]
+fn codes() {
+    assert_eq!("email.invalid", Email::parse_str("len").err().unwrap().code());
+    assert_eq!("email.local_disallow", Email::parse_str("len@localhost").err().unwrap().code());
+
+    assert_eq!("text.too_long", Text::parse_str("1234").err().unwrap().code());
+    assert_eq!("text.invalid", Text::parse_str("\0").err().unwrap().code());
+
+    assert_eq!("number.invalid", Number::parse_str("x").err().unwrap().code());
+    assert_eq!("number.nan_disallow", Number::parse_f64(f64::NAN).err().unwrap().code());
+    assert_eq!("number.forbidden", Number::parse_f64(0.5).err().unwrap().code());
+}
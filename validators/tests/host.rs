@@ -10,6 +10,7 @@ fn basic() {
             let test = $test;
 
             test("", false);
+            test("example.\u{ad}", false);
             test(
                 "127.0.0.1",
                 Validator::V_LOCAL.allow()
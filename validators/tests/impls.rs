@@ -0,0 +1,83 @@
+#![cfg(all(feature = "derive", feature = "domain", feature = "line", feature = "uuid"))]
+
+use std::{borrow::Cow, collections::HashSet, convert::TryFrom, str::FromStr};
+
+use validators::prelude::*;
+
+#[derive(Validator, PartialEq, Eq, Hash)]
+#[validator(line(char_length(max = 16), impls(Display, FromStr, TryFrom, AsRef, Deref, Borrow)))]
+pub struct Name(String);
+
+#[derive(Validator)]
+#[validator(uuid(case(Upper), separator(Allow(b'-')), impls(Display, AsRef, Deref)))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(domain(
+    ipv4(Allow),
+    local(Allow),
+    at_least_two_labels(Allow),
+    port(Allow),
+    impls(Display, TryFrom)
+))]
+pub struct DomainWithPort {
+    pub domain: String,
+    port:       Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 16), impls(Display, TryFrom, Deref)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 16), impls(FromStr, TryFrom, AsRef)))]
+pub struct Title<'a>(Cow<'a, str>);
+
+#[test]
+fn string() {
+    let name = Name::from_str("Len").unwrap();
+
+    assert_eq!("Len", name.to_string());
+    assert_eq!("Len", name.as_ref() as &str);
+    assert_eq!(3, name.len());
+    assert!(Name::try_from("Len\n").is_err());
+    assert!(Name::try_from(String::from("Len")).is_ok());
+
+    let names: HashSet<Name> = ["Len", "Magic"].into_iter().map(|s| s.parse().unwrap()).collect();
+
+    assert!(names.contains("Magic"));
+}
+
+#[test]
+fn canonical_string() {
+    let uuid = Uuid::parse_str("A866664AF9E94B5C8A59E6B5A7A5D4C8").unwrap();
+
+    assert_eq!("A866664A-F9E9-4B5C-8A59-E6B5A7A5D4C8", uuid.to_string());
+    assert_eq!(0xA866664AF9E94B5C8A59E6B5A7A5D4C8, *uuid);
+    assert_eq!(&0xA866664AF9E94B5C8A59E6B5A7A5D4C8, uuid.as_ref());
+
+    let domain = DomainWithPort::try_from("MagicLen.org:8080").unwrap();
+
+    assert_eq!("magiclen.org:8080", domain.to_string());
+    assert_eq!(Some(8080), domain.port);
+}
+
+#[test]
+fn borrowed() {
+    let s = String::from("hello-world");
+
+    let slug = Slug::try_from(s.as_str()).unwrap();
+
+    assert_eq!("hello-world", slug.to_string());
+    assert!(slug.starts_with("hello"));
+
+    let title = Title::try_from("hello").unwrap();
+
+    assert!(matches!(title.0, Cow::Borrowed(_)));
+    assert_eq!("hello", title.as_ref());
+
+    let title: Title = "hello".parse().unwrap();
+
+    assert!(matches!(title.0, Cow::Owned(_)));
+    assert!(Title::try_from(String::from("hello\nworld")).is_err());
+}
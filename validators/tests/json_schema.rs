@@ -0,0 +1,120 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "schemars",
+    feature = "serde_json",
+    feature = "all",
+    feature = "domain",
+    feature = "email",
+    feature = "length",
+    feature = "line",
+    feature = "number",
+    feature = "regex",
+    feature = "signed_integer",
+    feature = "unsigned_integer",
+    feature = "uuid"
+))]
+
+use validators::{
+    prelude::*,
+    schemars::{gen::SchemaGenerator, JsonSchema},
+    serde_json::{self, json, Value},
+};
+
+#[derive(Validator)]
+#[validator(line(char_length(min = 1, max = 1000)))]
+pub struct Comment(String);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 16)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), at_least_two_labels(Must), port(Disallow)))]
+pub struct Domain(String);
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0, max = 1.5, inclusive = false))))]
+pub struct Ratio(f64);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Outside(min = -10, max = 10))))]
+pub struct FarFromZero(i8);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(max = 100))))]
+pub struct Percentage(u8);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[0-9a-f]+$"))]
+pub struct Hex(String);
+
+#[derive(Validator)]
+#[validator(length(min = 1, max = 3))]
+pub struct Tags(Vec<String>);
+
+#[derive(Validator)]
+#[validator(all(Hex, Comment))]
+pub struct HexComment(Hex);
+
+fn json_schema<T: JsonSchema>() -> Value {
+    let mut gen = SchemaGenerator::default();
+
+    serde_json::to_value(T::json_schema(&mut gen)).unwrap()
+}
+
+#[test]
+fn string() {
+    assert_eq!(
+        json!({ "type": "string", "minLength": 1, "maxLength": 1000 }),
+        json_schema::<Comment>()
+    );
+    assert_eq!(json!({ "type": "string", "maxLength": 16 }), json_schema::<Slug>());
+    assert_eq!(json!({ "type": "string", "format": "email" }), json_schema::<Email>());
+    assert_eq!(json!({ "type": "string", "format": "hostname" }), json_schema::<Domain>());
+    assert_eq!(json!({ "type": "string", "format": "uuid" }), json_schema::<Uuid>());
+    assert_eq!(json!({ "type": "string", "pattern": "^[0-9a-f]+$" }), json_schema::<Hex>());
+}
+
+#[test]
+fn number() {
+    assert_eq!(
+        json!({ "type": "number", "minimum": 0.0, "exclusiveMaximum": 1.5 }),
+        json_schema::<Ratio>()
+    );
+    assert_eq!(
+        json!({ "type": "integer", "not": { "minimum": -10.0, "maximum": 10.0 } }),
+        json_schema::<FarFromZero>()
+    );
+    assert_eq!(
+        json!({ "type": "integer", "minimum": 0.0, "maximum": 100.0 }),
+        json_schema::<Percentage>()
+    );
+}
+
+#[test]
+fn collection() {
+    assert_eq!(
+        json!({ "type": "array", "items": { "type": "string" }, "minItems": 1, "maxItems": 3 }),
+        json_schema::<Tags>()
+    );
+}
+
+#[test]
+fn combinator() {
+    assert_eq!(
+        json!({ "allOf": [{ "$ref": "#/definitions/Hex" }, { "$ref": "#/definitions/Comment" }] }),
+        json_schema::<HexComment>()
+    );
+}
@@ -18,3 +18,20 @@ fn basic() {
     assert!(NonEmptyVec::parse_collection(vec![]).is_err());
     assert!(Set::parse_collection(BTreeSet::new()).is_err());
 }
+
+#[test]
+fn error_context() {
+    use validators::errors::LengthError;
+
+    assert!(matches!(
+        NonEmptyVec::parse_collection(vec![]),
+        Err(LengthError::TooSmall {
+            min: 1, length: 0
+        })
+    ));
+
+    assert_eq!(
+        "collection is too large, must have at most 100 elements (got 101)",
+        Set::parse_collection((0..=100).collect()).err().unwrap().to_string()
+    );
+}
@@ -193,3 +193,37 @@ fn byte() {
         },
     }
 }
+
+#[test]
+fn over_length_before_trimmed_min() {
+    use validators::errors::{LengthUnit, LineError};
+
+    #[derive(Validator)]
+    #[validator(line(char_length(trimmed_min = 4, min = 4, max = 4)))]
+    pub struct CharLine(pub String);
+
+    #[derive(Validator)]
+    #[validator(line(byte_length(trimmed_min = 4, min = 4, max = 4)))]
+    pub struct ByteLine(pub String);
+
+    for (error, unit) in [
+        (CharLine::parse_str("  abcd  ").err().unwrap(), LengthUnit::Char),
+        (ByteLine::parse_str("  abcd  ").err().unwrap(), LengthUnit::Byte),
+    ] {
+        match error {
+            LineError::TooLong {
+                max,
+                length,
+                unit: error_unit,
+            } => {
+                assert_eq!(4, max);
+                assert_eq!(8, length);
+                assert_eq!(unit, error_unit);
+            },
+            error => panic!("{error:?}"),
+        }
+    }
+
+    assert!(matches!(CharLine::parse_str("        "), Err(LineError::TooLong { .. })));
+    assert!(CharLine::parse_str("abcd").is_ok());
+}
@@ -0,0 +1,107 @@
+#![cfg(all(
+    feature = "i18n",
+    feature = "derive",
+    feature = "email",
+    feature = "text",
+    feature = "number"
+))]
+
+use validators::{
+    errors::{EmailError, NumberError, TextError},
+    i18n::{self, Catalog},
+    prelude::*,
+    traits::DynError,
+};
+
+#[derive(Validator)]
+#[validator(text(char_length(trimmed_min = 2, max = 3)))]
+pub struct Text(pub String);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(max = 10))))]
+pub struct Number(pub f64);
+
+#[test]
+fn bundled_locales() {
+    assert_eq!("不可包含註解", EmailError::CommentDisallow.localize("zh-TW"));
+    assert_eq!("コメントを含めることはできません", EmailError::CommentDisallow.localize("ja"));
+    assert_eq!("must not contain comments", EmailError::CommentDisallow.localize("en"));
+}
+
+#[test]
+fn locale_fallback() {
+    // the separator and the case of locales do not matter
+    assert_eq!("不可包含註解", EmailError::CommentDisallow.localize("zh_tw"));
+    // regions fall back to their languages
+    assert_eq!("コメントを含めることはできません", EmailError::CommentDisallow.localize("ja-JP"));
+    // scripts do not hide the catalogs of their regions, and Traditional Chinese falls back to `zh-TW`
+    assert_eq!("不可包含註解", EmailError::CommentDisallow.localize("zh-Hant-TW"));
+    assert_eq!("不可包含註解", EmailError::CommentDisallow.localize("zh_Hant"));
+    assert_eq!("must not contain comments", EmailError::CommentDisallow.localize("zh-Hans-CN"));
+    // unknown locales fall back to English
+    assert_eq!("must not contain comments", EmailError::CommentDisallow.localize("xx"));
+
+    // messages without an English entry fall back to `Display`
+    let error = "abc".parse::<f64>().unwrap_err();
+
+    assert_eq!(error.to_string(), NumberError::from(error.clone()).localize("xx"));
+    assert_eq!("無效的數字", NumberError::from(error).localize("zh-TW"));
+}
+
+#[test]
+fn message_args() {
+    let error = Text::parse_str("abcde").err().unwrap();
+
+    assert!(matches!(error, TextError::TooLong { .. }));
+    assert_eq!(error.to_string(), error.localize("en"));
+    assert_eq!("文字過長，最多 3 個字元（實際為 5）", error.localize("zh-TW"));
+    assert_eq!("長すぎます。3 文字以下にしてください（入力値: 5）", error.localize("ja"));
+
+    let error = Text::parse_str(" a ").err().unwrap();
+
+    assert_eq!(error.to_string(), error.localize("en"));
+    assert_eq!("去除前後空白後文字過短，至少 2 個字元（實際為 1）", error.localize("zh-TW"));
+
+    let error = Number::parse_str("11").err().unwrap();
+
+    assert_eq!(error.to_string(), error.localize("en"));
+    assert_eq!("數字過大，最大為 10（實際為 11）", error.localize("zh-TW"));
+
+    // boxed errors keep their keys and arguments
+    let error: Box<dyn DynError> = Box::new(Text::parse_str(" a ").err().unwrap());
+
+    assert_eq!("去除前後空白後文字過短，至少 2 個字元（實際為 1）", error.localize("zh-TW"));
+}
+
+#[test]
+fn register_catalog() {
+    let mut catalog = Catalog::new();
+
+    catalog.insert("email.comment_disallow", "주석을 포함할 수 없습니다");
+
+    i18n::register_catalog("ko", catalog);
+
+    assert_eq!("주석을 포함할 수 없습니다", EmailError::CommentDisallow.localize("ko-KR"));
+    // missing messages fall back to English
+    assert_eq!("must not be local", EmailError::LocalDisallow.localize("ko"));
+
+    let catalog = Catalog::from_toml(
+        r#"
+[text]
+too_long = "{length}/{max} {unit}"
+
+[length_unit]
+char = "글자"
+"#,
+    )
+    .unwrap();
+
+    i18n::register_catalog("ko", catalog);
+
+    let error = Text::parse_str("abcde").err().unwrap();
+
+    assert_eq!("5/3 글자", error.localize("ko"));
+    assert!(i18n::locales().contains(&String::from("ko")));
+
+    assert!(Catalog::from_toml("text = 1").is_err());
+}
@@ -60,6 +60,7 @@ fn basic() {
                     test("080027b246c3", Validator::V_CASE.lower() && !Validator::V_SEPARATOR.must().is_some());
                     test("080027B246C3", Validator::V_CASE.upper() && !Validator::V_SEPARATOR.must().is_some());
                     test("080027b246C3", Validator::V_CASE.any() && !Validator::V_SEPARATOR.must().is_some());
+                    test(":::::::::::\n", false);
                 }
             )*
         }
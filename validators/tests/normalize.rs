@@ -0,0 +1,54 @@
+#![cfg(all(feature = "derive", feature = "normalize", feature = "email", feature = "line"))]
+
+use std::borrow::Cow;
+
+use validators::{
+    functions::normalize_str,
+    options::{NormalizationForm, NormalizeOption},
+    prelude::*,
+};
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), normalize(trim, lowercase)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+#[validator(line(
+    char_length(trimmed_min = 1),
+    normalize(strip_zero_width, nfkc, collapse_whitespace, trim)
+))]
+pub struct Name(pub String);
+
+#[test]
+fn normalize() {
+    let email = Email::parse_str("  Len@MagicLen.ORG \n").unwrap();
+
+    assert_eq!("len", email.local_part);
+    assert_eq!("magiclen.org", email.domain_part);
+    assert!(Email::validate_str(" len@magiclen.org ").is_ok());
+
+    assert_eq!("Magic Len", Name::parse_string(" Magic\u{3000}\u{200B} \tLen ").unwrap().0);
+    assert_eq!("len", Name::parse_str("ｌｅｎ").unwrap().0);
+    assert!(Name::parse_str(" \u{200B} ").is_err());
+}
+
+#[test]
+fn normalize_str_borrows() {
+    let option = NormalizeOption {
+        trim:                true,
+        collapse_whitespace: true,
+        lowercase:           true,
+        form:                Some(NormalizationForm::NFC),
+        strip_zero_width:    true,
+    };
+
+    assert!(matches!(
+        normalize_str(Cow::Borrowed("  magic len "), option),
+        Cow::Borrowed("magic len")
+    ));
+    assert_eq!("é", normalize_str(Cow::Borrowed("E\u{301}"), option));
+}
@@ -297,3 +297,37 @@ fn basic() {
         },
     }
 }
+
+#[test]
+fn error_context() {
+    use validators::errors::NumberError;
+
+    #[derive(Validator)]
+    #[validator(number(range(Inside(min = 1, max = 10, inclusive = false))))]
+    pub struct Number(pub f64);
+
+    #[derive(Validator)]
+    #[validator(number(range(Outside(min = 1, max = 10))))]
+    pub struct NumberOutside(pub f64);
+
+    assert!(matches!(
+        Number::parse_f64(0.5),
+        Err(NumberError::TooSmall {
+            min, value
+        }) if min == 1.0 && value == 0.5
+    ));
+
+    assert!(matches!(
+        Number::parse_f64(10.0),
+        Err(NumberError::TooLarge {
+            max,
+            inclusive: false,
+            value,
+        }) if max == 10.0 && value == 10.0
+    ));
+
+    assert_eq!(
+        "number is forbidden, must not be in 1..=10 (got 2.5)",
+        NumberOutside::parse_f64(2.5).err().unwrap().to_string()
+    );
+}
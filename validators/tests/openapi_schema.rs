@@ -0,0 +1,155 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "utoipa",
+    feature = "serde_json",
+    feature = "any",
+    feature = "boolean",
+    feature = "email",
+    feature = "ipv4",
+    feature = "length",
+    feature = "line",
+    feature = "number",
+    feature = "signed_integer",
+    feature = "unsigned_integer",
+    feature = "uuid"
+))]
+
+use std::collections::HashMap;
+
+use validators::{
+    prelude::*,
+    serde_json::{self, json, Value},
+    utoipa::{PartialSchema, ToSchema},
+};
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(std::net::Ipv4Addr);
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(line(char_length(min = 1, max = 64)))]
+pub struct Name(String);
+
+#[derive(Validator)]
+#[validator(boolean)]
+pub struct Flag(bool);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 100))))]
+pub struct Count(u8);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Outside(min = -10, max = 10))))]
+pub struct FarFromZero(i8);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(max = 1.5, inclusive = false))))]
+pub struct Ratio(f64);
+
+#[derive(Validator)]
+#[validator(length(max = 3))]
+pub struct Tags(Vec<String>);
+
+#[derive(Validator)]
+#[validator(length(min = 1))]
+pub struct Labels(HashMap<String, String>);
+
+#[derive(Validator)]
+#[validator(any(Email, Uuid))]
+pub struct Account(String);
+
+fn schema<T: PartialSchema>() -> Value {
+    serde_json::to_value(T::schema()).unwrap()
+}
+
+#[test]
+fn string() {
+    assert_eq!(json!({ "type": "string", "format": "email" }), schema::<Email>());
+    assert_eq!(json!({ "type": "string", "format": "ipv4" }), schema::<Ipv4>());
+    assert_eq!(json!({ "type": "string", "format": "uuid" }), schema::<Uuid>());
+    assert_eq!(json!({ "type": "string", "minLength": 1, "maxLength": 64 }), schema::<Name>());
+}
+
+#[test]
+fn boolean() {
+    assert_eq!(
+        json!({
+            "oneOf": [
+                { "type": "boolean" },
+                {
+                    "type": "string",
+                    "enum": ["true", "false", "t", "f", "yes", "no", "y", "n", "on", "off", "1", "0"]
+                },
+                { "type": "integer", "enum": [0, 1] }
+            ]
+        }),
+        schema::<Flag>()
+    );
+}
+
+#[test]
+fn number() {
+    assert_eq!(json!({ "type": "integer", "minimum": 1, "maximum": 100 }), schema::<Count>());
+    assert_eq!(
+        json!({
+            "anyOf": [
+                { "type": "integer", "exclusiveMaximum": -10 },
+                { "type": "integer", "exclusiveMinimum": 10 }
+            ]
+        }),
+        schema::<FarFromZero>()
+    );
+    assert_eq!(json!({ "type": "number", "exclusiveMaximum": 1.5 }), schema::<Ratio>());
+}
+
+#[test]
+fn collection() {
+    assert_eq!(
+        json!({ "type": "array", "items": { "type": "string" }, "maxItems": 3 }),
+        schema::<Tags>()
+    );
+    assert_eq!(
+        json!({
+            "type": "object",
+            "additionalProperties": { "type": "string" },
+            "propertyNames": { "type": "string" },
+            "minProperties": 1
+        }),
+        schema::<Labels>()
+    );
+}
+
+#[test]
+fn combinator() {
+    assert_eq!("Account", Account::name());
+    assert_eq!(
+        json!({
+            "anyOf": [
+                { "$ref": "#/components/schemas/Email" },
+                { "$ref": "#/components/schemas/Uuid" }
+            ]
+        }),
+        schema::<Account>()
+    );
+
+    let mut schemas = Vec::new();
+
+    Account::schemas(&mut schemas);
+
+    assert_eq!(
+        vec!["Email", "Uuid"],
+        schemas.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,102 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "path"))]
+
+use std::{ffi::OsStr, path::PathBuf};
+
+use validators::{errors::PathError, functions::is_windows_reserved_name, prelude::*};
+
+#[derive(Validator)]
+#[validator(path)]
+pub struct AnyPath(pub PathBuf);
+
+#[derive(Validator)]
+#[validator(path(absolute(Must)))]
+pub struct AbsolutePath(pub PathBuf);
+
+#[derive(Validator)]
+#[validator(path(
+    absolute(Disallow),
+    root(Disallow),
+    parent(Disallow),
+    windows_reserved(Disallow),
+    extensions("txt", "md"),
+    component_length(max = 16)
+))]
+pub struct UploadPath(pub PathBuf);
+
+#[test]
+fn basic() {
+    assert!(matches!(AnyPath::validate_str(""), Err(PathError::Empty)));
+    assert!(matches!(AnyPath::validate_str("a\0b"), Err(PathError::ContainsNul)));
+    assert!(AnyPath::validate_str("a").is_ok());
+    assert!(AnyPath::validate_str("../CON/a.exe").is_ok());
+
+    assert!(matches!(AbsolutePath::validate_str("a/b"), Err(PathError::AbsoluteMust)));
+
+    assert!(UploadPath::validate_str("notes/a.txt").is_ok());
+    assert!(UploadPath::validate_str("./notes/README.MD").is_ok());
+    assert!(matches!(
+        UploadPath::validate_str("notes/../../a.txt"),
+        Err(PathError::ParentDisallow)
+    ));
+    assert!(matches!(UploadPath::validate_str("notes/a.exe"), Err(PathError::ExtensionNotAllowed)));
+    assert!(matches!(UploadPath::validate_str("notes"), Err(PathError::ExtensionNotAllowed)));
+    assert!(matches!(
+        UploadPath::validate_str("con/a.txt"),
+        Err(PathError::WindowsReservedDisallow)
+    ));
+    assert!(matches!(UploadPath::validate_str("Nul.txt"), Err(PathError::WindowsReservedDisallow)));
+    assert!(matches!(
+        UploadPath::validate_str("aaaaaaaaaaaaaaaaa/a.txt"),
+        Err(PathError::ComponentTooLong {
+            max: 16, length: 17
+        })
+    ));
+
+    assert_eq!(Some(&["txt", "md"][..]), UploadPath::V_EXTENSIONS);
+    assert_eq!(Some(16), UploadPath::V_COMPONENT_LENGTH_MAX);
+}
+
+#[cfg(unix)]
+#[test]
+fn unix() {
+    use std::os::unix::ffi::OsStrExt;
+
+    assert!(AbsolutePath::validate_str("/etc/passwd").is_ok());
+    assert!(matches!(UploadPath::validate_str("/etc/a.txt"), Err(PathError::AbsoluteDisallow)));
+
+    // a prefix is only a file name on Unix
+    assert!(UploadPath::validate_str("C:a.txt").is_ok());
+
+    let path = UploadPath::parse_os_str(OsStr::from_bytes(b"\xff.txt")).unwrap();
+
+    assert_eq!(OsStr::from_bytes(b"\xff.txt"), path.0.as_os_str());
+    assert!(matches!(
+        UploadPath::parse_os_string(OsStr::from_bytes(b"\xff/../a.txt")),
+        Err(PathError::ParentDisallow)
+    ));
+    assert!(matches!(
+        AnyPath::parse_os_str(OsStr::from_bytes(b"\xff\0.txt")),
+        Err(PathError::ContainsNul)
+    ));
+}
+
+#[cfg(windows)]
+#[test]
+fn windows() {
+    assert!(AbsolutePath::validate_str("C:\\Windows").is_ok());
+    assert!(matches!(UploadPath::validate_str("C:\\a.txt"), Err(PathError::AbsoluteDisallow)));
+    assert!(matches!(UploadPath::validate_str("\\a.txt"), Err(PathError::RootDisallow)));
+    assert!(matches!(UploadPath::validate_str("C:a.txt"), Err(PathError::RootDisallow)));
+    assert!(matches!(UploadPath::validate_str("a\\..\\b.txt"), Err(PathError::ParentDisallow)));
+}
+
+#[test]
+fn windows_reserved_name() {
+    for name in ["CON", "con", "nul.txt", "NUL .tar.gz", "COM1", "lpt9.log", "COM¹"] {
+        assert!(is_windows_reserved_name(name), "{name}");
+    }
+
+    for name in ["CONSOLE", "NULL.txt", "COM", "COM10", "LPT", "a.CON", ".nul"] {
+        assert!(!is_windows_reserved_name(name), "{name}");
+    }
+}
@@ -0,0 +1,118 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "email",
+    feature = "ipv4",
+    feature = "number",
+    feature = "uuid",
+    feature = "serde_json"
+))]
+
+use std::net::Ipv4Addr;
+
+use serde_json::json;
+use validators::{
+    options::TriAllow,
+    prelude::*,
+    registry::Registry,
+    runtime::EmailValidator,
+    traits::{DynError, DynValidator},
+};
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+#[validator(uuid(case(Any), separator(Allow(b'-'))))]
+pub struct Uuid(pub u128);
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(pub Ipv4Addr);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0, max = 1))))]
+pub struct Ratio(pub f64);
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry.register::<Email, _>("email");
+    registry.register::<Uuid, _>("uuid");
+    registry.register::<Ipv4, _>("ipv4");
+    registry.register::<Ratio, _>("ratio");
+
+    registry
+}
+
+fn code(result: Result<(), Box<dyn DynError>>) -> Option<&'static str> {
+    result.err().map(|error| error.code())
+}
+
+#[test]
+fn validate_str() {
+    let registry = registry();
+
+    assert_eq!(4, registry.len());
+    assert_eq!(vec!["email", "ipv4", "ratio", "uuid"], registry.names().collect::<Vec<_>>());
+
+    let rows = [
+        ("email", "len@magiclen.org", None),
+        ("email", "len", Some("email.invalid")),
+        ("uuid", "a866664a-f9d3-4dde-89cb-182015fa4f41", None),
+        ("uuid", "len@magiclen.org", Some("uuid.invalid")),
+        ("ipv4", "127.0.0.1", None),
+        ("ipv4", "127.0.0.1:80", Some("ipv4.port_disallow")),
+        ("ratio", "0.5", None),
+        ("ratio", "2", Some("number.too_large")),
+        ("phone", "0912345678", Some("dyn_validator.unknown_validator")),
+    ];
+
+    for (name, s, expected) in rows {
+        assert_eq!(expected, code(registry.validate_str(name, s)), "{name}: {s}");
+    }
+
+    assert_eq!(
+        "unknown validator `phone`",
+        registry.validate_str("phone", "0912345678").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn validate_json_value() {
+    let registry = registry();
+
+    assert_eq!(None, code(registry.validate_json_value("email", &json!("len@magiclen.org"))));
+    assert_eq!(None, code(registry.validate_json_value("ratio", &json!(0.5))));
+    assert_eq!(Some("number.too_large"), code(registry.validate_json_value("ratio", &json!(2))));
+    assert_eq!(
+        Some("dyn_validator.unsupported_value"),
+        code(registry.validate_json_value("ratio", &json!(null)))
+    );
+    assert_eq!(
+        Some("dyn_validator.unsupported_value"),
+        code(registry.validate_json_value("email", &json!(["len@magiclen.org"])))
+    );
+}
+
+#[test]
+fn runtime_validators() {
+    let mut registry = registry();
+
+    let previous = registry
+        .insert("email", EmailValidator::builder().local(TriAllow::Disallow).build().unwrap());
+
+    assert!(previous.is_some());
+    assert_eq!(Some("email.local_disallow"), code(registry.validate_str("email", "len@localhost")));
+
+    let validator: &dyn DynValidator = registry.get("email").unwrap();
+
+    assert_eq!(None, code(validator.validate_str("len@magiclen.org")));
+
+    assert!(registry.remove("email").is_some());
+    assert!(!registry.contains("email"));
+}
@@ -0,0 +1,378 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "email",
+    feature = "host",
+    feature = "http_ftp_url",
+    feature = "mac_address",
+    feature = "number",
+    feature = "signed_integer",
+    feature = "unsigned_integer",
+    feature = "uuid"
+))]
+
+use validators::{
+    errors::OptionsError,
+    models::{Host, Protocol},
+    options::{CaseOption, RangeOption, SeparatorOption, TriAllow},
+    prelude::*,
+    runtime::{
+        EmailValidator, HostValidator, HttpFtpUrlValidator, MacAddressValidator, NumberValidator,
+        SignedIntegerValidator, UnsignedIntegerValidator, UuidValidator,
+    },
+};
+
+const EMAILS: [&str; 11] = [
+    "len@magiclen.org",
+    "len@localhost",
+    "len@[127.0.0.1]",
+    "len@[IPv6:::1]",
+    "len@[8.8.8.8]",
+    "len@magiclen",
+    "len(comment)@magiclen.org",
+    "len@管理員.台灣",
+    "len@foo..bar",
+    "len",
+    "",
+];
+
+const HOSTS: [&str; 13] = [
+    "magiclen.org",
+    "magiclen.org:8080",
+    "localhost",
+    "127.0.0.1:80",
+    "[::1]:443",
+    "[::1]",
+    "[::1]:abc",
+    "8.8.8.8",
+    "magiclen",
+    "magiclen.org:abc",
+    "foo..bar",
+    "foo..bar:80",
+    "",
+];
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Disallow),
+    ip(Allow),
+    local(Disallow),
+    at_least_two_labels(Must),
+    non_ascii(Disallow)
+))]
+pub struct StrictEmail {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: Host,
+}
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Allow),
+    ip(Must),
+    local(Allow),
+    at_least_two_labels(Allow),
+    non_ascii(Allow)
+))]
+pub struct IpEmail {
+    pub local_part:                 String,
+    pub need_quoted:                bool,
+    pub domain_part:                std::net::IpAddr,
+    pub comment_before_local_part:  Option<String>,
+    pub comment_after_local_part:   Option<String>,
+    pub comment_before_domain_part: Option<String>,
+    pub comment_after_domain_part:  Option<String>,
+}
+
+#[derive(Validator)]
+#[validator(host(local(Must), port(Must), at_least_two_labels(Allow)))]
+pub struct LocalHostWithPort {
+    pub host: Host,
+    pub port: u16,
+}
+
+#[derive(Validator)]
+#[validator(host(local(Allow), port(Disallow), at_least_two_labels(Disallow)))]
+pub struct SingleLabelHost {
+    pub host:     Host,
+    pub is_local: bool,
+}
+
+#[test]
+fn email() {
+    let strict = EmailValidator::builder()
+        .comment(TriAllow::Disallow)
+        .local(TriAllow::Disallow)
+        .at_least_two_labels(TriAllow::Must)
+        .non_ascii(TriAllow::Disallow)
+        .build()
+        .unwrap();
+
+    let ip = EmailValidator::builder().ip(TriAllow::Must).build().unwrap();
+
+    for s in EMAILS {
+        assert_eq!(
+            StrictEmail::parse_str(s).err().map(|error| error.code()),
+            strict.parse_str(s).err().map(|error| error.code()),
+            "{s}"
+        );
+        assert_eq!(
+            IpEmail::parse_str(s).err().map(|error| error.code()),
+            ip.parse_str(s).err().map(|error| error.code()),
+            "{s}"
+        );
+    }
+
+    let email =
+        EmailValidator::builder().build().unwrap().parse_str("len(comment)@localhost").unwrap();
+
+    assert_eq!("len", email.local_part);
+    assert_eq!(Host::Domain(String::from("localhost")), email.domain_part);
+    assert_eq!(Some("comment"), email.comment_after_local_part.as_deref());
+    assert!(email.is_local);
+}
+
+#[test]
+fn host() {
+    let local_with_port =
+        HostValidator::builder().local(TriAllow::Must).port(TriAllow::Must).build().unwrap();
+
+    let single_label = HostValidator::builder()
+        .port(TriAllow::Disallow)
+        .at_least_two_labels(TriAllow::Disallow)
+        .build()
+        .unwrap();
+
+    for s in HOSTS {
+        assert_eq!(
+            LocalHostWithPort::parse_str(s).err().map(|error| error.code()),
+            local_with_port.parse_str(s).err().map(|error| error.code()),
+            "{s}"
+        );
+        assert_eq!(
+            SingleLabelHost::parse_str(s).err().map(|error| error.code()),
+            single_label.parse_str(s).err().map(|error| error.code()),
+            "{s}"
+        );
+    }
+
+    let host = local_with_port.parse_str("[::1]:443").unwrap();
+
+    assert_eq!(Host::IPv6(std::net::Ipv6Addr::LOCALHOST), host.host);
+    assert_eq!(Some(443), host.port);
+    assert!(host.is_local);
+}
+
+#[test]
+fn http_ftp_url() {
+    let validator = HttpFtpUrlValidator::builder().local(TriAllow::Must).build().unwrap();
+
+    let url = validator.parse_str("https://localhost:8000/path").unwrap();
+
+    assert_eq!(Protocol::HTTPS, url.protocol);
+    assert_eq!(Some(8000), url.url.port());
+    assert!(url.is_local);
+
+    assert_eq!(
+        "http_ftp_url.local_must",
+        validator.parse_str("ftp://magiclen.org").err().unwrap().code()
+    );
+    assert_eq!(
+        "http_ftp_url.protocol_disallow",
+        validator.parse_str("ssh://localhost").err().unwrap().code()
+    );
+}
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct HyphenatedUuid(u128);
+
+#[derive(Validator)]
+#[validator(uuid(case(Upper), separator(Disallow)))]
+pub struct SimpleUuid(u128);
+
+#[derive(Validator)]
+#[validator(mac_address(separator(Allow(b'-'))))]
+pub struct DashMacAddress(u64);
+
+#[test]
+fn uuid() {
+    let hyphenated = UuidValidator::builder()
+        .case(CaseOption::Lower)
+        .separator(SeparatorOption::Must(b'-'))
+        .build()
+        .unwrap();
+
+    let simple = UuidValidator::builder()
+        .case(CaseOption::Upper)
+        .separator(SeparatorOption::Disallow)
+        .build()
+        .unwrap();
+
+    for s in [
+        "a866664a-c970-4cd1-9f5c-6f0fa4e7b0e6",
+        "A866664A-C970-4CD1-9F5C-6F0FA4E7B0E6",
+        "a866664ac9704cd19f5c6f0fa4e7b0e6",
+        "A866664AC9704CD19F5C6F0FA4E7B0E6",
+        "a866664a-c9704cd1-9f5c-6f0fa4e7b0e6",
+        "a866664a_c970_4cd1_9f5c_6f0fa4e7b0e6",
+        "g866664ac9704cd19f5c6f0fa4e7b0e6",
+        "",
+    ] {
+        assert_eq!(
+            HyphenatedUuid::parse_str(s).map(|uuid| uuid.0).map_err(|error| error.code()),
+            hyphenated.parse_str(s).map_err(|error| error.code()),
+            "{s}"
+        );
+        assert_eq!(
+            SimpleUuid::parse_str(s).map(|uuid| uuid.0).map_err(|error| error.code()),
+            simple.parse_str(s).map_err(|error| error.code()),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn mac_address() {
+    let dash =
+        MacAddressValidator::builder().separator(SeparatorOption::Allow(b'-')).build().unwrap();
+
+    for s in [
+        "08-00-2b-01-02-03",
+        "08-00-2B-01-02-03",
+        "08002b010203",
+        "08-002b-01-0203",
+        "08:00:2b:01:02:03",
+        "08-00-2b-01-02-0",
+        "08-00-2b-01-02-03-",
+        "",
+    ] {
+        assert_eq!(
+            DashMacAddress::parse_str(s)
+                .map(|mac_address| mac_address.0)
+                .map_err(|error| error.code()),
+            dash.parse_str(s).map_err(|error| error.code()),
+            "{s}"
+        );
+    }
+
+    assert_eq!(0x08002B010203, dash.parse_str("08-00-2b-01-02-03").unwrap());
+}
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = -1, max = 1, inclusive = false))))]
+pub struct Ratio(f64);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Outside(min = -10, max = 10))))]
+pub struct FarFromZero(i64);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 100))))]
+pub struct Percent(u8);
+
+#[test]
+fn numbers() {
+    let ratio = NumberValidator::builder()
+        .nan(TriAllow::Disallow)
+        .range(RangeOption::Inside {
+            min:       Some(-1.0),
+            max:       Some(1.0),
+            inclusive: false,
+        })
+        .build()
+        .unwrap();
+
+    for s in ["0", "-1", "1", "0.999", "-2", "NaN", "x"] {
+        assert_eq!(
+            Ratio::parse_str(s).err().map(|error| error.to_string()),
+            ratio.parse_str(s).err().map(|error| error.to_string()),
+            "{s}"
+        );
+    }
+
+    let far_from_zero = SignedIntegerValidator::builder()
+        .range(RangeOption::Outside {
+            min: Some(-10), max: Some(10), inclusive: true
+        })
+        .build()
+        .unwrap();
+
+    for s in ["-11", "-10", "0", "10", "11", "x"] {
+        assert_eq!(
+            FarFromZero::parse_str(s).err().map(|error| error.to_string()),
+            far_from_zero.parse_str(s).err().map(|error| error.to_string()),
+            "{s}"
+        );
+    }
+
+    let percent = UnsignedIntegerValidator::builder()
+        .range(RangeOption::Inside {
+            min: Some(1), max: Some(100), inclusive: true
+        })
+        .build()
+        .unwrap();
+
+    for s in ["0", "1", "100", "101", "-1"] {
+        assert_eq!(
+            Percent::parse_str(s).err().map(|error| error.to_string()),
+            percent.parse_str(s).err().map(|error| error.to_string()),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn conflicting_options() {
+    assert_eq!(
+        Some(OptionsError::IPMustAtLeastTwoLabelsDisallow),
+        EmailValidator::builder()
+            .ip(TriAllow::Must)
+            .at_least_two_labels(TriAllow::Disallow)
+            .build()
+            .err()
+    );
+    assert_eq!(
+        Some(OptionsError::MustUnsupported {
+            option: "comment"
+        }),
+        EmailValidator::builder().comment(TriAllow::Must).build().err()
+    );
+    assert_eq!(
+        Some(OptionsError::MustUnsupported {
+            option: "non_ascii"
+        }),
+        EmailValidator::builder().non_ascii(TriAllow::Must).build().err()
+    );
+
+    assert_eq!(
+        Some(OptionsError::NaNMustRangeInside),
+        NumberValidator::builder()
+            .nan(TriAllow::Must)
+            .range(RangeOption::Inside {
+                min: Some(0.0), max: None, inclusive: true
+            })
+            .build()
+            .err()
+    );
+
+    assert_eq!(
+        Some(OptionsError::RangeMinMax {
+            inclusive: false
+        }),
+        SignedIntegerValidator::builder()
+            .range(RangeOption::Outside {
+                min:       Some(1),
+                max:       Some(1),
+                inclusive: false,
+            })
+            .build()
+            .err()
+    );
+
+    assert!(UnsignedIntegerValidator::builder()
+        .range(RangeOption::Inside {
+            min: Some(1), max: Some(1), inclusive: true
+        })
+        .build()
+        .is_ok());
+}
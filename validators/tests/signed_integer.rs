@@ -248,3 +248,31 @@ fn basic() {
         },
     }
 }
+
+#[test]
+fn error_context() {
+    use validators::errors::SignedIntegerError;
+
+    #[derive(Validator)]
+    #[validator(signed_integer(range(Inside(min = -1, max = 100))))]
+    pub struct Score(pub i8);
+
+    assert!(matches!(
+        Score::parse_i8(-2),
+        Err(SignedIntegerError::TooSmall {
+            min: -1, value: -2
+        })
+    ));
+
+    assert!(matches!(
+        Score::parse_i128(1000),
+        Err(SignedIntegerError::TooLarge {
+            max: 127, inclusive: true, value: 1000
+        })
+    ));
+
+    assert_eq!(
+        "integer is too large, must be at most 100 (got 101)",
+        Score::parse_str("101").err().unwrap().to_string()
+    );
+}
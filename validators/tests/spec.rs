@@ -0,0 +1,96 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "all",
+    feature = "domain",
+    feature = "line",
+    feature = "number",
+    feature = "regex",
+    feature = "signed_integer",
+    feature = "uuid"
+))]
+
+use validators::{
+    models::SpecValue,
+    options::{CaseOption, RangeOption, SeparatorOption, TriAllow},
+    prelude::*,
+};
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 1000)))]
+pub struct Comment(String);
+
+#[derive(Validator)]
+#[validator(line(byte_length(min = 2, trimmed_min = 1)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), at_least_two_labels(Must), port(Disallow)))]
+pub struct Domain(String);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0, max = 1.5))))]
+pub struct Ratio(f32);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Outside(min = -10, max = 10))))]
+pub struct FarFromZero(i8);
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[0-9a-f]+$"))]
+pub struct Hex(String);
+
+#[derive(Validator)]
+#[validator(all(Hex, Comment, custom = not_empty))]
+pub struct HexComment(Hex);
+
+fn not_empty(_: &HexComment) -> Result<(), validators::errors::LineError> {
+    Ok(())
+}
+
+#[test]
+fn spec() {
+    assert_eq!("line", Comment::SPEC.validator);
+    assert_eq!(Some(&SpecValue::Length(Some(1000))), Comment::SPEC.get("char_length_max"));
+    assert_eq!(Some(&SpecValue::Length(None)), Comment::SPEC.get("char_length_min"));
+
+    assert_eq!(Some(&SpecValue::Length(Some(1))), Slug::SPEC.get("byte_length_trimmed_min"));
+    assert_eq!(None, Slug::SPEC.get("char_length_max"));
+
+    assert_eq!("domain", Domain::SPEC.validator);
+    assert_eq!(Some(&SpecValue::TriAllow(TriAllow::Disallow)), Domain::SPEC.get("local"));
+    assert_eq!(Some(&SpecValue::TriAllow(TriAllow::Must)), Domain::SPEC.get("at_least_two_labels"));
+
+    assert_eq!(Some(&SpecValue::TriAllow(TriAllow::Disallow)), Ratio::SPEC.get("nan"));
+    assert_eq!(
+        Some(&SpecValue::NumberRange(RangeOption::Inside {
+            min:       Some(0.0),
+            max:       Some(1.5),
+            inclusive: true,
+        })),
+        Ratio::SPEC.get("range")
+    );
+
+    assert_eq!(
+        Some(&SpecValue::SignedRange(RangeOption::Outside {
+            min:       Some(-10),
+            max:       Some(10),
+            inclusive: true,
+        })),
+        FarFromZero::SPEC.get("range")
+    );
+
+    assert_eq!(Some(&SpecValue::Case(CaseOption::Lower)), Uuid::SPEC.get("case"));
+    assert_eq!(
+        Some(&SpecValue::Separator(SeparatorOption::Must(b'-'))),
+        Uuid::SPEC.get("separator")
+    );
+
+    assert_eq!(Some(&SpecValue::Str("^[0-9a-f]+$")), Hex::SPEC.get("regex"));
+
+    assert_eq!("all", HexComment::SPEC.validator);
+    assert_eq!(Some(&SpecValue::Strs(&["Hex", "Comment"])), HexComment::SPEC.get("validators"));
+}
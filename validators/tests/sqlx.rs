@@ -0,0 +1,105 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "sqlx",
+    feature = "boolean",
+    feature = "line",
+    feature = "mac_address",
+    feature = "number",
+    feature = "signed_integer",
+    feature = "uuid"
+))]
+
+use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(pub u128);
+
+#[derive(Validator)]
+#[validator(mac_address(case(Upper), separator(Must(b':'))))]
+pub struct MacAddress(pub u64);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Name(pub String);
+
+#[derive(Validator)]
+#[validator(signed_integer(range(Inside(min = 1, max = 100))))]
+pub struct Score(pub i32);
+
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0.0, max = 1.0))))]
+pub struct Ratio(pub f64);
+
+#[derive(Validator)]
+#[validator(boolean)]
+pub struct Flag(pub bool);
+
+async fn pool() -> SqlitePool {
+    let pool =
+        SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query(
+        "CREATE TABLE users (id TEXT, mac INTEGER, name TEXT, score INTEGER, ratio REAL, active \
+         BOOLEAN)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+#[tokio::test]
+async fn round_trip() {
+    let pool = pool().await;
+
+    sqlx::query("INSERT INTO users VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(Uuid::parse_str("a866664a-f9d3-4dde-89cb-182015fa4f41").unwrap())
+        .bind(MacAddress::parse_str("08:00:27:B2:46:C3").unwrap())
+        .bind(Name::parse_str("len").unwrap())
+        .bind(Score::parse_i128(42).unwrap())
+        .bind(Ratio::parse_f64(0.5).unwrap())
+        .bind(Flag::parse_bool(true).unwrap())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let row = sqlx::query("SELECT * FROM users").fetch_one(&pool).await.unwrap();
+
+    #[cfg(not(feature = "sqlx_uuid"))]
+    assert_eq!("a866664a-f9d3-4dde-89cb-182015fa4f41", row.get::<String, _>("id"));
+    #[cfg(feature = "sqlx_uuid")]
+    assert_eq!(
+        0xA866664AF9D34DDE89CB182015FA4F41u128.to_be_bytes().to_vec(),
+        row.get::<Vec<u8>, _>("id")
+    );
+    assert_eq!(0x080027B246C3, row.get::<i64, _>("mac"));
+
+    assert_eq!(0xA866664AF9D34DDE89CB182015FA4F41, row.get::<Uuid, _>("id").0);
+    assert_eq!(0x080027B246C3, row.get::<MacAddress, _>("mac").0);
+    assert_eq!("len", row.get::<Name, _>("name").0);
+    assert_eq!(42, row.get::<Score, _>("score").0);
+    assert_eq!(0.5, row.get::<Ratio, _>("ratio").0);
+    assert!(row.get::<Flag, _>("active").0);
+}
+
+#[tokio::test]
+async fn invalid_row() {
+    let pool = pool().await;
+
+    sqlx::query("INSERT INTO users VALUES ('A866664A', -1, 'magiclen.org', 0, 2.0, 1)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let row = sqlx::query("SELECT * FROM users").fetch_one(&pool).await.unwrap();
+
+    assert!(row.try_get::<Uuid, _>("id").is_err());
+    assert!(row.try_get::<MacAddress, _>("mac").is_err());
+    assert!(row.try_get::<Name, _>("name").is_err());
+    assert!(row.try_get::<Score, _>("score").is_err());
+    assert!(row.try_get::<Ratio, _>("ratio").is_err());
+    assert!(row.try_get::<Flag, _>("active").is_ok());
+}
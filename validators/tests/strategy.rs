@@ -0,0 +1,315 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "proptest",
+    feature = "all",
+    feature = "any",
+    feature = "base32",
+    feature = "base64",
+    feature = "base64_url_decoded",
+    feature = "boolean",
+    feature = "byte",
+    feature = "domain",
+    feature = "email",
+    feature = "host",
+    feature = "http_url",
+    feature = "ipv4",
+    feature = "ipv6",
+    feature = "json",
+    feature = "length",
+    feature = "line",
+    feature = "mac_address",
+    feature = "not",
+    feature = "number",
+    feature = "path",
+    feature = "phone",
+    feature = "regex",
+    feature = "semver",
+    feature = "signed_integer",
+    feature = "text",
+    feature = "unsigned_integer",
+    feature = "uuid"
+))]
+
+use std::{
+    net::{Ipv4Addr, Ipv6Addr},
+    path::PathBuf,
+};
+
+use validators::{
+    byte_unit::Byte,
+    models::Host,
+    phonenumber::PhoneNumber,
+    prelude::*,
+    proptest::{
+        arbitrary::{any, Arbitrary},
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    },
+    semver::Version,
+};
+
+#[derive(Debug, Validator)]
+#[validator(base32(padding(Must)))]
+pub struct Base32(String);
+
+#[derive(Debug, Validator)]
+#[validator(base64(padding(Must)))]
+pub struct Base64(String);
+
+#[derive(Debug, Validator)]
+#[validator(base64_url_decoded(padding(Disallow)))]
+pub struct Base64UrlDecoded(Vec<u8>);
+
+#[derive(Debug, Validator)]
+#[validator(boolean)]
+pub struct Boolean(bool);
+
+#[derive(Debug, Validator)]
+#[validator(byte(range(min = 1, max = 1024)))]
+pub struct SmallByte(Byte);
+
+#[derive(Debug, Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), at_least_two_labels(Must), port(Disallow)))]
+pub struct Domain(String);
+
+#[derive(Debug, Validator)]
+#[validator(domain(ipv4(Allow), local(Allow), at_least_two_labels(Allow), port(Must)))]
+pub struct DomainWithPort {
+    pub domain: String,
+    port:       u16,
+}
+
+#[derive(Debug, Validator)]
+#[validator(email(
+    comment(Disallow),
+    ip(Allow),
+    local(Disallow),
+    at_least_two_labels(Must),
+    non_ascii(Disallow)
+))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: Host,
+}
+
+#[derive(Debug, Validator)]
+#[validator(host(local(Allow), port(Allow), at_least_two_labels(Allow)))]
+pub struct HostAllowPort {
+    pub host: Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Debug, Validator)]
+#[validator(http_url(local(Disallow)))]
+pub struct HttpUrl {
+    pub url:      validators::url::Url,
+    pub is_https: bool,
+}
+
+#[derive(Debug, Validator)]
+#[validator(ipv4(local(Disallow), port(Disallow)))]
+pub struct Ipv4(Ipv4Addr);
+
+#[derive(Debug, Validator)]
+#[validator(ipv6(local(Must), port(Must)))]
+pub struct LocalIpv6WithPort {
+    pub ipv6: Ipv6Addr,
+    pub port: u16,
+}
+
+#[derive(Debug, Validator)]
+#[validator(json)]
+pub struct Json(String);
+
+#[derive(Debug, Validator)]
+#[validator(line(char_length(trimmed_min = 1, min = 1, max = 16)))]
+pub struct Title(String);
+
+#[derive(Debug, Validator)]
+#[validator(text(byte_length(max = 64)))]
+pub struct Note(String);
+
+#[derive(Debug, Validator)]
+#[validator(mac_address(case(Upper), separator(Must(b':'))))]
+pub struct MacAddress(u64);
+
+#[derive(Debug, Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0, max = 1.5, inclusive = false))))]
+pub struct Ratio(f64);
+
+#[derive(Debug, Validator)]
+#[validator(number(nan(Allow), range(Unlimited)))]
+pub struct Float(f32);
+
+#[derive(Debug, Validator)]
+#[validator(path(root(Disallow), parent(Disallow), windows_reserved(Disallow), extensions("txt")))]
+pub struct TextPath(PathBuf);
+
+#[derive(Debug, Validator)]
+#[validator(phone(countries(TW)))]
+pub struct TWPhone(PhoneNumber);
+
+#[derive(Debug, Validator)]
+#[validator(regex(regex = "^[0-9a-f]{4,8}$"))]
+pub struct Hex(String);
+
+#[derive(Debug, Validator)]
+#[validator(semver)]
+pub struct SemVer(Version);
+
+#[derive(Debug, Validator)]
+#[validator(signed_integer(range(Outside(min = -10, max = 10))))]
+pub struct FarFromZero(i8);
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Positive(u16);
+
+#[derive(Debug, Validator)]
+#[validator(uuid(case(Lower), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Debug, Validator)]
+#[validator(length(min = 1, max = 3))]
+pub struct Tags(Vec<u8>);
+
+#[derive(Debug, Validator)]
+#[validator(all(Hex, Title))]
+pub struct HexTitle(Hex);
+
+#[derive(Debug, Validator)]
+#[validator(any(Ipv4, Uuid))]
+pub struct Ipv4OrUuid(String);
+
+#[derive(Debug, Validator)]
+#[validator(not(Ipv4))]
+pub struct NotIpv4(String);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Slug<'a>(&'a str);
+
+#[derive(Validator)]
+#[validator(boolean)]
+pub struct BooleanWithoutDebug(bool);
+
+const CASES: usize = 64;
+
+fn sample<S: Strategy>(strategy: S) -> Vec<S::Value> {
+    let mut runner = TestRunner::deterministic();
+
+    (0..CASES).map(|_| strategy.new_tree(&mut runner).unwrap().current()).collect()
+}
+
+/// Check that the strategies of `T` can create enough values and that their values are accepted or rejected as described.
+fn check<T: ValidatorStrategy + ValidateString + Arbitrary>() -> Vec<String> {
+    let valid = sample(T::valid_strings());
+
+    for s in valid.iter() {
+        assert!(T::validate_str(s).is_ok(), "{s:?} should be valid");
+    }
+
+    for s in sample(T::invalid_strings()) {
+        assert!(T::validate_str(&s).is_err(), "{s:?} should be invalid");
+    }
+
+    sample(any::<T>());
+
+    valid
+}
+
+#[test]
+fn encoded() {
+    for s in check::<Base32>() {
+        assert_eq!(0, s.len() % 8);
+    }
+
+    for s in check::<Base64>() {
+        assert_eq!(0, s.len() % 4);
+    }
+
+    for s in check::<Base64UrlDecoded>() {
+        assert!(!s.contains('='));
+    }
+}
+
+#[test]
+fn string() {
+    check::<Boolean>();
+    check::<Json>();
+    check::<Title>();
+    check::<Note>();
+    check::<TWPhone>();
+    check::<Hex>();
+    check::<SemVer>();
+
+    for s in check::<TextPath>() {
+        assert!(s.ends_with(".txt"));
+    }
+
+    for s in check::<MacAddress>() {
+        assert_eq!(17, s.len());
+        assert!(!s.chars().any(|c| c.is_ascii_lowercase()));
+    }
+
+    for s in check::<Uuid>() {
+        let groups: Vec<usize> = s.split('-').map(str::len).collect();
+
+        assert_eq!(vec![8, 4, 4, 4, 12], groups);
+        assert!(!s.chars().any(|c| c.is_ascii_uppercase()));
+    }
+}
+
+#[test]
+fn host() {
+    for s in check::<Domain>() {
+        assert!(s.split('.').count() >= 2);
+    }
+
+    check::<DomainWithPort>();
+    check::<Email>();
+    check::<HostAllowPort>();
+    check::<HttpUrl>();
+    check::<Ipv4>();
+    check::<LocalIpv6WithPort>();
+}
+
+#[test]
+fn number() {
+    check::<SmallByte>();
+    check::<Float>();
+    check::<FarFromZero>();
+    check::<Positive>();
+
+    for s in check::<Ratio>() {
+        let n: f64 = s.parse().unwrap();
+
+        assert!((0.0..1.5).contains(&n));
+    }
+
+    for ratio in sample(any::<Ratio>()) {
+        assert!((0.0..1.5).contains(&ratio.0));
+    }
+}
+
+#[test]
+fn collection() {
+    for tags in sample(any::<Tags>()) {
+        assert!((1..=3).contains(&tags.0.len()));
+    }
+}
+
+#[test]
+fn combinator() {
+    check::<HexTitle>();
+    check::<Ipv4OrUuid>();
+    check::<NotIpv4>();
+}
+
+#[test]
+fn without_debug() {
+    for s in sample(BooleanWithoutDebug::valid_strings()) {
+        assert!(BooleanWithoutDebug::validate_str(s).is_ok());
+    }
+}
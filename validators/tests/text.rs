@@ -193,3 +193,84 @@ fn byte() {
         },
     }
 }
+
+#[test]
+fn error_context() {
+    use validators::errors::{LengthUnit, TextError};
+
+    #[derive(Validator)]
+    #[validator(text(char_length(trimmed_min = 2, min = 2, max = 3)))]
+    pub struct Text(pub String);
+
+    match Text::parse_str("12345").err().unwrap() {
+        TextError::TooLong {
+            max,
+            length,
+            unit,
+        } => {
+            assert_eq!(3, max);
+            assert_eq!(5, length);
+            assert_eq!(LengthUnit::Char, unit);
+        },
+        error => panic!("{error:?}"),
+    }
+
+    match Text::parse_str(" 1 ").err().unwrap() {
+        TextError::TooShort {
+            min,
+            trimmed_min,
+            length,
+            trimmed_length,
+            ..
+        } => {
+            assert_eq!(Some(2), min);
+            assert_eq!(Some(2), trimmed_min);
+            assert_eq!(3, length);
+            assert_eq!(1, trimmed_length);
+        },
+        error => panic!("{error:?}"),
+    }
+
+    assert_eq!(
+        "text is too long, must be at most 3 characters (got 5)",
+        Text::parse_str("12345").err().unwrap().to_string()
+    );
+    assert_eq!(
+        "text is too short, must be at least 2 characters after trimming (got 1)",
+        Text::parse_str(" 1 ").err().unwrap().to_string()
+    );
+}
+
+#[test]
+fn over_length_before_trimmed_min() {
+    use validators::errors::{LengthUnit, TextError};
+
+    #[derive(Validator)]
+    #[validator(text(char_length(trimmed_min = 4, min = 4, max = 4)))]
+    pub struct CharText(pub String);
+
+    #[derive(Validator)]
+    #[validator(text(byte_length(trimmed_min = 4, min = 4, max = 4)))]
+    pub struct ByteText(pub String);
+
+    for (error, unit) in [
+        (CharText::parse_str("  abcd  ").err().unwrap(), LengthUnit::Char),
+        (ByteText::parse_str("  abcd  ").err().unwrap(), LengthUnit::Byte),
+    ] {
+        match error {
+            TextError::TooLong {
+                max,
+                length,
+                unit: error_unit,
+            } => {
+                assert_eq!(4, max);
+                assert_eq!(8, length);
+                assert_eq!(unit, error_unit);
+            },
+            error => panic!("{error:?}"),
+        }
+    }
+
+    assert!(matches!(CharText::parse_str("        "), Err(TextError::TooLong { .. })));
+    assert!(CharText::parse_str("abcd").is_ok());
+}
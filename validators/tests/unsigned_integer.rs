@@ -185,6 +185,9 @@ fn basic() {
         {
             range => Outside(min = 0, max = 1, inclusive = false),
         },
+        {
+            range => Outside(min = 1, max = 3),
+        },
     }
 
     test2! {
@@ -206,6 +209,9 @@ fn basic() {
         {
             range => Outside(min = 0, max = 1, inclusive = false),
         },
+        {
+            range => Outside(min = 1, max = 3),
+        },
     }
 
     test3! {
@@ -227,5 +233,32 @@ fn basic() {
         {
             range => Outside(min = 0, max = 1, inclusive = false),
         },
+        {
+            range => Outside(min = 1, max = 3),
+        },
     }
 }
+
+#[test]
+fn error_context() {
+    use validators::errors::UnsignedIntegerError;
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Outside(min = 1, max = 3))))]
+    pub struct Count(pub u8);
+
+    assert!(matches!(
+        Count::parse_u8(2),
+        Err(UnsignedIntegerError::Forbidden {
+            min:       Some(1),
+            max:       Some(3),
+            inclusive: true,
+            value:     2,
+        })
+    ));
+
+    assert_eq!(
+        "integer is forbidden, must not be in 1..=3 (got 3)",
+        Count::parse_str("3").err().unwrap().to_string()
+    );
+}
@@ -0,0 +1,111 @@
+#![cfg(all(feature = "derive", feature = "std", feature = "text", feature = "unsigned_integer"))]
+
+use std::collections::{BTreeMap, HashMap};
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 100))))]
+pub struct Count(pub u8);
+
+#[derive(Validator)]
+#[validator(text(char_length(trimmed_min = 1, min = 1, max = 10)))]
+pub struct Name(pub String);
+
+#[derive(Validate)]
+pub struct Form {
+    pub count:    Count,
+    #[validate(rename = "display-name")]
+    pub name:     Name,
+    pub nickname: Option<Name>,
+}
+
+#[test]
+fn parse_fields() {
+    let mut fields = HashMap::new();
+
+    fields.insert("count", "5");
+    fields.insert("display-name", "Len");
+
+    let form = Form::parse_fields(&fields).unwrap();
+
+    assert_eq!(5, form.count.0);
+    assert_eq!("Len", form.name.0);
+    assert!(form.nickname.is_none());
+
+    fields.insert("nickname", "magic");
+
+    let form = Form::parse_fields(&fields).unwrap();
+
+    assert_eq!("magic", form.nickname.unwrap().0);
+
+    assert!(Form::validate_fields(&fields).is_ok());
+}
+
+#[test]
+fn all_errors() {
+    let mut fields = BTreeMap::new();
+
+    fields.insert("count", "0");
+    fields.insert("nickname", "   ");
+
+    let errors = Form::parse_fields(&fields).err().unwrap();
+
+    let names = errors.iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(["count", "display-name", "nickname"], names.as_slice());
+
+    assert!(errors.errors()[1].1.is::<validators::errors::MissingFieldError>());
+
+    let errors = Form::validate_fields(&fields).err().unwrap();
+
+    assert_eq!(3, errors.len());
+}
+
+#[test]
+fn slice_source() {
+    let fields = [("count", "101"), ("display-name", "Len")];
+
+    let errors = Form::parse_fields(&fields[..]).err().unwrap();
+
+    assert_eq!(1, errors.len());
+    assert_eq!("count", errors.errors()[0].0.to_string());
+}
+
+#[cfg(all(feature = "boolean", feature = "number"))]
+#[derive(Validator)]
+#[validator(number(nan(Disallow), range(Inside(min = 0.0, max = 1.0))))]
+pub struct Ratio(pub f64);
+
+#[cfg(all(feature = "boolean", feature = "number"))]
+#[derive(Validator)]
+#[validator(boolean)]
+pub struct Flag(pub bool);
+
+#[cfg(all(feature = "boolean", feature = "number"))]
+mod settings {
+    // only the items which the generated code of `Validate` refers to are in scope
+    use validators::{prelude::validators_prelude, Validate};
+
+    #[derive(Validate)]
+    pub struct Settings {
+        pub ratio:   super::Ratio,
+        pub enabled: super::Flag,
+    }
+}
+
+#[cfg(all(feature = "boolean", feature = "number"))]
+#[test]
+fn number_and_boolean() {
+    use settings::Settings;
+
+    let settings = Settings::parse_fields(&[("ratio", "0.5"), ("enabled", "yes")][..]).unwrap();
+
+    assert_eq!(0.5, settings.ratio.0);
+    assert!(settings.enabled.0);
+
+    let errors =
+        Settings::validate_fields(&[("ratio", "2"), ("enabled", "maybe")][..]).unwrap_err();
+
+    assert_eq!(2, errors.len());
+}
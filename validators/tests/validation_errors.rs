@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use validators::errors::{FieldPath, FieldPathSegment, MissingFieldError, ValidationErrors};
+
+fn user_errors() -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+
+    errors.push("email", MissingFieldError);
+    errors.push("name", MissingFieldError);
+
+    errors
+}
+
+#[test]
+fn field_path() {
+    let mut path = FieldPath::from("users");
+
+    path.push_index(3);
+    path.push_field("email");
+
+    assert_eq!("users[3].email", path.to_string());
+    assert_eq!(
+        &[
+            FieldPathSegment::Field(String::from("users")),
+            FieldPathSegment::Index(3),
+            FieldPathSegment::Field(String::from("email"))
+        ],
+        path.segments()
+    );
+
+    assert_eq!("[0].a", FieldPath::from(0usize).join("a").to_string());
+    assert_eq!("", FieldPath::new().to_string());
+}
+
+#[test]
+fn merge() {
+    let mut errors = ValidationErrors::new();
+
+    errors.push("id", MissingFieldError);
+    errors.merge(FieldPath::from("users").join(3usize), user_errors());
+
+    assert_eq!(3, errors.len());
+    assert_eq!(
+        "id: missing field\nusers[3].email: missing field\nusers[3].name: missing field",
+        errors.to_string()
+    );
+
+    let paths = errors.iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(["id", "users[3].email", "users[3].name"], paths.as_slice());
+    assert_eq!(Some("field.missing"), errors.code(2));
+    assert_eq!(None, errors.code(3));
+
+    for (_, error) in &errors {
+        assert!(error.is::<MissingFieldError>());
+    }
+
+    assert_eq!(3, errors.into_iter().count());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serialize() {
+    let mut errors = ValidationErrors::new();
+
+    errors.merge("user", user_errors());
+    errors.push_boxed("token", Box::from("expired token"));
+
+    assert_eq!(
+        r#"[{"path":"user.email","code":"field.missing","message":"missing field"},{"path":"user.name","code":"field.missing","message":"missing field"},{"path":"token","code":null,"message":"expired token"}]"#,
+        validators::serde_json::to_string(&errors).unwrap()
+    );
+}